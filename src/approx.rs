@@ -0,0 +1,246 @@
+use crate::difference::{Comparable, DeltaE};
+use crate::models::hsv::{HSVColor, HSV};
+use crate::models::ictcp::Ictcp;
+use crate::models::jzazbz::Jzazbz;
+use crate::models::lab::Lab;
+use crate::models::oklab::Oklab;
+use crate::models::rgb::linear::LinearRgb;
+use crate::models::rgb::RGBColor;
+use crate::models::xyz::XYZ;
+use crate::number_utils;
+use crate::{RGB24, RGB48};
+use std::fmt::Debug;
+
+/// The tolerance of an approximate comparison
+///
+/// Channel based tolerances compare the channels of [`ApproxEq::channels`], where channels
+/// with a fixed range (RGB, HSV) are normalized to `0.0 - 1.0`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Tolerance {
+    /// The difference of each channel must be less than the given value
+    Absolute(f64),
+    /// The difference of each channel must be less than the given fraction of the larger magnitude
+    Relative(f64),
+    /// The color difference of the given metric must be less than the given value
+    DeltaE(DeltaE, f64),
+}
+
+/// Approximate equality of colors with a tolerance per comparison
+///
+/// Unlike [`PartialEq`] the comparison is not transitive.
+///
+/// # Example
+/// ```
+/// use colorful::approx::{ApproxEq, Tolerance};
+/// use colorful::difference::DeltaE;
+/// use colorful::{RGBColor, RGB24};
+///
+/// let (a, b) = (RGB24::from_rgb(200, 30, 30), RGB24::from_rgb(201, 30, 29));
+/// assert!(a.approx_eq(&b, Tolerance::Absolute(2.0 / 255.0)));
+/// assert!(!a.approx_eq(&b, Tolerance::Relative(0.01)));
+/// assert!(a.approx_eq(&b, Tolerance::DeltaE(DeltaE::Ciede2000, 1.0)));
+/// ```
+pub trait ApproxEq: Comparable + Sized {
+    /// If the first channel is a hue normalized to `0.0 - 1.0`, which is compared around the circle
+    const CIRCULAR_HUE: bool = false;
+
+    /// Returns the channels compared by [`Tolerance::Absolute`] and [`Tolerance::Relative`]
+    fn channels(&self) -> (f64, f64, f64);
+
+    /// Returns if this and `other` are equal within the given `tolerance`
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance) -> bool {
+        let (a, mut b) = (self.channels(), other.channels());
+        if Self::CIRCULAR_HUE {
+            // compare the shorter distance, e.g. 359.9° and 0.1° differ by 0.2°
+            let turns = (b.0 - a.0).round();
+            if turns.is_finite() {
+                b.0 -= turns;
+            }
+        }
+        match tolerance {
+            Tolerance::Absolute(epsilon) => {
+                let equal =
+                    |a: f64, b: f64| a == b || number_utils::approx_equal_f64(a, b, epsilon);
+                equal(a.0, b.0) && equal(a.1, b.1) && equal(a.2, b.2)
+            }
+            Tolerance::Relative(fraction) => {
+                let equal = |a: f64, b: f64| {
+                    a == b || number_utils::approx_equal_f64(a, b, fraction * a.abs().max(b.abs()))
+                };
+                equal(a.0, b.0) && equal(a.1, b.1) && equal(a.2, b.2)
+            }
+            Tolerance::DeltaE(metric, epsilon) => metric.difference(self, other) < epsilon,
+        }
+    }
+}
+
+/// Asserts that `actual` is approximately equal to `expected`
+///
+/// # Panics
+/// If the colors aren't equal within the given `tolerance`
+///
+/// # Example
+/// ```
+/// use colorful::approx::{assert_approx_eq, Tolerance};
+/// use colorful::{Lab, RGBColor, RGB24};
+///
+/// let red = Lab::from_rgb(&RGB24::RED);
+/// assert_approx_eq(&Lab::new(53.24, 80.09, 67.2), &red, Tolerance::Absolute(0.01));
+/// ```
+#[track_caller]
+pub fn assert_approx_eq<T: ApproxEq + Debug>(expected: &T, actual: &T, tolerance: Tolerance) {
+    if !expected.approx_eq(actual, tolerance) {
+        panic!(
+            "assertion failed: expected {:?}, actual {:?} (tolerance {:?})",
+            expected, actual, tolerance
+        );
+    }
+}
+
+impl ApproxEq for Lab {
+    fn channels(&self) -> (f64, f64, f64) {
+        self.as_tuple()
+    }
+}
+
+impl ApproxEq for Oklab {
+    fn channels(&self) -> (f64, f64, f64) {
+        self.as_tuple()
+    }
+}
+
+impl ApproxEq for Ictcp {
+    fn channels(&self) -> (f64, f64, f64) {
+        self.as_tuple()
+    }
+}
+
+impl ApproxEq for Jzazbz {
+    fn channels(&self) -> (f64, f64, f64) {
+        self.as_tuple()
+    }
+}
+
+impl ApproxEq for XYZ {
+    fn channels(&self) -> (f64, f64, f64) {
+        self.as_tuple()
+    }
+}
+
+impl ApproxEq for LinearRgb {
+    fn channels(&self) -> (f64, f64, f64) {
+        self.as_tuple()
+    }
+}
+
+impl ApproxEq for RGB24 {
+    fn channels(&self) -> (f64, f64, f64) {
+        self.as_tuple_f64()
+    }
+}
+
+impl ApproxEq for RGB48 {
+    fn channels(&self) -> (f64, f64, f64) {
+        self.as_tuple_f64()
+    }
+}
+
+impl ApproxEq for HSV {
+    const CIRCULAR_HUE: bool = true;
+
+    fn channels(&self) -> (f64, f64, f64) {
+        (
+            self.h() / HSV::H_MAX,
+            self.s() / HSV::S_MAX,
+            self.v() / HSV::V_MAX,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approx::{assert_approx_eq, ApproxEq, Tolerance};
+    use crate::difference::DeltaE;
+    use crate::models::hsv::{HSVColor, HSV};
+    use crate::models::lab::Lab;
+    use crate::models::rgb::RGBColor;
+    use crate::models::xyz::XYZ;
+    use crate::{RGB24, RGB48};
+
+    #[test]
+    fn absolute() {
+        let lab = Lab::new(50.0, 10.0, -10.0);
+        assert!(lab.approx_eq(&lab, Tolerance::Absolute(0.0)));
+        assert!(lab.approx_eq(&Lab::new(50.5, 10.0, -10.5), Tolerance::Absolute(0.6)));
+        assert!(!lab.approx_eq(&Lab::new(50.5, 10.0, -10.5), Tolerance::Absolute(0.5)));
+
+        // channels with a fixed range are normalized
+        let (a, b) = (RGB24::from_rgb(0, 100, 255), RGB24::from_rgb(3, 100, 252));
+        assert!(a.approx_eq(&b, Tolerance::Absolute(4.0 / 255.0)));
+        assert!(!a.approx_eq(&b, Tolerance::Absolute(3.0 / 255.0)));
+        assert!(RGB48::WHITE.approx_eq(
+            &RGB48::from_rgb(65535, 65535, 65500),
+            Tolerance::Absolute(1e-3)
+        ));
+        assert!(HSV::from_hsv(0.0, 0.5, 0.5)
+            .approx_eq(&HSV::from_hsv(3.6, 0.5, 0.5), Tolerance::Absolute(0.011)));
+    }
+
+    #[test]
+    fn circular_hue() {
+        let (a, b) = (HSV::from_hsv(359.9, 0.5, 0.5), HSV::from_hsv(0.1, 0.5, 0.5));
+        assert!(a.approx_eq(&b, Tolerance::Absolute(0.01)));
+        assert!(b.approx_eq(&a, Tolerance::Absolute(0.01)));
+        assert!(!a.approx_eq(&b, Tolerance::Absolute(0.1 / 360.0)));
+        assert!(a.approx_eq(&b, Tolerance::Absolute(0.3 / 360.0)));
+
+        // the largest distance is half a turn
+        let opposite = HSV::from_hsv(180.0, 0.5, 0.5);
+        assert!(!HSV::from_hsv(0.0, 0.5, 0.5).approx_eq(&opposite, Tolerance::Absolute(0.49)));
+        assert!(HSV::from_hsv(0.0, 0.5, 0.5).approx_eq(&opposite, Tolerance::Absolute(0.51)));
+    }
+
+    #[test]
+    fn relative() {
+        let xyz = XYZ::new(100.0, 1.0, 0.0);
+        assert!(xyz.approx_eq(&XYZ::new(100.9, 1.009, 0.0), Tolerance::Relative(0.01)));
+        assert!(!xyz.approx_eq(&XYZ::new(100.0, 1.02, 0.0), Tolerance::Relative(0.01)));
+        assert!(!xyz.approx_eq(&XYZ::new(100.0, 1.0, 1e-9), Tolerance::Relative(0.01)));
+    }
+
+    #[test]
+    fn delta_e() {
+        let (a, b) = (RGB24::from_rgb(200, 30, 30), RGB24::from_rgb(30, 30, 200));
+        assert!(!a.approx_eq(&b, Tolerance::DeltaE(DeltaE::Ciede2000, 10.0)));
+        assert!(a.approx_eq(&b, Tolerance::DeltaE(DeltaE::Ciede2000, 100.0)));
+        let lab = Lab::from_rgb(&a);
+        assert!(lab.approx_eq(
+            &Lab::new(lab.l() + 0.5, lab.a(), lab.b()),
+            Tolerance::DeltaE(DeltaE::Cie76, 0.6)
+        ));
+    }
+
+    #[test]
+    fn non_finite() {
+        let nan = Lab::new(f64::NAN, 0.0, 0.0);
+        assert!(nan.approx_eq(&nan, Tolerance::Absolute(1.0)));
+        assert!(!nan.approx_eq(&Lab::default(), Tolerance::Absolute(1.0)));
+        let infinite = XYZ::new(f64::INFINITY, 0.0, 0.0);
+        assert!(infinite.approx_eq(&infinite, Tolerance::Relative(0.1)));
+    }
+
+    #[test]
+    fn assert_approx_eq_() {
+        assert_approx_eq(
+            &RGB24::RED,
+            &RGB24::from_rgb(254, 1, 0),
+            Tolerance::Absolute(0.01),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn assert_approx_eq_panics() {
+        assert_approx_eq(&RGB24::RED, &RGB24::BLUE, Tolerance::Absolute(0.01));
+    }
+}
@@ -0,0 +1,526 @@
+use crate::gamut::GamutMapping;
+use crate::models::oklab::Oklab;
+use crate::models::rgb::RGBColor;
+use crate::space::RgbSpace;
+use crate::RGB24;
+
+/// The exponent of the simplified transfer function of APCA
+const APCA_TRC: f64 = 2.4;
+/// The luminance coefficients of APCA (sRGB)
+const APCA_COEFFICIENTS: (f64, f64, f64) = (0.212_672_9, 0.715_152_2, 0.072_175);
+/// The threshold of the soft clamp of black levels
+const APCA_BLACK_THRESHOLD: f64 = 0.022;
+/// The exponent of the soft clamp of black levels
+const APCA_BLACK_CLAMP: f64 = 1.414;
+/// The minimal difference of luminance
+const APCA_DELTA_Y_MIN: f64 = 0.0005;
+/// The exponents of background and text for normal polarity (dark text on light background)
+const APCA_NORMAL: (f64, f64) = (0.56, 0.57);
+/// The exponents of background and text for reverse polarity (light text on dark background)
+const APCA_REVERSE: (f64, f64) = (0.65, 0.62);
+/// The scale of the contrast
+const APCA_SCALE: f64 = 1.14;
+/// The offset of low contrasts
+const APCA_LOW_OFFSET: f64 = 0.027;
+/// The contrast below which the result is clipped to zero
+const APCA_LOW_CLIP: f64 = 0.1;
+
+/// The minimal font sizes in px by Lc (rows in steps of 5) and font weight (columns 100 - 900)
+///
+/// `N` is too low for text.
+#[rustfmt::skip]
+const APCA_FONT_SIZES: [[f64; 9]; 26] = [
+    [N, N, N, N, N, N, N, N, N],
+    [N, N, N, N, N, N, N, N, N],
+    [N, N, N, N, N, N, N, N, N],
+    [N, N, N, N, N, N, N, N, N],
+    [N, N, N, N, N, N, N, N, N],
+    [N, N, N, 120.0, 120.0, 108.0, 96.0, 96.0, 96.0],
+    [N, N, 120.0, 108.0, 108.0, 96.0, 72.0, 72.0, 72.0],
+    [N, 120.0, 108.0, 96.0, 72.0, 60.0, 48.0, 48.0, 48.0],
+    [120.0, 108.0, 96.0, 60.0, 48.0, 42.0, 32.0, 32.0, 32.0],
+    [108.0, 96.0, 72.0, 42.0, 32.0, 28.0, 24.0, 24.0, 24.0],
+    [96.0, 72.0, 60.0, 32.0, 28.0, 24.0, 21.0, 21.0, 21.0],
+    [80.0, 60.0, 48.0, 28.0, 24.0, 21.0, 18.0, 18.0, 18.0],
+    [72.0, 48.0, 42.0, 24.0, 21.0, 18.0, 16.0, 16.0, 18.0],
+    [68.0, 46.0, 32.0, 21.75, 19.0, 17.0, 15.0, 16.0, 18.0],
+    [64.0, 44.0, 28.0, 19.5, 18.0, 16.0, 14.5, 16.0, 18.0],
+    [60.0, 42.0, 24.0, 18.0, 16.0, 15.0, 14.0, 16.0, 18.0],
+    [56.0, 38.25, 23.0, 17.25, 15.81, 14.81, 14.0, 16.0, 18.0],
+    [52.0, 34.5, 22.0, 16.5, 15.625, 14.625, 14.0, 16.0, 18.0],
+    [48.0, 32.0, 21.0, 16.0, 15.5, 14.5, 14.0, 16.0, 18.0],
+    [45.0, 28.0, 19.5, 15.5, 15.0, 14.0, 13.5, 16.0, 18.0],
+    [42.0, 26.5, 18.5, 15.0, 14.5, 13.5, 13.0, 16.0, 18.0],
+    [39.0, 25.0, 18.0, 14.0, 14.0, 13.0, 12.0, 16.0, 18.0],
+    [36.0, 24.0, 18.0, 14.0, 13.0, 12.0, 11.0, 16.0, 18.0],
+    [34.0, 22.5, 17.5, 13.5, 12.5, 11.5, 10.0, 16.0, 18.0],
+    [32.0, 21.0, 17.0, 13.0, 12.0, 11.0, 10.0, 16.0, 18.0],
+    [30.0, 20.0, 16.0, 12.0, 11.0, 10.0, 10.0, 16.0, 18.0],
+];
+
+/// Marks contrasts too low for text in [`APCA_FONT_SIZES`]
+const N: f64 = f64::INFINITY;
+
+/// The precision of the lightness search in Oklch
+const ADJUST_EPSILON: f64 = 1e-4;
+
+/// The size of text regarding the contrast requirements of WCAG 2.x
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum TextSize {
+    /// Text below 18pt (24px) or below 14pt (18.66px) if bold
+    #[default]
+    Normal,
+    /// Text of at least 18pt (24px) or 14pt (18.66px) if bold
+    Large,
+}
+
+/// A conformance level of WCAG 2.x regarding contrast
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WcagLevel {
+    /// Level AA - success criterion 1.4.3 (Contrast (Minimum))
+    Aa,
+    /// Level AAA - success criterion 1.4.6 (Contrast (Enhanced))
+    Aaa,
+}
+
+impl WcagLevel {
+    /// Returns the minimal contrast ratio of this level for text of the given size
+    ///
+    /// # Example
+    /// ```
+    /// use colorful::contrast::{TextSize, WcagLevel};
+    ///
+    /// assert_eq!(4.5, WcagLevel::Aa.minimum_ratio(TextSize::Normal));
+    /// assert_eq!(4.5, WcagLevel::Aaa.minimum_ratio(TextSize::Large));
+    /// ```
+    pub fn minimum_ratio(&self, size: TextSize) -> f64 {
+        match (self, size) {
+            (WcagLevel::Aa, TextSize::Normal) => 4.5,
+            (WcagLevel::Aa, TextSize::Large) => 3.0,
+            (WcagLevel::Aaa, TextSize::Normal) => 7.0,
+            (WcagLevel::Aaa, TextSize::Large) => 4.5,
+        }
+    }
+
+    /// Returns the highest level met by the given contrast ratio for text of the given size
+    ///
+    /// # Returns
+    /// `None` if not even [`WcagLevel::Aa`] is met
+    pub fn from_ratio(ratio: f64, size: TextSize) -> Option<WcagLevel> {
+        [WcagLevel::Aaa, WcagLevel::Aa]
+            .iter()
+            .copied()
+            .find(|level| ratio >= level.minimum_ratio(size))
+    }
+}
+
+/// Calculates the contrast ratio of WCAG 2.x (1.0 - 21.0) between the given colors (sRGB)
+///
+/// The order of the colors doesn't matter.
+///
+/// # Example
+/// ```
+/// use colorful::contrast::contrast_ratio;
+/// use colorful::{RGBColor, RGB24};
+///
+/// assert_eq!(21.0, contrast_ratio(&RGB24::BLACK, &RGB24::WHITE));
+/// assert!(contrast_ratio(&RGB24::from_rgb(0x76, 0x76, 0x76), &RGB24::WHITE) >= 4.5);
+/// ```
+pub fn contrast_ratio<T, U>(first: &impl RGBColor<T>, second: &impl RGBColor<U>) -> f64 {
+    let (first, second) = (first.relative_luminance(), second.relative_luminance());
+    (first.max(second) + 0.05) / (first.min(second) + 0.05)
+}
+
+/// Returns the highest WCAG 2.x level met by the given text and background colors (sRGB)
+///
+/// # Returns
+/// `None` if not even [`WcagLevel::Aa`] is met
+pub fn wcag_level<T, U>(
+    text: &impl RGBColor<T>,
+    background: &impl RGBColor<U>,
+    size: TextSize,
+) -> Option<WcagLevel> {
+    WcagLevel::from_ratio(contrast_ratio(text, background), size)
+}
+
+/// Returns if the given text and background colors (sRGB) meet the given WCAG 2.x level
+pub fn meets_wcag<T, U>(
+    text: &impl RGBColor<T>,
+    background: &impl RGBColor<U>,
+    level: WcagLevel,
+    size: TextSize,
+) -> bool {
+    contrast_ratio(text, background) >= level.minimum_ratio(size)
+}
+
+/// Calculates the lightness contrast Lc of [APCA](https://github.com/Myndex/apca-w3) (0.0.98G-4g)
+///
+/// The result is polarity aware: positive (up to about 106) for dark text on a light background,
+/// negative (down to about -108) for light text on a dark background. Contrasts below 10 are clipped to `0.0`.
+///
+/// # Example
+/// ```
+/// use colorful::contrast::apca_contrast;
+/// use colorful::{RGBColor, RGB24};
+///
+/// assert!((apca_contrast(&RGB24::BLACK, &RGB24::WHITE) - 106.04).abs() < 0.01);
+/// assert!((apca_contrast(&RGB24::WHITE, &RGB24::BLACK) + 107.88).abs() < 0.01);
+/// ```
+pub fn apca_contrast(text: &RGB24, background: &RGB24) -> f64 {
+    let (text, background) = (apca_luminance(text), apca_luminance(background));
+    if (background - text).abs() < APCA_DELTA_Y_MIN {
+        return 0.0;
+    }
+
+    let contrast = if background > text {
+        let (background_exponent, text_exponent) = APCA_NORMAL;
+        let contrast =
+            (background.powf(background_exponent) - text.powf(text_exponent)) * APCA_SCALE;
+        if contrast < APCA_LOW_CLIP {
+            0.0
+        } else {
+            contrast - APCA_LOW_OFFSET
+        }
+    } else {
+        let (background_exponent, text_exponent) = APCA_REVERSE;
+        let contrast =
+            (background.powf(background_exponent) - text.powf(text_exponent)) * APCA_SCALE;
+        if contrast > -APCA_LOW_CLIP {
+            0.0
+        } else {
+            contrast + APCA_LOW_OFFSET
+        }
+    };
+    contrast * 100.0
+}
+
+/// Returns the minimal font size in px for the given APCA contrast Lc and font weight
+///
+/// The lookup rounds down to the table rows in steps of Lc 5 and font weights
+/// in steps of 100 (so lighter weights are assumed), the polarity is ignored.
+///
+/// # Parameters
+/// - `lc`: the contrast as calculated by [`apca_contrast`]
+/// - `weight`: the font weight (100 - 900)
+///
+/// # Returns
+/// `None` if the contrast is too low for text of this weight
+///
+/// # Example
+/// ```
+/// use colorful::contrast::apca_minimum_font_size;
+///
+/// assert_eq!(Some(16.0), apca_minimum_font_size(90.0, 400));
+/// assert_eq!(Some(24.0), apca_minimum_font_size(-62.3, 400));
+/// assert_eq!(None, apca_minimum_font_size(20.0, 900));
+/// ```
+pub fn apca_minimum_font_size(lc: f64, weight: u16) -> Option<f64> {
+    let row = ((lc.abs() / 5.0).floor() as usize).min(APCA_FONT_SIZES.len() - 1);
+    let column = (weight.clamp(100, 900) / 100 - 1) as usize;
+    Some(APCA_FONT_SIZES[row][column]).filter(|size| size.is_finite())
+}
+
+/// Returns if the given text and background colors have enough APCA contrast
+/// for text of the given size and weight
+///
+/// # Parameters
+/// - `font_size`: the font size in px
+/// - `weight`: the font weight (100 - 900)
+pub fn meets_apca(text: &RGB24, background: &RGB24, font_size: f64, weight: u16) -> bool {
+    apca_minimum_font_size(apca_contrast(text, background), weight)
+        .is_some_and(|minimum| font_size >= minimum)
+}
+
+/// A minimal contrast between text and background
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ContrastTarget {
+    /// The contrast ratio of WCAG 2.x (1.0 - 21.0), see [`contrast_ratio`]
+    Wcag(f64),
+    /// The absolute lightness contrast |Lc| of APCA, see [`apca_contrast`]
+    Apca(f64),
+}
+
+impl ContrastTarget {
+    /// Returns if the given text and background colors meet this target
+    pub fn is_met(&self, text: &RGB24, background: &RGB24) -> bool {
+        match *self {
+            ContrastTarget::Wcag(ratio) => contrast_ratio(text, background) >= ratio,
+            ContrastTarget::Apca(lc) => apca_contrast(text, background).abs() >= lc,
+        }
+    }
+}
+
+/// Finds the color closest to `text` in Oklch lightness which meets the `target` on the `background`
+///
+/// Hue and chroma are kept, only if the lighter or darker color is outside of sRGB
+/// the chroma is reduced (see [`GamutMapping::Oklch`]). Both directions are searched,
+/// the one with the smaller change of lightness wins.
+///
+/// # Returns
+/// `text` if it already meets the target, `None` if neither black nor white meet it
+///
+/// # Example
+/// ```
+/// use colorful::contrast::{adjust_for_contrast, contrast_ratio, ContrastTarget};
+/// use colorful::{RGBColor, RGB24};
+///
+/// let (text, background) = (RGB24::from_rgb(90, 160, 255), RGB24::WHITE);
+/// let adjusted = adjust_for_contrast(&text, &background, ContrastTarget::Wcag(4.5)).unwrap();
+/// assert!(contrast_ratio(&adjusted, &background) >= 4.5);
+/// assert!(adjusted.r() < text.r() && adjusted.b() > adjusted.r());
+/// ```
+pub fn adjust_for_contrast(
+    text: &RGB24,
+    background: &RGB24,
+    target: ContrastTarget,
+) -> Option<RGB24> {
+    if target.is_met(text, background) {
+        return Some(*text);
+    }
+
+    let (lightness, chroma, hue) = Oklab::from_rgb(text).to_lch();
+    let color = |lightness: f64| -> RGB24 {
+        let xyz = Oklab::from_lch(lightness, chroma, hue).to_xyz();
+        GamutMapping::Oklch.map_to_rgb(&xyz, RgbSpace::srgb()).0
+    };
+
+    [0.0, 1.0]
+        .iter()
+        .filter(|&&bound| target.is_met(&color(bound), background))
+        .map(|&bound| {
+            let (mut near, mut far) = (lightness, bound);
+            while (far - near).abs() > ADJUST_EPSILON {
+                let middle = (near + far) / 2.0;
+                if target.is_met(&color(middle), background) {
+                    far = middle;
+                } else {
+                    near = middle;
+                }
+            }
+            ((far - lightness).abs(), color(far))
+        })
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, color)| color)
+}
+
+/// Returns the estimated screen luminance of APCA with soft clamped black levels
+fn apca_luminance(color: &RGB24) -> f64 {
+    let (r, g, b) = color.as_tuple_f64();
+    let (r_coefficient, g_coefficient, b_coefficient) = APCA_COEFFICIENTS;
+    let luminance = r_coefficient * r.powf(APCA_TRC)
+        + g_coefficient * g.powf(APCA_TRC)
+        + b_coefficient * b.powf(APCA_TRC);
+    if luminance < APCA_BLACK_THRESHOLD {
+        luminance + (APCA_BLACK_THRESHOLD - luminance).powf(APCA_BLACK_CLAMP)
+    } else {
+        luminance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contrast::{
+        adjust_for_contrast, apca_contrast, apca_minimum_font_size, contrast_ratio, meets_apca,
+        meets_wcag, wcag_level, ContrastTarget, TextSize, WcagLevel,
+    };
+    use crate::models::oklab::Oklab;
+    use crate::models::rgb::RGBColor;
+    use crate::{RGB24, RGB48};
+
+    #[test]
+    fn relative_luminance() {
+        assert_eq!(1.0, RGB24::WHITE.relative_luminance());
+        assert_eq!(0.0, RGB48::BLACK.relative_luminance());
+        assert_eq!(0.2126, RGB24::RED.relative_luminance());
+        // linearized, not the raw value 0.5
+        let gray = RGB24::from_rgb(0x80, 0x80, 0x80).relative_luminance();
+        assert!((gray - 0.2159).abs() < 1e-4);
+    }
+
+    #[test]
+    fn contrast_ratio_() {
+        assert_eq!(21.0, contrast_ratio(&RGB24::WHITE, &RGB24::BLACK));
+        assert_eq!(21.0, contrast_ratio(&RGB24::BLACK, &RGB48::WHITE));
+        assert_eq!(1.0, contrast_ratio(&RGB24::RED, &RGB24::RED));
+
+        let (light, dark) = (
+            RGB24::from_rgb(0x77, 0x77, 0x77),
+            RGB24::from_rgb(0x76, 0x76, 0x76),
+        );
+        assert!((contrast_ratio(&light, &RGB24::WHITE) - 4.48).abs() < 0.01);
+        assert!((contrast_ratio(&dark, &RGB24::WHITE) - 4.54).abs() < 0.01);
+        assert!((contrast_ratio(&RGB24::BLUE, &RGB24::WHITE) - 8.59).abs() < 0.01);
+    }
+
+    #[test]
+    fn levels() {
+        assert_eq!(
+            Some(WcagLevel::Aaa),
+            WcagLevel::from_ratio(7.0, TextSize::Normal)
+        );
+        assert_eq!(
+            Some(WcagLevel::Aa),
+            WcagLevel::from_ratio(6.9, TextSize::Normal)
+        );
+        assert_eq!(
+            Some(WcagLevel::Aaa),
+            WcagLevel::from_ratio(4.5, TextSize::Large)
+        );
+        assert_eq!(
+            Some(WcagLevel::Aa),
+            WcagLevel::from_ratio(3.0, TextSize::Large)
+        );
+        assert_eq!(None, WcagLevel::from_ratio(2.9, TextSize::Large));
+        assert_eq!(None, WcagLevel::from_ratio(4.4, TextSize::Normal));
+    }
+
+    #[test]
+    fn apca() {
+        // reference values of APCA-W3 0.0.98G-4g
+        let expected = [
+            (
+                (0x88, 0x88, 0x88),
+                (0xff, 0xff, 0xff),
+                63.056_469_930_209_424,
+            ),
+            (
+                (0xff, 0xff, 0xff),
+                (0x88, 0x88, 0x88),
+                -68.541_464_366_449_62,
+            ),
+            (
+                (0x00, 0x00, 0x00),
+                (0xaa, 0xaa, 0xaa),
+                58.146_262_578_561_334,
+            ),
+            (
+                (0xaa, 0xaa, 0xaa),
+                (0x00, 0x00, 0x00),
+                -56.241_133_368_397_42,
+            ),
+            (
+                (0x11, 0x22, 0x33),
+                (0xdd, 0xee, 0xff),
+                91.668_308_114_816_31,
+            ),
+            (
+                (0xdd, 0xee, 0xff),
+                (0x11, 0x22, 0x33),
+                -93.067_700_494_842_75,
+            ),
+            ((0x11, 0x22, 0x33), (0x44, 0x44, 0x44), 8.323_261_369_573_93),
+            (
+                (0x44, 0x44, 0x44),
+                (0x11, 0x22, 0x33),
+                -7.526_878_460_278_154,
+            ),
+        ];
+        for (text, background, lc) in expected {
+            let actual = apca_contrast(&RGB24::from(text), &RGB24::from(background));
+            assert!(
+                (lc - actual).abs() < 1e-9,
+                "{:?} {:?}: {}",
+                text,
+                background,
+                actual
+            );
+        }
+        assert_eq!(0.0, apca_contrast(&RGB24::RED, &RGB24::RED));
+        // low contrasts are clipped
+        let gray = RGB24::from_rgb(0x80, 0x80, 0x80);
+        assert_eq!(
+            0.0,
+            apca_contrast(&gray, &RGB24::from_rgb(0x84, 0x84, 0x84))
+        );
+    }
+
+    #[test]
+    fn apca_font_size() {
+        assert_eq!(Some(15.0), apca_minimum_font_size(100.0, 400));
+        assert_eq!(Some(18.0), apca_minimum_font_size(79.9, 400));
+        assert_eq!(Some(10.0), apca_minimum_font_size(200.0, 700));
+        assert_eq!(Some(24.0), apca_minimum_font_size(-60.0, 450));
+        assert_eq!(Some(120.0), apca_minimum_font_size(40.0, 1));
+        assert_eq!(None, apca_minimum_font_size(39.9, 100));
+        assert_eq!(None, apca_minimum_font_size(0.0, 900));
+
+        assert!(meets_apca(&RGB24::BLACK, &RGB24::WHITE, 16.0, 400));
+        let gray = RGB24::from_rgb(0x88, 0x88, 0x88);
+        assert!(!meets_apca(&gray, &RGB24::WHITE, 16.0, 400));
+        assert!(meets_apca(&gray, &RGB24::WHITE, 24.0, 400));
+        assert!(!meets_apca(&RGB24::WHITE, &RGB24::WHITE, 200.0, 900));
+    }
+
+    #[test]
+    fn adjust_for_contrast_() {
+        let target = ContrastTarget::Wcag(4.5);
+        assert_eq!(
+            Some(RGB24::BLACK),
+            adjust_for_contrast(&RGB24::BLACK, &RGB24::WHITE, target)
+        );
+
+        // the nearest passing gray
+        let gray = RGB24::from_rgb(0x88, 0x88, 0x88);
+        let adjusted = adjust_for_contrast(&gray, &RGB24::WHITE, target).unwrap();
+        let ratio = contrast_ratio(&adjusted, &RGB24::WHITE);
+        assert!((4.5..4.6).contains(&ratio), "{}", ratio);
+        assert_eq!(adjusted.r(), adjusted.b());
+
+        // light text on a dark background gets lighter, the hue is kept
+        let (text, background) = (RGB24::from_rgb(40, 80, 40), RGB24::from_rgb(20, 20, 20));
+        let adjusted = adjust_for_contrast(&text, &background, target).unwrap();
+        assert!(contrast_ratio(&adjusted, &background) >= 4.5);
+        let (before, after) = (Oklab::from_rgb(&text), Oklab::from_rgb(&adjusted));
+        assert!(after.l() > before.l());
+        assert!((after.hue() - before.hue()).abs() < 3.0);
+
+        assert_eq!(
+            None,
+            adjust_for_contrast(&gray, &RGB24::WHITE, ContrastTarget::Wcag(22.0))
+        );
+    }
+
+    #[test]
+    fn adjust_for_apca() {
+        let target = ContrastTarget::Apca(75.0);
+        let (text, background) = (RGB24::from_rgb(230, 60, 60), RGB24::WHITE);
+        assert!(!target.is_met(&text, &background));
+        let adjusted = adjust_for_contrast(&text, &background, target).unwrap();
+        let lc = apca_contrast(&adjusted, &background);
+        assert!((75.0..77.0).contains(&lc), "{}", lc);
+        assert!(adjusted.r() > adjusted.g());
+
+        // a dark background is met by the lighter color
+        let background = RGB24::from_rgb(0x30, 0x30, 0x30);
+        let adjusted = adjust_for_contrast(&text, &background, target).unwrap();
+        assert!(apca_contrast(&adjusted, &background) <= -75.0);
+    }
+
+    #[test]
+    fn wcag() {
+        let gray = RGB24::from_rgb(0x77, 0x77, 0x77);
+        assert_eq!(None, wcag_level(&gray, &RGB24::WHITE, TextSize::Normal));
+        assert_eq!(
+            Some(WcagLevel::Aa),
+            wcag_level(&gray, &RGB24::WHITE, TextSize::Large)
+        );
+        assert!(!meets_wcag(
+            &gray,
+            &RGB24::WHITE,
+            WcagLevel::Aa,
+            TextSize::Normal
+        ));
+        assert!(meets_wcag(
+            &gray,
+            &RGB24::WHITE,
+            WcagLevel::Aa,
+            TextSize::Large
+        ));
+        assert!(meets_wcag(
+            &RGB24::BLACK,
+            &RGB24::WHITE,
+            WcagLevel::Aaa,
+            TextSize::Normal
+        ));
+    }
+}
@@ -1,14 +1,20 @@
+use crate::matrix::{self, Matrix3};
 use crate::models::hsv::{HSVColor, HSV};
+use crate::models::rgb::linear::LinearRgb;
 use crate::models::rgb::rgb24::RGB24;
 use crate::models::rgb::rgb48::RGB48;
 use crate::models::rgb::RGBColor;
+use crate::models::xyz::XYZ;
 use crate::number_utils;
+use crate::space::{RgbSpace, TransferFunction, PQ_PEAK_LUMINANCE};
+use std::sync::OnceLock;
 
 /// [HSV]: crate::models::hsv::HSV
 /// [RGBColor]: crate::models::rgb::RGBColor
 /// [RGB24]: crate::models::rgb::rgb24::RGB24
-/// [RGB48]: crate::models::rgb::rgb24::RGB24
-
+/// [RGB48]: crate::models::rgb::rgb48::RGB48
+/// [LinearRgb]: crate::models::rgb::linear::LinearRgb
+/// [XYZ]: crate::models::xyz::XYZ
 /// Converts the given [`RGBColor`] -> [`HSV`]
 pub fn rgb_to_hsv<T>(rgb_color: &impl RGBColor<T>) -> HSV {
     let (r, g, b) = rgb_color.as_tuple_f64();
@@ -61,6 +67,422 @@ where
     T::from_rgb_f64(a.0, a.1, a.2)
 }
 
+/// Decodes an sRGB encoded channel (0.0 - 1.0) to linear light
+///
+/// Negative values are mirrored, so out of gamut colors are preserved.
+pub fn srgb_decode(value: f64) -> f64 {
+    let abs = value.abs();
+    if abs <= 0.04045 {
+        value / 12.92
+    } else {
+        value.signum() * ((abs + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encodes a linear channel to sRGB (0.0 - 1.0)
+///
+/// Negative values are mirrored, so out of gamut colors are preserved.
+pub fn srgb_encode(value: f64) -> f64 {
+    let abs = value.abs();
+    if abs <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        value.signum() * (1.055 * abs.powf(1.0 / 2.4) - 0.055)
+    }
+}
+
+/// Returns the lookup table for decoding each `u8` value of sRGB
+fn rgb24_decode_table() -> &'static [f64; 256] {
+    static TABLE: OnceLock<[f64; 256]> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = [0.0; 256];
+        for (value, linear) in table.iter_mut().enumerate() {
+            *linear = srgb_decode(value as f64 / RGB24::MAX as f64);
+        }
+        table
+    })
+}
+
+/// Returns the lookup table for encoding linear values to `u8`
+///
+/// Contains the linear values at which the encoded value switches to the next `u8`.
+fn rgb24_encode_table() -> &'static [f64; 255] {
+    static TABLE: OnceLock<[f64; 255]> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = [0.0; 255];
+        for (value, threshold) in table.iter_mut().enumerate() {
+            *threshold = srgb_decode((value as f64 + 0.5) / RGB24::MAX as f64);
+        }
+        table
+    })
+}
+
+/// Returns the lookup table for decoding each `u16` value of sRGB
+fn rgb48_decode_table() -> &'static [f64] {
+    static TABLE: OnceLock<Vec<f64>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        (0..=RGB48::MAX)
+            .map(|value| srgb_decode(value as f64 / RGB48::MAX as f64))
+            .collect()
+    })
+}
+
+/// Converts the given [`RGBColor`] (sRGB) -> [`LinearRgb`]
+pub fn rgb_to_linear<T>(rgb_color: &impl RGBColor<T>) -> LinearRgb {
+    let (r, g, b) = rgb_color.as_tuple_f64();
+    LinearRgb::new(srgb_decode(r), srgb_decode(g), srgb_decode(b))
+}
+
+/// Converts the given [`LinearRgb`] -> [`RGBColor`] (sRGB)
+pub fn linear_to_rgb<T, U>(linear: &LinearRgb) -> T
+where
+    T: RGBColor<U>,
+{
+    T::from_rgb_f64(
+        srgb_encode(linear.r()),
+        srgb_encode(linear.g()),
+        srgb_encode(linear.b()),
+    )
+}
+
+/// Converts the given [`RGB24`] -> [`LinearRgb`] using a lookup table
+pub fn rgb24_to_linear(rgb: &RGB24) -> LinearRgb {
+    let table = rgb24_decode_table();
+    LinearRgb::new(
+        table[rgb.r() as usize],
+        table[rgb.g() as usize],
+        table[rgb.b() as usize],
+    )
+}
+
+/// Converts the given [`RGB48`] -> [`LinearRgb`] using a lookup table
+pub fn rgb48_to_linear(rgb: &RGB48) -> LinearRgb {
+    let table = rgb48_decode_table();
+    LinearRgb::new(
+        table[rgb.r() as usize],
+        table[rgb.g() as usize],
+        table[rgb.b() as usize],
+    )
+}
+
+/// Converts the given [`LinearRgb`] -> [`RGB24`] using a lookup table
+pub fn linear_to_rgb24(linear: &LinearRgb) -> RGB24 {
+    let table = rgb24_encode_table();
+    let encode = |value: f64| table.partition_point(|&threshold| threshold <= value) as u8;
+    RGB24::from_rgb(encode(linear.r()), encode(linear.g()), encode(linear.b()))
+}
+
+/// Converts the given [`XYZ`] -> CIELAB relative to the given white point
+///
+/// # Returns
+/// Values as tuple (L, a, b)
+pub fn xyz_to_lab(xyz: &XYZ, white_point: (f64, f64)) -> (f64, f64, f64) {
+    let white = XYZ::from_xyy(white_point.0, white_point.1, 1.0);
+    let (fx, fy, fz) = (
+        lab_f(xyz.x() / white.x()),
+        lab_f(xyz.y() / white.y()),
+        lab_f(xyz.z() / white.z()),
+    );
+
+    (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
+/// Converts the given CIELAB values (relative to the given white point) -> [`XYZ`]
+pub fn lab_to_xyz(lab: (f64, f64, f64), white_point: (f64, f64)) -> XYZ {
+    let white = XYZ::from_xyy(white_point.0, white_point.1, 1.0);
+    let fy = (lab.0 + 16.0) / 116.0;
+    let fx = fy + lab.1 / 500.0;
+    let fz = fy - lab.2 / 200.0;
+    let f_inverse = |t: f64| {
+        if t.powi(3) > LAB_EPSILON {
+            t.powi(3)
+        } else {
+            (116.0 * t - 16.0) / LAB_KAPPA
+        }
+    };
+    let y = if lab.0 > LAB_KAPPA * LAB_EPSILON {
+        fy.powi(3)
+    } else {
+        lab.0 / LAB_KAPPA
+    };
+
+    XYZ::new(
+        f_inverse(fx) * white.x(),
+        y * white.y(),
+        f_inverse(fz) * white.z(),
+    )
+}
+
+/// Converts the given [`XYZ`] (relative to D65) -> Oklab
+///
+/// # Returns
+/// Values as tuple (L, a, b)
+pub fn xyz_to_oklab(xyz: &XYZ) -> (f64, f64, f64) {
+    let (l, m, s) = matrix::apply(&XYZ_TO_LMS, xyz.as_tuple());
+    matrix::apply(&LMS_TO_OKLAB, (l.cbrt(), m.cbrt(), s.cbrt()))
+}
+
+/// Converts the given Oklab values -> [`XYZ`] (relative to D65)
+pub fn oklab_to_xyz(oklab: (f64, f64, f64)) -> XYZ {
+    let (l, m, s) = matrix::apply(oklab_to_lms(), oklab);
+    XYZ::from(matrix::apply(
+        lms_to_xyz(),
+        (l.powi(3), m.powi(3), s.powi(3)),
+    ))
+}
+
+/// Converts the given linear Rec. 2020 values (absolute in cd/m²) -> ICtCp (PQ)
+///
+/// # Returns
+/// Values as tuple (I, Ct, Cp)
+pub fn rec2020_to_ictcp(rgb: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (l, m, s) = matrix::apply(&REC2020_TO_ICTCP_LMS, rgb);
+    let pq = |value: f64| TransferFunction::Pq.encode(value / PQ_PEAK_LUMINANCE);
+    matrix::apply(&ICTCP_PQ_LMS_TO_ICTCP, (pq(l), pq(m), pq(s)))
+}
+
+/// Converts the given ICtCp (PQ) values -> linear Rec. 2020 values (absolute in cd/m²)
+pub fn ictcp_to_rec2020(ictcp: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (l, m, s) = matrix::apply(ictcp_to_pq_lms(), ictcp);
+    let pq = |value: f64| TransferFunction::Pq.decode(value) * PQ_PEAK_LUMINANCE;
+    matrix::apply(ictcp_lms_to_rec2020(), (pq(l), pq(m), pq(s)))
+}
+
+/// Converts the given linear Rec. 2020 scene light values (0.0 - 1.0) -> ICtCp (HLG)
+///
+/// # Returns
+/// Values as tuple (I, Ct, Cp)
+pub fn rec2020_to_ictcp_hlg(rgb: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (l, m, s) = matrix::apply(&REC2020_TO_ICTCP_LMS, rgb);
+    let hlg = |value: f64| TransferFunction::Hlg.encode(value);
+    matrix::apply(&ICTCP_HLG_LMS_TO_ICTCP, (hlg(l), hlg(m), hlg(s)))
+}
+
+/// Converts the given ICtCp (HLG) values -> linear Rec. 2020 scene light values (0.0 - 1.0)
+pub fn ictcp_hlg_to_rec2020(ictcp: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (l, m, s) = matrix::apply(ictcp_to_hlg_lms(), ictcp);
+    let hlg = |value: f64| TransferFunction::Hlg.decode(value);
+    matrix::apply(ictcp_lms_to_rec2020(), (hlg(l), hlg(m), hlg(s)))
+}
+
+/// Converts the given absolute [`XYZ`] (relative to D65, in cd/m²) -> ICtCp (PQ)
+///
+/// # Returns
+/// Values as tuple (I, Ct, Cp)
+pub fn xyz_to_ictcp(xyz: &XYZ) -> (f64, f64, f64) {
+    rec2020_to_ictcp(RgbSpace::rec2020().xyz_to_linear(xyz).as_tuple())
+}
+
+/// Converts the given ICtCp (PQ) values -> absolute [`XYZ`] (relative to D65, in cd/m²)
+pub fn ictcp_to_xyz(ictcp: (f64, f64, f64)) -> XYZ {
+    RgbSpace::rec2020().linear_to_xyz(&LinearRgb::from(ictcp_to_rec2020(ictcp)))
+}
+
+/// Converts the given absolute [`XYZ`] (relative to D65, in cd/m²) -> Jzazbz
+///
+/// # Returns
+/// Values as tuple (Jz, az, bz)
+pub fn xyz_to_jzazbz(xyz: &XYZ) -> (f64, f64, f64) {
+    let (x, y, z) = xyz.as_tuple();
+    let x = JZ_B * x - (JZ_B - 1.0) * z;
+    let y = JZ_G * y - (JZ_G - 1.0) * xyz.x();
+    let (l, m, s) = matrix::apply(&JZ_XYZ_TO_LMS, (x, y, z));
+    let (i, a, b) = matrix::apply(&JZ_LMS_TO_IAB, (jz_pq(l), jz_pq(m), jz_pq(s)));
+    ((1.0 + JZ_D) * i / (1.0 + JZ_D * i) - JZ_D0, a, b)
+}
+
+/// Converts the given Jzazbz values -> absolute [`XYZ`] (relative to D65, in cd/m²)
+pub fn jzazbz_to_xyz(jzazbz: (f64, f64, f64)) -> XYZ {
+    let (jz, a, b) = jzazbz;
+    let i = (jz + JZ_D0) / (1.0 + JZ_D - JZ_D * (jz + JZ_D0));
+    let (l, m, s) = matrix::apply(jz_iab_to_lms(), (i, a, b));
+    let (x, y, z) = matrix::apply(
+        jz_lms_to_xyz(),
+        (jz_pq_inverse(l), jz_pq_inverse(m), jz_pq_inverse(s)),
+    );
+    let x = (x + (JZ_B - 1.0) * z) / JZ_B;
+    XYZ::new(x, (y + (JZ_G - 1.0) * x) / JZ_G, z)
+}
+
+/// Converts the rectangular coordinates `(a, b)` -> polar coordinates
+///
+/// # Returns
+/// Values as tuple (chroma, hue in degrees 0.0 - 360.0)
+pub fn to_polar(a: f64, b: f64) -> (f64, f64) {
+    let hue = b.atan2(a).to_degrees();
+    (a.hypot(b), if hue < 0.0 { hue + 360.0 } else { hue })
+}
+
+/// Converts the polar coordinates `(chroma, hue in degrees)` -> rectangular coordinates
+///
+/// # Returns
+/// Values as tuple (a, b)
+pub fn from_polar(chroma: f64, hue: f64) -> (f64, f64) {
+    let (sin, cos) = hue.to_radians().sin_cos();
+    (chroma * cos, chroma * sin)
+}
+
+/// `ε` of CIELAB (CIE standard)
+const LAB_EPSILON: f64 = 216.0 / 24389.0;
+/// `κ` of CIELAB (CIE standard)
+const LAB_KAPPA: f64 = 24389.0 / 27.0;
+
+/// The matrix converting XYZ (D65) -> LMS of Oklab (as defined by CSS Color 4)
+const XYZ_TO_LMS: Matrix3 = [
+    [
+        0.819_022_437_996_703,
+        0.361_906_260_052_890_4,
+        -0.128_873_781_520_987_9,
+    ],
+    [
+        0.032_983_653_932_388_5,
+        0.929_286_861_586_343_4,
+        0.036_144_666_350_642_4,
+    ],
+    [
+        0.048_177_189_359_624_2,
+        0.264_239_531_752_730_8,
+        0.633_547_828_469_430_9,
+    ],
+];
+
+/// The matrix converting non-linear LMS -> Oklab (as defined by CSS Color 4)
+const LMS_TO_OKLAB: Matrix3 = [
+    [
+        0.210_454_268_309_314,
+        0.793_617_774_702_305_4,
+        -0.004_072_043_011_619_3,
+    ],
+    [
+        1.977_998_532_431_168_4,
+        -2.428_592_242_048_58,
+        0.450_593_709_617_411,
+    ],
+    [
+        0.025_904_042_465_547_8,
+        0.782_771_712_457_529_6,
+        -0.808_675_754_923_077_4,
+    ],
+];
+
+/// The matrix converting linear Rec. 2020 -> LMS of ICtCp (Rec. 2100)
+const REC2020_TO_ICTCP_LMS: Matrix3 = [
+    [1688.0 / 4096.0, 2146.0 / 4096.0, 262.0 / 4096.0],
+    [683.0 / 4096.0, 2951.0 / 4096.0, 462.0 / 4096.0],
+    [99.0 / 4096.0, 309.0 / 4096.0, 3688.0 / 4096.0],
+];
+
+/// The matrix converting PQ encoded LMS -> ICtCp (Rec. 2100)
+const ICTCP_PQ_LMS_TO_ICTCP: Matrix3 = [
+    [0.5, 0.5, 0.0],
+    [6610.0 / 4096.0, -13613.0 / 4096.0, 7003.0 / 4096.0],
+    [17933.0 / 4096.0, -17390.0 / 4096.0, -543.0 / 4096.0],
+];
+
+/// The matrix converting HLG encoded LMS -> ICtCp (Rec. 2100)
+const ICTCP_HLG_LMS_TO_ICTCP: Matrix3 = [
+    [0.5, 0.5, 0.0],
+    [3625.0 / 4096.0, -7465.0 / 4096.0, 3840.0 / 4096.0],
+    [9500.0 / 4096.0, -9212.0 / 4096.0, -288.0 / 4096.0],
+];
+
+/// `b` of Jzazbz
+const JZ_B: f64 = 1.15;
+/// `g` of Jzazbz
+const JZ_G: f64 = 0.66;
+/// `d` of Jzazbz
+const JZ_D: f64 = -0.56;
+/// `d0` of Jzazbz
+const JZ_D0: f64 = 1.629_549_953_282_156_6e-11;
+/// `n` of Jzazbz
+const JZ_N: f64 = 2610.0 / 16384.0;
+/// `c1` of Jzazbz
+const JZ_C1: f64 = 3424.0 / 4096.0;
+/// `c2` of Jzazbz
+const JZ_C2: f64 = 2413.0 / 128.0;
+/// `c3` of Jzazbz
+const JZ_C3: f64 = 2392.0 / 128.0;
+/// `p` of Jzazbz
+const JZ_P: f64 = 1.7 * 2523.0 / 32.0;
+
+/// The matrix converting modified XYZ -> LMS of Jzazbz
+const JZ_XYZ_TO_LMS: Matrix3 = [
+    [0.414_789_72, 0.579_999, 0.014_648],
+    [-0.201_51, 1.120_649, 0.053_100_8],
+    [-0.016_600_8, 0.2648, 0.668_479_9],
+];
+
+/// The matrix converting non-linear LMS -> Iz, az, bz of Jzazbz
+const JZ_LMS_TO_IAB: Matrix3 = [
+    [0.5, 0.5, 0.0],
+    [3.524, -4.066_708, 0.542_708],
+    [0.199_076, 1.096_799, -1.295_875],
+];
+
+/// The non-linear function of CIELAB
+fn lab_f(t: f64) -> f64 {
+    if t > LAB_EPSILON {
+        t.cbrt()
+    } else {
+        (LAB_KAPPA * t + 16.0) / 116.0
+    }
+}
+
+/// The PQ like non-linear function of Jzazbz
+fn jz_pq(value: f64) -> f64 {
+    let power = (value.abs() / PQ_PEAK_LUMINANCE).powf(JZ_N);
+    ((JZ_C1 + JZ_C2 * power) / (1.0 + JZ_C3 * power)).powf(JZ_P) * value.signum()
+}
+
+/// The inverse of [`jz_pq`]
+fn jz_pq_inverse(value: f64) -> f64 {
+    let power = value.abs().powf(1.0 / JZ_P);
+    let linear = ((JZ_C1 - power) / (JZ_C3 * power - JZ_C2)).max(0.0);
+    linear.powf(1.0 / JZ_N) * PQ_PEAK_LUMINANCE * value.signum()
+}
+
+/// Returns the inverse of [`REC2020_TO_ICTCP_LMS`]
+fn ictcp_lms_to_rec2020() -> &'static Matrix3 {
+    static MATRIX: OnceLock<Matrix3> = OnceLock::new();
+    MATRIX.get_or_init(|| matrix::inverse(&REC2020_TO_ICTCP_LMS).expect("matrix is invertible"))
+}
+
+/// Returns the inverse of [`ICTCP_PQ_LMS_TO_ICTCP`]
+fn ictcp_to_pq_lms() -> &'static Matrix3 {
+    static MATRIX: OnceLock<Matrix3> = OnceLock::new();
+    MATRIX.get_or_init(|| matrix::inverse(&ICTCP_PQ_LMS_TO_ICTCP).expect("matrix is invertible"))
+}
+
+/// Returns the inverse of [`ICTCP_HLG_LMS_TO_ICTCP`]
+fn ictcp_to_hlg_lms() -> &'static Matrix3 {
+    static MATRIX: OnceLock<Matrix3> = OnceLock::new();
+    MATRIX.get_or_init(|| matrix::inverse(&ICTCP_HLG_LMS_TO_ICTCP).expect("matrix is invertible"))
+}
+
+/// Returns the inverse of [`JZ_XYZ_TO_LMS`]
+fn jz_lms_to_xyz() -> &'static Matrix3 {
+    static MATRIX: OnceLock<Matrix3> = OnceLock::new();
+    MATRIX.get_or_init(|| matrix::inverse(&JZ_XYZ_TO_LMS).expect("matrix is invertible"))
+}
+
+/// Returns the inverse of [`JZ_LMS_TO_IAB`]
+fn jz_iab_to_lms() -> &'static Matrix3 {
+    static MATRIX: OnceLock<Matrix3> = OnceLock::new();
+    MATRIX.get_or_init(|| matrix::inverse(&JZ_LMS_TO_IAB).expect("matrix is invertible"))
+}
+
+/// Returns the inverse of [`XYZ_TO_LMS`]
+fn lms_to_xyz() -> &'static Matrix3 {
+    static MATRIX: OnceLock<Matrix3> = OnceLock::new();
+    MATRIX.get_or_init(|| matrix::inverse(&XYZ_TO_LMS).expect("matrix is invertible"))
+}
+
+/// Returns the inverse of [`LMS_TO_OKLAB`]
+fn oklab_to_lms() -> &'static Matrix3 {
+    static MATRIX: OnceLock<Matrix3> = OnceLock::new();
+    MATRIX.get_or_init(|| matrix::inverse(&LMS_TO_OKLAB).expect("matrix is invertible"))
+}
+
 /// Converts the given [`RGB24`] -> [`RGB48`]
 pub fn rgb24_to_rgb48(rgb: &RGB24) -> RGB48 {
     const FACTOR: u16 = RGB48::MAX / RGB24::MAX as u16;
@@ -83,38 +505,21 @@ pub fn rgb48_to_rgb24(rgb: &RGB48) -> RGB24 {
 
 #[cfg(test)]
 mod tests {
-    use crate::converter::{hsv_to_rgb, rgb24_to_rgb48, rgb48_to_rgb24, rgb_to_hsv};
+    use crate::approx::{assert_approx_eq, Tolerance};
+    use crate::converter::{
+        from_polar, hsv_to_rgb, ictcp_hlg_to_rec2020, ictcp_to_rec2020, ictcp_to_xyz,
+        jzazbz_to_xyz, lab_to_xyz, oklab_to_xyz, rec2020_to_ictcp, rec2020_to_ictcp_hlg,
+        rgb24_to_rgb48, rgb48_to_rgb24, rgb_to_hsv, srgb_decode, srgb_encode, to_polar,
+        xyz_to_ictcp, xyz_to_jzazbz, xyz_to_lab, xyz_to_oklab,
+    };
     use crate::models::hsv::{HSVColor, HSV};
+    use crate::models::rgb::linear::LinearRgb;
     use crate::models::rgb::rgb24::RGB24;
     use crate::models::rgb::rgb48::RGB48;
     use crate::models::rgb::RGBColor;
+    use crate::models::xyz::XYZ;
     use crate::presets::X11Color;
-    use std::fmt::Debug;
-    use strum::IntoEnumIterator;
-
-    fn assert_approx_equal_hsv(a: &HSV, b: &HSV) {
-        const EPSILON: f64 = 0.02;
-
-        if (a.h() - b.h()).abs() / HSV::H_MAX >= EPSILON
-            || (a.s() - b.s()).abs() / HSV::S_MAX >= EPSILON
-            || (a.v() - b.v()).abs() / HSV::V_MAX >= EPSILON
-        {
-            panic!("{:?} !~ {:?}", a, b);
-        }
-    }
-
-    fn assert_approx_equal_rgb<T>(a: &T, b: &T)
-    where
-        T: RGBColor<u8> + Debug,
-    {
-        const EPSILON: i32 = 4;
-        if (a.r() as i32 - b.r() as i32).abs() >= EPSILON
-            || (a.g() as i32 - b.g() as i32).abs() >= EPSILON
-            || (a.b() as i32 - b.b() as i32).abs() >= EPSILON
-        {
-            panic!("{:?} !~ {:?}", a, b);
-        }
-    }
+    use crate::space::{pq_encode_nits, RgbSpace, TransferFunction};
 
     #[test]
     fn rgb_to_hsv_rgb24() {
@@ -127,15 +532,23 @@ mod tests {
 
     #[test]
     fn rgb_to_hsv_x11() {
-        for color in X11Color::iter() {
-            assert_approx_equal_hsv(&color.to_hsv(), &rgb_to_hsv(&color.to_rgb::<RGB48, u16>()));
+        for color in X11Color::all() {
+            assert_approx_eq(
+                &color.to_hsv(),
+                &rgb_to_hsv(&color.to_rgb::<RGB48, u16>()),
+                Tolerance::Absolute(0.02),
+            );
         }
     }
 
     #[test]
     fn hsv_to_rgb_x11() {
-        for color in X11Color::iter() {
-            assert_approx_equal_rgb(&color.to_rgb(), &hsv_to_rgb::<RGB24, u8>(&color.to_hsv()));
+        for color in X11Color::all() {
+            assert_approx_eq(
+                &color.to_rgb(),
+                &hsv_to_rgb::<RGB24, u8>(&color.to_hsv()),
+                Tolerance::Absolute(4.0 / 255.0),
+            );
         }
     }
 
@@ -171,6 +584,149 @@ mod tests {
         assert_eq!(RGB48::BLUE, hsv_to_rgb(&HSV::BLUE));
     }
 
+    #[test]
+    fn srgb_encode_decode() {
+        assert_eq!(0.0, srgb_decode(0.0));
+        assert_eq!(1.0, srgb_decode(1.0));
+        assert!((1.0 - srgb_encode(1.0)).abs() < 1e-12);
+        assert!((srgb_decode(0.5) - 0.214_041_140_5).abs() < 1e-9);
+        assert!((srgb_encode(0.5) - 0.735_356_983_1).abs() < 1e-9);
+        assert_eq!(-srgb_decode(0.5), srgb_decode(-0.5));
+
+        for step in 0..=1000 {
+            let value = step as f64 / 1000.0;
+            assert!((value - srgb_encode(srgb_decode(value))).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn lab_round_trip() {
+        for white in [(0.3127, 0.3290), (0.3457, 0.3585)] {
+            for xyz in [XYZ::new(0.2, 0.3, 0.4), XYZ::new(0.001, 0.002, 0.001)] {
+                let back = lab_to_xyz(xyz_to_lab(&xyz, white), white);
+                assert!((xyz.x() - back.x()).abs() < 1e-12);
+                assert!((xyz.y() - back.y()).abs() < 1e-12);
+                assert!((xyz.z() - back.z()).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn xyz_to_oklab_() {
+        // reference values of https://bottosson.github.io/posts/oklab/
+        let expected = [
+            ((0.950, 1.000, 1.089), (1.000, 0.000, 0.000)),
+            ((1.000, 0.000, 0.000), (0.450, 1.236, -0.019)),
+            ((0.000, 1.000, 0.000), (0.922, -0.671, 0.263)),
+            ((0.000, 0.000, 1.000), (0.153, -1.415, -0.449)),
+        ];
+        for (xyz, (l, a, b)) in expected {
+            let oklab = xyz_to_oklab(&XYZ::from(xyz));
+            assert!((l - oklab.0).abs() < 2e-3, "{:?}", oklab);
+            assert!((a - oklab.1).abs() < 2e-3, "{:?}", oklab);
+            assert!((b - oklab.2).abs() < 2e-3, "{:?}", oklab);
+        }
+
+        let red = xyz_to_oklab(&RgbSpace::srgb().to_xyz(&RGB24::RED));
+        assert!((red.0 - 0.627_955).abs() < 1e-5);
+        assert!((red.1 - 0.224_863).abs() < 1e-5);
+        assert!((red.2 - 0.125_846).abs() < 1e-5);
+    }
+
+    #[test]
+    fn oklab_round_trip() {
+        let xyz = XYZ::new(0.2, 0.3, 0.4);
+        let back = oklab_to_xyz(xyz_to_oklab(&xyz));
+        assert!((xyz.x() - back.x()).abs() < 1e-12);
+        assert!((xyz.y() - back.y()).abs() < 1e-12);
+        assert!((xyz.z() - back.z()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn xyz_to_ictcp_() {
+        // achromatic colors have no chroma, intensity is the PQ signal of the luminance
+        for nits in [0.1, 100.0, 1000.0] {
+            let (i, ct, cp) = xyz_to_ictcp(&XYZ::from_xyy(0.3127, 0.3290, nits));
+            assert!((i - pq_encode_nits(nits)).abs() < 1e-12);
+            assert!(ct.abs() < 1e-12 && cp.abs() < 1e-12);
+        }
+
+        let linear = LinearRgb::new(100.0, 0.0, 0.0);
+        let (i, ct, cp) = xyz_to_ictcp(&RgbSpace::rec2020().linear_to_xyz(&linear));
+        let (l, m, s) = (
+            pq_encode_nits(1688.0 / 40.96),
+            pq_encode_nits(683.0 / 40.96),
+            pq_encode_nits(99.0 / 40.96),
+        );
+        assert!((i - (l + m) / 2.0).abs() < 1e-12);
+        assert!((ct - (6610.0 * l - 13613.0 * m + 7003.0 * s) / 4096.0).abs() < 1e-12);
+        assert!((cp - (17933.0 * l - 17390.0 * m - 543.0 * s) / 4096.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn xyz_to_jzazbz_() {
+        // D65 white of 203 cd/m² is almost achromatic
+        let (jz, az, bz) = xyz_to_jzazbz(&XYZ::from_xyy(0.3127, 0.3290, 203.0));
+        assert!((jz - 0.222_07).abs() < 1e-4);
+        assert!(az.abs() < 5e-4 && bz.abs() < 5e-4);
+        let brighter = xyz_to_jzazbz(&XYZ::from_xyy(0.3127, 0.3290, 1000.0));
+        assert!(brighter.0 > jz);
+        assert!(xyz_to_jzazbz(&XYZ::default()).0.abs() < 1e-12);
+    }
+
+    #[test]
+    fn ictcp_round_trip() {
+        for rgb in [(100.0, 20.0, 3.0), (0.5, 4000.0, 0.0), (0.0, 0.0, 0.0)] {
+            let back = ictcp_to_rec2020(rec2020_to_ictcp(rgb));
+            assert!((rgb.0 - back.0).abs() < 1e-9 * rgb.0.max(1.0));
+            assert!((rgb.1 - back.1).abs() < 1e-9 * rgb.1.max(1.0));
+            assert!((rgb.2 - back.2).abs() < 1e-9 * rgb.2.max(1.0));
+        }
+
+        let xyz = XYZ::new(50.0, 60.0, 20.0);
+        let back = ictcp_to_xyz(xyz_to_ictcp(&xyz));
+        assert!((xyz.x() - back.x()).abs() < 1e-9);
+        assert!((xyz.y() - back.y()).abs() < 1e-9);
+        assert!((xyz.z() - back.z()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn ictcp_hlg() {
+        // achromatic colors have no chroma, intensity is the HLG signal
+        let (i, ct, cp) = rec2020_to_ictcp_hlg((0.5, 0.5, 0.5));
+        assert!((i - TransferFunction::Hlg.encode(0.5)).abs() < 1e-12);
+        assert!(ct.abs() < 1e-12 && cp.abs() < 1e-12);
+
+        let rgb = (0.8, 0.1, 0.02);
+        let back = ictcp_hlg_to_rec2020(rec2020_to_ictcp_hlg(rgb));
+        assert!((rgb.0 - back.0).abs() < 1e-12);
+        assert!((rgb.1 - back.1).abs() < 1e-12);
+        assert!((rgb.2 - back.2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn jzazbz_round_trip() {
+        for xyz in [
+            XYZ::new(50.0, 60.0, 20.0),
+            XYZ::new(0.01, 0.02, 0.5),
+            XYZ::new(3000.0, 2000.0, 1000.0),
+        ] {
+            let back = jzazbz_to_xyz(xyz_to_jzazbz(&xyz));
+            assert!((xyz.x() - back.x()).abs() < 1e-9 * xyz.x().max(1.0));
+            assert!((xyz.y() - back.y()).abs() < 1e-9 * xyz.y().max(1.0));
+            assert!((xyz.z() - back.z()).abs() < 1e-9 * xyz.z().max(1.0));
+        }
+    }
+
+    #[test]
+    fn polar() {
+        let (c, h) = to_polar(0.0, -2.0);
+        assert_eq!(2.0, c);
+        assert_eq!(270.0, h);
+        let (a, b) = from_polar(c, h);
+        assert!(a.abs() < 1e-12 && (b + 2.0).abs() < 1e-12);
+    }
+
     #[test]
     fn rgb24_to_rgb48_() {
         assert_eq!(RGB48::WHITE, rgb24_to_rgb48(&RGB24::WHITE));
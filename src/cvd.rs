@@ -0,0 +1,762 @@
+use crate::converter;
+use crate::difference::DeltaE;
+use crate::matrix::{self, Matrix3};
+use crate::models::rgb::linear::LinearRgb;
+use crate::models::rgb::RGBColor;
+use crate::models::xyz::XYZ;
+use crate::space::RgbSpace;
+use std::sync::OnceLock;
+
+/// Linear sRGB -> LMS cone responses (Smith & Pokorny) of Viénot et al. 1999
+const VIENOT_RGB_TO_LMS: Matrix3 = [
+    [17.8824, 43.5161, 4.11935],
+    [3.45565, 27.1554, 3.86714],
+    [0.029_956_6, 0.184_309, 1.46709],
+];
+
+/// Simulation of protanomaly in linear sRGB of Machado et al. 2009 (severity 0.0, 0.1, ... 1.0)
+#[rustfmt::skip]
+const MACHADO_PROTAN: [Matrix3; 11] = [
+    matrix::IDENTITY,
+    [[0.856_167, 0.182_038, -0.038_205], [0.029_342, 0.955_115, 0.015_544], [-0.002_880, -0.001_563, 1.004_443]],
+    [[0.734_766, 0.334_872, -0.069_637], [0.051_840, 0.919_198, 0.028_963], [-0.004_928, -0.004_209, 1.009_137]],
+    [[0.630_323, 0.465_641, -0.095_964], [0.069_181, 0.890_046, 0.040_773], [-0.006_308, -0.007_724, 1.014_032]],
+    [[0.539_009, 0.579_343, -0.118_352], [0.082_546, 0.866_121, 0.051_332], [-0.007_136, -0.011_959, 1.019_095]],
+    [[0.458_064, 0.679_578, -0.137_642], [0.092_785, 0.846_313, 0.060_902], [-0.007_494, -0.016_807, 1.024_301]],
+    [[0.385_450, 0.769_005, -0.154_455], [0.100_526, 0.829_802, 0.069_673], [-0.007_442, -0.022_190, 1.029_632]],
+    [[0.319_627, 0.849_633, -0.169_261], [0.106_241, 0.815_969, 0.077_790], [-0.007_025, -0.028_051, 1.035_076]],
+    [[0.259_411, 0.923_008, -0.182_420], [0.110_296, 0.804_340, 0.085_364], [-0.006_276, -0.034_346, 1.040_622]],
+    [[0.203_876, 0.990_338, -0.194_214], [0.112_975, 0.794_542, 0.092_483], [-0.005_222, -0.041_043, 1.046_265]],
+    [[0.152_286, 1.052_583, -0.204_868], [0.114_503, 0.786_281, 0.099_216], [-0.003_882, -0.048_116, 1.051_998]],
+];
+
+/// Simulation of deuteranomaly in linear sRGB of Machado et al. 2009 (severity 0.0, 0.1, ... 1.0)
+#[rustfmt::skip]
+const MACHADO_DEUTAN: [Matrix3; 11] = [
+    matrix::IDENTITY,
+    [[0.866_435, 0.177_704, -0.044_139], [0.049_567, 0.939_063, 0.011_370], [-0.003_453, 0.007_233, 0.996_220]],
+    [[0.760_729, 0.319_078, -0.079_807], [0.090_568, 0.889_315, 0.020_117], [-0.006_027, 0.013_325, 0.992_702]],
+    [[0.675_425, 0.433_850, -0.109_275], [0.125_303, 0.847_755, 0.026_942], [-0.007_950, 0.018_572, 0.989_378]],
+    [[0.605_511, 0.528_560, -0.134_071], [0.155_318, 0.812_366, 0.032_316], [-0.009_376, 0.023_176, 0.986_200]],
+    [[0.547_494, 0.607_765, -0.155_259], [0.181_692, 0.781_742, 0.036_566], [-0.010_410, 0.027_275, 0.983_136]],
+    [[0.498_864, 0.674_741, -0.173_604], [0.205_199, 0.754_872, 0.039_929], [-0.011_131, 0.030_969, 0.980_162]],
+    [[0.457_771, 0.731_899, -0.189_670], [0.226_409, 0.731_012, 0.042_579], [-0.011_595, 0.034_333, 0.977_261]],
+    [[0.422_823, 0.781_057, -0.203_881], [0.245_752, 0.709_602, 0.044_646], [-0.011_843, 0.037_423, 0.974_421]],
+    [[0.392_952, 0.823_610, -0.216_562], [0.263_559, 0.690_210, 0.046_232], [-0.011_910, 0.040_281, 0.971_630]],
+    [[0.367_322, 0.860_646, -0.227_968], [0.280_085, 0.672_501, 0.047_413], [-0.011_820, 0.042_940, 0.968_881]],
+];
+
+/// Simulation of tritanomaly in linear sRGB of Machado et al. 2009 (severity 0.0, 0.1, ... 1.0)
+#[rustfmt::skip]
+const MACHADO_TRITAN: [Matrix3; 11] = [
+    matrix::IDENTITY,
+    [[0.926_670, 0.092_514, -0.019_184], [0.021_191, 0.964_503, 0.014_306], [0.008_437, 0.054_813, 0.936_750]],
+    [[0.895_720, 0.133_330, -0.029_050], [0.029_997, 0.945_400, 0.024_603], [0.013_027, 0.104_707, 0.882_266]],
+    [[0.905_871, 0.127_791, -0.033_662], [0.026_856, 0.941_251, 0.031_893], [0.013_410, 0.148_296, 0.838_294]],
+    [[0.948_035, 0.089_490, -0.037_526], [0.014_364, 0.946_792, 0.038_844], [0.010_853, 0.193_991, 0.795_156]],
+    [[1.017_277, 0.027_029, -0.044_306], [-0.006_113, 0.958_479, 0.047_634], [0.006_379, 0.248_708, 0.744_913]],
+    [[1.104_996, -0.046_633, -0.058_363], [-0.032_137, 0.971_635, 0.060_503], [0.001_336, 0.317_922, 0.680_742]],
+    [[1.193_214, -0.109_812, -0.083_402], [-0.058_496, 0.979_410, 0.079_086], [-0.002_346, 0.403_492, 0.598_854]],
+    [[1.257_728, -0.139_648, -0.118_081], [-0.078_003, 0.975_409, 0.102_594], [-0.003_316, 0.501_214, 0.502_102]],
+    [[1.278_864, -0.125_333, -0.153_531], [-0.084_748, 0.957_674, 0.127_074], [-0.000_989, 0.601_151, 0.399_838]],
+    [[1.255_528, -0.076_749, -0.178_779], [-0.078_411, 0.930_809, 0.147_602], [0.004_733, 0.691_367, 0.303_900]],
+];
+/// Daltonization: shifts the error of protans and deutans to green and blue (Fidaner et al.)
+const SHIFT_RED_GREEN: Matrix3 = [[0.0, 0.0, 0.0], [0.7, 1.0, 0.0], [0.7, 0.0, 1.0]];
+
+/// Daltonization: shifts the error of tritans to red and green
+const SHIFT_BLUE_YELLOW: Matrix3 = [[1.0, 0.0, 0.7], [0.0, 1.0, 0.7], [0.0, 0.0, 0.0]];
+
+/// The CIE 1931 chromaticities of the spectral anchors of Brettel et al. 1997
+const ANCHOR_475: (f64, f64) = (0.1096, 0.0868);
+const ANCHOR_485: (f64, f64) = (0.0687, 0.2007);
+const ANCHOR_575: (f64, f64) = (0.4788, 0.5202);
+const ANCHOR_660: (f64, f64) = (0.7300, 0.2700);
+
+/// A type of color vision deficiency, named after the affected cone
+///
+/// A severity of `1.0` is the lack of the cone (dichromacy, e.g. protanopia), lower severities
+/// are a shifted cone sensitivity (anomalous trichromacy, e.g. protanomaly).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Deficiency {
+    /// Affected L cones (red) - protanopia and protanomaly
+    Protan,
+    /// Affected M cones (green) - deuteranopia and deuteranomaly
+    Deutan,
+    /// Affected S cones (blue) - tritanopia and tritanomaly
+    Tritan,
+}
+
+impl Deficiency {
+    /// All deficiencies
+    pub const ALL: [Deficiency; 3] = [Deficiency::Protan, Deficiency::Deutan, Deficiency::Tritan];
+
+    /// Returns the index of the affected cone in LMS
+    fn cone(self) -> usize {
+        match self {
+            Deficiency::Protan => 0,
+            Deficiency::Deutan => 1,
+            Deficiency::Tritan => 2,
+        }
+    }
+}
+
+/// The model of a color vision deficiency simulation
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum CvdModel {
+    /// Brettel, Viénot & Mollon 1997: projection onto two half-planes in LMS
+    ///
+    /// The most accurate model for dichromacy, especially for tritanopia.
+    Brettel,
+    /// Viénot, Brettel & Mollon 1999: projection onto a single plane in LMS
+    ///
+    /// A single matrix, accurate for protanopia and deuteranopia.
+    Vienot,
+    /// Machado, Oliveira & Fernandes 2009: physiologically based matrices
+    #[default]
+    Machado,
+}
+
+/// A simulation of a color vision deficiency
+///
+/// All calculations are done in linear sRGB. [`CvdModel::Machado`] models anomalous trichromacy
+/// with the published matrices in steps of `0.1` of severity, interpolated linearly in between.
+/// [`CvdModel::Brettel`] and [`CvdModel::Vienot`] only model dichromacy, so severities below `1.0`
+/// interpolate linearly between the original and the dichromatic simulation.
+///
+/// # Example
+/// ```
+/// use colorful::cvd::{CvdModel, CvdSimulation, Deficiency};
+/// use colorful::{RGBColor, RGB24};
+///
+/// let protanopia = CvdSimulation::new(Deficiency::Protan, CvdModel::Vienot, 1.0);
+/// // red is seen as a dark yellow
+/// let red = protanopia.simulate(&RGB24::RED);
+/// assert_eq!(RGB24::from_rgb(94, 94, 13), red);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CvdSimulation {
+    deficiency: Deficiency,
+    model: CvdModel,
+    severity: f64,
+}
+
+impl CvdSimulation {
+    /// Creates a new `CvdSimulation`
+    ///
+    /// # Parameters
+    /// - `deficiency`: the affected cone
+    /// - `model`: the model of the simulation
+    /// - `severity`: `0.0` (normal vision) - `1.0` (dichromacy)
+    ///
+    /// # Panics
+    /// If `severity` is not within `0.0 - 1.0`
+    pub fn new(deficiency: Deficiency, model: CvdModel, severity: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&severity),
+            "severity must be within 0.0 - 1.0: {}",
+            severity
+        );
+        CvdSimulation {
+            deficiency,
+            model,
+            severity,
+        }
+    }
+
+    /// Returns the simulated deficiency
+    pub fn deficiency(&self) -> Deficiency {
+        self.deficiency
+    }
+
+    /// Returns the model of the simulation
+    pub fn model(&self) -> CvdModel {
+        self.model
+    }
+
+    /// Returns the severity (`0.0 - 1.0`)
+    pub fn severity(&self) -> f64 {
+        self.severity
+    }
+
+    /// Simulates the given linear sRGB color
+    ///
+    /// The result may be out of gamut.
+    pub fn simulate_linear(&self, color: &LinearRgb) -> LinearRgb {
+        let rgb = color.as_tuple();
+        let dichromatic = match self.model {
+            CvdModel::Brettel => brettel(self.deficiency).apply(rgb),
+            CvdModel::Vienot => matrix::apply(vienot(self.deficiency), rgb),
+            CvdModel::Machado => {
+                let simulation = machado(self.deficiency, self.severity);
+                return LinearRgb::from(matrix::apply(&simulation, rgb));
+            }
+        };
+        color.mix(&LinearRgb::from(dichromatic), self.severity)
+    }
+
+    /// Simulates the given sRGB color
+    ///
+    /// Out of gamut results are clamped.
+    pub fn simulate<S: RGBColor<U>, U>(&self, color: &S) -> S {
+        converter::linear_to_rgb(&self.simulate_linear(&converter::rgb_to_linear(color)))
+    }
+
+    /// Simulates all sRGB colors (e.g. the pixels of an image) in place
+    pub fn simulate_slice<S: RGBColor<U>, U>(&self, colors: &mut [S]) {
+        for color in colors.iter_mut() {
+            *color = self.simulate(color);
+        }
+    }
+
+    /// Daltonizes the given linear sRGB color, so it is easier to distinguish with the deficiency
+    ///
+    /// The difference to the simulated color, which is lost with the deficiency, is shifted to
+    /// channels that remain visible. Neutral colors are unchanged.
+    /// The result may be out of gamut.
+    pub fn daltonize_linear(&self, color: &LinearRgb) -> LinearRgb {
+        let simulated = self.simulate_linear(color);
+        let error = (
+            color.r() - simulated.r(),
+            color.g() - simulated.g(),
+            color.b() - simulated.b(),
+        );
+        let shift = match self.deficiency {
+            Deficiency::Protan | Deficiency::Deutan => &SHIFT_RED_GREEN,
+            Deficiency::Tritan => &SHIFT_BLUE_YELLOW,
+        };
+        let (r, g, b) = matrix::apply(shift, error);
+        LinearRgb::new(color.r() + r, color.g() + g, color.b() + b)
+    }
+
+    /// Daltonizes the given sRGB color
+    ///
+    /// Out of gamut results are clamped.
+    ///
+    /// # Example
+    /// ```
+    /// use colorful::cvd::{CvdModel, CvdSimulation, Deficiency};
+    /// use colorful::{RGBColor, RGB24};
+    ///
+    /// let deuteranopia = CvdSimulation::new(Deficiency::Deutan, CvdModel::Machado, 1.0);
+    /// assert_eq!(RGB24::WHITE, deuteranopia.daltonize(&RGB24::WHITE));
+    /// assert_ne!(RGB24::RED, deuteranopia.daltonize(&RGB24::RED));
+    /// ```
+    pub fn daltonize<S: RGBColor<U>, U>(&self, color: &S) -> S {
+        converter::linear_to_rgb(&self.daltonize_linear(&converter::rgb_to_linear(color)))
+    }
+
+    /// Daltonizes all sRGB colors (e.g. the pixels of an image) in place
+    pub fn daltonize_slice<S: RGBColor<U>, U>(&self, colors: &mut [S]) {
+        for color in colors.iter_mut() {
+            *color = self.daltonize(color);
+        }
+    }
+}
+
+/// A pair of colors which becomes indistinguishable with a deficiency
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Confusion {
+    deficiency: Deficiency,
+    first: usize,
+    second: usize,
+    difference: f64,
+}
+
+impl Confusion {
+    /// Returns the deficiency
+    pub fn deficiency(&self) -> Deficiency {
+        self.deficiency
+    }
+
+    /// Returns the indices of the two colors (`first < second`)
+    pub fn indices(&self) -> (usize, usize) {
+        (self.first, self.second)
+    }
+
+    /// Returns the color difference of the simulated colors
+    pub fn difference(&self) -> f64 {
+        self.difference
+    }
+}
+
+/// Finds the pairs of colors which become indistinguishable with a deficiency
+///
+/// Every [`Deficiency`] is simulated as dichromacy. Pairs that are already indistinguishable
+/// with normal vision are not reported.
+///
+/// # Parameters
+/// - `colors`: the sRGB colors (e.g. a palette)
+/// - `model`: the model of the simulation
+/// - `metric`: the metric of the color difference
+/// - `threshold`: the color difference below which colors are indistinguishable
+///
+/// # Returns
+/// The confusions ordered by deficiency and indices
+///
+/// # Example
+/// ```
+/// use colorful::cvd::{find_confusions, CvdModel, Deficiency};
+/// use colorful::difference::DeltaE;
+/// use colorful::{RGBColor, RGB24};
+///
+/// let palette = [
+///     RGB24::from_rgb(200, 60, 40),
+///     RGB24::from_rgb(90, 110, 40),
+///     RGB24::from_rgb(40, 90, 200),
+/// ];
+/// let confusions = find_confusions(&palette, CvdModel::Machado, DeltaE::Ciede2000, 10.0);
+/// assert!(confusions
+///     .iter()
+///     .any(|c| c.deficiency() == Deficiency::Deutan && c.indices() == (0, 1)));
+/// ```
+pub fn find_confusions<S: RGBColor<U>, U>(
+    colors: &[S],
+    model: CvdModel,
+    metric: DeltaE,
+    threshold: f64,
+) -> Vec<Confusion> {
+    let linear: Vec<LinearRgb> = colors.iter().map(converter::rgb_to_linear).collect();
+    let mut confusions = Vec::new();
+    for deficiency in Deficiency::ALL {
+        let simulation = CvdSimulation::new(deficiency, model, 1.0);
+        let simulated: Vec<LinearRgb> = linear
+            .iter()
+            .map(|color| clamp(&simulation.simulate_linear(color)))
+            .collect();
+        for first in 0..linear.len() {
+            for second in first + 1..linear.len() {
+                if metric.difference(&linear[first], &linear[second]) < threshold {
+                    continue;
+                }
+                let difference = metric.difference(&simulated[first], &simulated[second]);
+                if difference < threshold {
+                    confusions.push(Confusion {
+                        deficiency,
+                        first,
+                        second,
+                        difference,
+                    });
+                }
+            }
+        }
+    }
+    confusions
+}
+
+/// Clamps the given color to the sRGB gamut
+fn clamp(color: &LinearRgb) -> LinearRgb {
+    LinearRgb::new(
+        color.r().clamp(0.0, 1.0),
+        color.g().clamp(0.0, 1.0),
+        color.b().clamp(0.0, 1.0),
+    )
+}
+
+/// The two half-planes of the Brettel model in linear sRGB
+struct Brettel {
+    /// The normal of the plane separating the half-planes
+    separation: (f64, f64, f64),
+    /// The projection of colors on the positive side of the separation
+    positive: Matrix3,
+    /// The projection of colors on the negative side of the separation
+    negative: Matrix3,
+}
+
+impl Brettel {
+    /// Projects the given linear sRGB color
+    fn apply(&self, rgb: (f64, f64, f64)) -> (f64, f64, f64) {
+        if dot(rgb, self.separation) >= 0.0 {
+            matrix::apply(&self.positive, rgb)
+        } else {
+            matrix::apply(&self.negative, rgb)
+        }
+    }
+}
+
+/// Returns the inverse of [`VIENOT_RGB_TO_LMS`]
+fn vienot_lms_to_rgb() -> &'static Matrix3 {
+    static MATRIX: OnceLock<Matrix3> = OnceLock::new();
+    MATRIX.get_or_init(|| matrix::inverse(&VIENOT_RGB_TO_LMS).expect("matrix is invertible"))
+}
+
+/// Returns the simulation matrix of Machado et al. for the given deficiency and severity
+///
+/// Interpolates linearly between the published matrices.
+fn machado(deficiency: Deficiency, severity: f64) -> Matrix3 {
+    let matrices = match deficiency {
+        Deficiency::Protan => &MACHADO_PROTAN,
+        Deficiency::Deutan => &MACHADO_DEUTAN,
+        Deficiency::Tritan => &MACHADO_TRITAN,
+    };
+    let position = severity * (matrices.len() - 1) as f64;
+    let lower = (position.floor() as usize).min(matrices.len() - 2);
+    let t = position - lower as f64;
+
+    let (first, second) = (&matrices[lower], &matrices[lower + 1]);
+    let mut result = [[0.0; 3]; 3];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = first[i][j] + (second[i][j] - first[i][j]) * t;
+        }
+    }
+    result
+}
+
+/// Returns the simulation matrix of Viénot et al. in linear sRGB for the given deficiency
+///
+/// The plane contains black, white and blue (protan, deutan) or red (tritan).
+fn vienot(deficiency: Deficiency) -> &'static Matrix3 {
+    static MATRICES: OnceLock<[Matrix3; 3]> = OnceLock::new();
+    let matrices = MATRICES.get_or_init(|| {
+        let white = matrix::apply(&VIENOT_RGB_TO_LMS, (1.0, 1.0, 1.0));
+        Deficiency::ALL.map(|deficiency| {
+            let anchor = match deficiency {
+                Deficiency::Protan | Deficiency::Deutan => (0.0, 0.0, 1.0),
+                Deficiency::Tritan => (1.0, 0.0, 0.0),
+            };
+            let anchor = matrix::apply(&VIENOT_RGB_TO_LMS, anchor);
+            projection_rgb(deficiency, cross(white, anchor))
+        })
+    });
+    &matrices[deficiency.cone()]
+}
+
+/// Returns the half-planes of Brettel et al. in linear sRGB for the given deficiency
+fn brettel(deficiency: Deficiency) -> &'static Brettel {
+    static PLANES: OnceLock<[Brettel; 3]> = OnceLock::new();
+    let planes = PLANES.get_or_init(|| {
+        let white = matrix::apply(&VIENOT_RGB_TO_LMS, (1.0, 1.0, 1.0));
+        Deficiency::ALL.map(|deficiency| {
+            let (first, second) = match deficiency {
+                Deficiency::Protan | Deficiency::Deutan => (ANCHOR_475, ANCHOR_575),
+                Deficiency::Tritan => (ANCHOR_485, ANCHOR_660),
+            };
+            let (first, second) = (spectral_lms(first), spectral_lms(second));
+
+            // the plane of the neutral axis and the affected cone separates the half-planes
+            let mut cone = (0.0, 0.0, 0.0);
+            match deficiency.cone() {
+                0 => cone.0 = 1.0,
+                1 => cone.1 = 1.0,
+                _ => cone.2 = 1.0,
+            }
+            let separation = cross(white, cone);
+            let (positive, negative) = if dot(first, separation) >= 0.0 {
+                (first, second)
+            } else {
+                (second, first)
+            };
+
+            Brettel {
+                separation: matrix::apply(&transpose(&VIENOT_RGB_TO_LMS), separation),
+                positive: projection_rgb(deficiency, cross(white, positive)),
+                negative: projection_rgb(deficiency, cross(white, negative)),
+            }
+        })
+    });
+    &planes[deficiency.cone()]
+}
+
+/// Returns the LMS cone responses of the monochromatic light with the given chromaticity
+fn spectral_lms(chromaticity: (f64, f64)) -> (f64, f64, f64) {
+    let xyz = XYZ::from_xyy(chromaticity.0, chromaticity.1, 1.0);
+    let linear = RgbSpace::srgb().xyz_to_linear(&xyz);
+    matrix::apply(&VIENOT_RGB_TO_LMS, linear.as_tuple())
+}
+
+/// Returns the projection in linear sRGB replacing the affected cone by the plane with the given normal
+fn projection_rgb(deficiency: Deficiency, normal: (f64, f64, f64)) -> Matrix3 {
+    let cone = deficiency.cone();
+    let normal = [normal.0, normal.1, normal.2];
+    let mut projection = matrix::IDENTITY;
+    for (i, value) in projection[cone].iter_mut().enumerate() {
+        *value = if i == cone {
+            0.0
+        } else {
+            -normal[i] / normal[cone]
+        };
+    }
+    matrix::multiply(
+        vienot_lms_to_rgb(),
+        &matrix::multiply(&projection, &VIENOT_RGB_TO_LMS),
+    )
+}
+
+/// Returns the transposed matrix
+fn transpose(m: &Matrix3) -> Matrix3 {
+    [
+        [m[0][0], m[1][0], m[2][0]],
+        [m[0][1], m[1][1], m[2][1]],
+        [m[0][2], m[1][2], m[2][2]],
+    ]
+}
+
+/// Returns the cross product of the two vectors
+fn cross(a: (f64, f64, f64), b: (f64, f64, f64)) -> (f64, f64, f64) {
+    (
+        a.1 * b.2 - a.2 * b.1,
+        a.2 * b.0 - a.0 * b.2,
+        a.0 * b.1 - a.1 * b.0,
+    )
+}
+
+/// Returns the dot product of the two vectors
+fn dot(a: (f64, f64, f64), b: (f64, f64, f64)) -> f64 {
+    a.0 * b.0 + a.1 * b.1 + a.2 * b.2
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::approx::{assert_approx_eq, Tolerance};
+    use crate::cvd::{find_confusions, CvdModel, CvdSimulation, Deficiency};
+    use crate::difference::{delta_e2000, DeltaE};
+    use crate::models::lab::Lab;
+    use crate::models::rgb::linear::LinearRgb;
+    use crate::models::rgb::RGBColor;
+    use crate::{RGB24, RGB48};
+
+    const MODELS: [CvdModel; 3] = [CvdModel::Brettel, CvdModel::Vienot, CvdModel::Machado];
+
+    #[test]
+    fn neutral_colors() {
+        for deficiency in Deficiency::ALL {
+            for model in MODELS {
+                let simulation = CvdSimulation::new(deficiency, model, 1.0);
+                for gray in [0.0, 0.2, 1.0] {
+                    let color = LinearRgb::new(gray, gray, gray);
+                    assert_approx_eq(
+                        &color,
+                        &simulation.simulate_linear(&color),
+                        Tolerance::Absolute(1e-5),
+                    );
+                }
+                assert_eq!(RGB24::WHITE, simulation.simulate(&RGB24::WHITE));
+                assert_eq!(RGB24::BLACK, simulation.simulate(&RGB24::BLACK));
+            }
+        }
+    }
+
+    #[test]
+    fn vienot() {
+        let red = LinearRgb::new(1.0, 0.0, 0.0);
+        let simulate = |deficiency| CvdSimulation::new(deficiency, CvdModel::Vienot, 1.0);
+        assert_approx_eq(
+            &LinearRgb::new(0.11238, 0.11238, 0.00401),
+            &simulate(Deficiency::Protan).simulate_linear(&red),
+            Tolerance::Absolute(1e-5),
+        );
+        assert_approx_eq(
+            &LinearRgb::new(0.29275, 0.29275, -0.02234),
+            &simulate(Deficiency::Deutan).simulate_linear(&red),
+            Tolerance::Absolute(1e-5),
+        );
+        assert_approx_eq(
+            &LinearRgb::new(0.14461, 0.85924, 0.85924),
+            &simulate(Deficiency::Tritan).simulate_linear(&LinearRgb::new(0.0, 1.0, 0.0)),
+            Tolerance::Absolute(1e-5),
+        );
+    }
+
+    #[test]
+    fn machado() {
+        let simulation = CvdSimulation::new(Deficiency::Deutan, CvdModel::Machado, 1.0);
+        assert_approx_eq(
+            &LinearRgb::new(0.367_322, 0.280_085, -0.011_820),
+            &simulation.simulate_linear(&LinearRgb::new(1.0, 0.0, 0.0)),
+            Tolerance::Absolute(1e-9),
+        );
+
+        // protanomaly at severity 0.5, the columns of the published matrix
+        let simulation = CvdSimulation::new(Deficiency::Protan, CvdModel::Machado, 0.5);
+        let columns = [
+            (0.458_064, 0.092_785, -0.007_494),
+            (0.679_578, 0.846_313, -0.016_807),
+            (-0.137_642, 0.060_902, 1.024_301),
+        ];
+        let units = [(1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 1.0)];
+        for (column, unit) in columns.iter().zip(units.iter()) {
+            let simulated = simulation.simulate_linear(&LinearRgb::from(*unit));
+            assert_approx_eq(
+                &LinearRgb::from(*column),
+                &simulated,
+                Tolerance::Absolute(1e-9),
+            );
+        }
+
+        // between the published steps the matrices are interpolated
+        let color = LinearRgb::new(0.8, 0.3, 0.1);
+        let simulate = |severity| {
+            CvdSimulation::new(Deficiency::Tritan, CvdModel::Machado, severity)
+                .simulate_linear(&color)
+        };
+        let between = simulate(0.4).mix(&simulate(0.5), 0.5);
+        assert_approx_eq(&between, &simulate(0.45), Tolerance::Absolute(1e-12));
+    }
+
+    #[test]
+    fn confusion_colors() {
+        // red / green for protans and deutans, blue / green for tritans
+        let pairs = [
+            (
+                Deficiency::Protan,
+                RGB24::from_rgb(200, 60, 40),
+                RGB24::from_rgb(90, 110, 40),
+            ),
+            (
+                Deficiency::Deutan,
+                RGB24::from_rgb(200, 60, 40),
+                RGB24::from_rgb(90, 110, 40),
+            ),
+            (
+                Deficiency::Tritan,
+                RGB24::from_rgb(40, 90, 200),
+                RGB24::from_rgb(40, 150, 130),
+            ),
+        ];
+        for (deficiency, a, b) in pairs {
+            let original = delta_e2000(&Lab::from_rgb(&a), &Lab::from_rgb(&b));
+            for model in MODELS {
+                let simulation = CvdSimulation::new(deficiency, model, 1.0);
+                let simulated = delta_e2000(
+                    &Lab::from_rgb(&simulation.simulate(&a)),
+                    &Lab::from_rgb(&simulation.simulate(&b)),
+                );
+                assert!(
+                    simulated < original / 2.0,
+                    "{:?} {:?}: {} -> {}",
+                    deficiency,
+                    model,
+                    original,
+                    simulated
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn severity() {
+        let color = LinearRgb::new(0.8, 0.3, 0.1);
+        for deficiency in Deficiency::ALL {
+            for model in MODELS {
+                let none = CvdSimulation::new(deficiency, model, 0.0);
+                assert_eq!(color, none.simulate_linear(&color));
+            }
+
+            // dichromatic models blend linearly with the original
+            for model in [CvdModel::Brettel, CvdModel::Vienot] {
+                let full = CvdSimulation::new(deficiency, model, 1.0).simulate_linear(&color);
+                let half = CvdSimulation::new(deficiency, model, 0.5).simulate_linear(&color);
+                assert_approx_eq(&color.mix(&full, 0.5), &half, Tolerance::Absolute(1e-12));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_severity() {
+        CvdSimulation::new(Deficiency::Protan, CvdModel::Machado, 1.5);
+    }
+
+    #[test]
+    fn slices() {
+        let simulation = CvdSimulation::new(Deficiency::Protan, CvdModel::Brettel, 0.7);
+        let colors = [
+            RGB48::RED,
+            RGB48::from_rgb(1000, 40000, 65535),
+            RGB48::WHITE,
+        ];
+        let mut simulated = colors;
+        simulation.simulate_slice(&mut simulated);
+        for (color, simulated) in colors.iter().zip(simulated.iter()) {
+            assert_eq!(simulation.simulate(color), *simulated);
+        }
+    }
+
+    #[test]
+    fn daltonize() {
+        let pairs = [
+            (
+                Deficiency::Protan,
+                RGB24::from_rgb(200, 60, 40),
+                RGB24::from_rgb(90, 110, 40),
+            ),
+            (
+                Deficiency::Deutan,
+                RGB24::from_rgb(200, 60, 40),
+                RGB24::from_rgb(90, 110, 40),
+            ),
+            (
+                Deficiency::Tritan,
+                RGB24::from_rgb(204, 0, 102),
+                RGB24::from_rgb(204, 51, 0),
+            ),
+        ];
+        for (deficiency, a, b) in pairs {
+            for model in MODELS {
+                let simulation = CvdSimulation::new(deficiency, model, 1.0);
+                let difference = |a: &RGB24, b: &RGB24| {
+                    delta_e2000(
+                        &Lab::from_rgb(&simulation.simulate(a)),
+                        &Lab::from_rgb(&simulation.simulate(b)),
+                    )
+                };
+                let original = difference(&a, &b);
+                let corrected = difference(&simulation.daltonize(&a), &simulation.daltonize(&b));
+                assert!(
+                    corrected > original * 1.5,
+                    "{:?} {:?}: {} -> {}",
+                    deficiency,
+                    model,
+                    original,
+                    corrected
+                );
+
+                let gray = LinearRgb::new(0.4, 0.4, 0.4);
+                assert_approx_eq(
+                    &gray,
+                    &simulation.daltonize_linear(&gray),
+                    Tolerance::Absolute(1e-5),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn daltonize_slice() {
+        let simulation = CvdSimulation::new(Deficiency::Tritan, CvdModel::Vienot, 0.6);
+        let colors = [RGB24::BLUE, RGB24::from_rgb(250, 200, 20), RGB24::BLACK];
+        let mut daltonized = colors;
+        simulation.daltonize_slice(&mut daltonized);
+        for (color, daltonized) in colors.iter().zip(daltonized.iter()) {
+            assert_eq!(simulation.daltonize(color), *daltonized);
+        }
+    }
+
+    #[test]
+    fn find_confusions_() {
+        let palette = [
+            RGB24::from_rgb(200, 60, 40),
+            RGB24::from_rgb(90, 110, 40),
+            RGB24::from_rgb(204, 0, 102),
+            RGB24::from_rgb(204, 51, 0),
+            RGB24::from_rgb(201, 60, 40),
+        ];
+        for model in MODELS {
+            let confusions = find_confusions(&palette, model, DeltaE::Ciede2000, 10.0);
+            let contains = |deficiency, indices| {
+                confusions
+                    .iter()
+                    .any(|c| c.deficiency() == deficiency && c.indices() == indices)
+            };
+            assert!(contains(Deficiency::Protan, (0, 1)), "{:?}", model);
+            assert!(contains(Deficiency::Deutan, (0, 1)), "{:?}", model);
+            assert!(contains(Deficiency::Tritan, (2, 3)), "{:?}", model);
+            assert!(!contains(Deficiency::Tritan, (0, 1)), "{:?}", model);
+            // already indistinguishable with normal vision
+            assert!(confusions.iter().all(|c| c.indices() != (0, 4)));
+            assert!(confusions.iter().all(|c| c.difference() < 10.0));
+        }
+        assert!(find_confusions(&[RGB24::RED], CvdModel::Brettel, DeltaE::Ok, 1.0).is_empty());
+    }
+}
@@ -0,0 +1,587 @@
+use crate::models::hsv::{HSVColor, HSV};
+use crate::models::ictcp::Ictcp;
+use crate::models::jzazbz::Jzazbz;
+use crate::models::lab::Lab;
+use crate::models::oklab::Oklab;
+use crate::models::rgb::linear::LinearRgb;
+use crate::models::xyz::XYZ;
+use crate::space::{RgbSpace, REFERENCE_WHITE_LUMINANCE};
+use crate::{RGB24, RGB48};
+
+/// A color which can be compared by the [`DeltaE`] metrics
+///
+/// Colors without a color space (RGB, HSV) are interpreted as sRGB.
+pub trait Comparable {
+    /// Converts this -> [`XYZ`] relative to D65
+    fn xyz_d65(&self) -> XYZ;
+
+    /// Converts this -> [`Lab`] relative to D65
+    fn lab(&self) -> Lab {
+        Lab::from_xyz(&self.xyz_d65())
+    }
+
+    /// Converts this -> [`Oklab`]
+    fn oklab(&self) -> Oklab {
+        Oklab::from_xyz(&self.xyz_d65())
+    }
+
+    /// Converts this -> [`Ictcp`], placing relative colors at the reference white luminance
+    fn ictcp(&self) -> Ictcp {
+        Ictcp::from_xyz(&absolute(&self.xyz_d65()))
+    }
+
+    /// Converts this -> [`Jzazbz`], placing relative colors at the reference white luminance
+    fn jzazbz(&self) -> Jzazbz {
+        Jzazbz::from_xyz(&absolute(&self.xyz_d65()))
+    }
+}
+
+/// A metric for the [color difference](https://en.wikipedia.org/wiki/Color_difference) ΔE
+///
+/// A difference of about `1.0` (`2.3` for [`DeltaE::Cie76`], `0.02` for [`DeltaE::Ok`]) is just noticeable.
+/// [`DeltaE::Itp`] and [`DeltaE::Jz`] are based on absolute luminance: relative colors are placed at
+/// the [`REFERENCE_WHITE_LUMINANCE`] of 203 cd/m², so SDR white is `Y = 1.0`.
+/// [`DeltaE::Cie94`], [`DeltaE::Cie94Textiles`] and [`DeltaE::Cmc`] are not symmetric:
+/// the first color is the reference (the standard) and the second the sample.
+///
+/// # Example
+/// ```
+/// use colorful::difference::DeltaE;
+/// use colorful::{RGBColor, RGB24};
+///
+/// let reference = RGB24::from_rgb(200, 30, 30);
+/// let sample = RGB24::from_rgb(198, 33, 31);
+/// assert!(DeltaE::Ciede2000.difference(&reference, &sample) < 1.0);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub enum DeltaE {
+    /// CIE76 - the euclidean distance in Lab
+    Cie76,
+    /// CIE94 with the weights for graphic arts
+    Cie94,
+    /// CIE94 with the weights for textiles
+    Cie94Textiles,
+    /// CIEDE2000, the default
+    #[default]
+    Ciede2000,
+    /// ΔEOK - the euclidean distance in Oklab
+    Ok,
+    /// ΔEITP (ITU-R BT.2124) - the weighted distance in ICtCp, suited for HDR and wide gamut
+    Itp,
+    /// ΔEz - the distance in Jzazbz, suited for HDR and wide gamut
+    Jz,
+    /// CMC l:c (1984) with the weights for lightness and chroma (usually 2:1 or 1:1)
+    Cmc {
+        /// The weight of lightness `l`
+        lightness: f64,
+        /// The weight of chroma `c`
+        chroma: f64,
+    },
+}
+
+impl DeltaE {
+    /// Calculates the difference between the `reference` and the `sample`
+    pub fn difference(&self, reference: &impl Comparable, sample: &impl Comparable) -> f64 {
+        let lab = || (reference.lab(), sample.lab());
+        match *self {
+            DeltaE::Cie76 => {
+                let (reference, sample) = lab();
+                delta_e76(&reference, &sample)
+            }
+            DeltaE::Cie94 => {
+                let (reference, sample) = lab();
+                delta_e94(&reference, &sample, 1.0, 0.045, 0.015)
+            }
+            DeltaE::Cie94Textiles => {
+                let (reference, sample) = lab();
+                delta_e94(&reference, &sample, 2.0, 0.048, 0.014)
+            }
+            DeltaE::Ciede2000 => {
+                let (reference, sample) = lab();
+                delta_e2000(&reference, &sample)
+            }
+            DeltaE::Ok => delta_e_ok(&reference.oklab(), &sample.oklab()),
+            DeltaE::Itp => delta_e_itp(&reference.ictcp(), &sample.ictcp()),
+            DeltaE::Jz => delta_e_z(&reference.jzazbz(), &sample.jzazbz()),
+            DeltaE::Cmc { lightness, chroma } => {
+                let (reference, sample) = lab();
+                delta_e_cmc(&reference, &sample, lightness, chroma)
+            }
+        }
+    }
+}
+
+/// Calculates ΔE*ab (CIE76) - the euclidean distance in Lab
+pub fn delta_e76(reference: &Lab, sample: &Lab) -> f64 {
+    let (l1, a1, b1) = reference.as_tuple();
+    let (l2, a2, b2) = sample.as_tuple();
+    ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+}
+
+/// Calculates ΔE*94 (CIE94)
+///
+/// # Parameters
+/// - `reference`, `sample`: the colors to compare
+/// - `k_l`: the weight of lightness (1.0 for graphic arts, 2.0 for textiles)
+/// - `k1`, `k2`: the weights of chroma and hue (0.045 and 0.015 for graphic arts,
+///   0.048 and 0.014 for textiles)
+pub fn delta_e94(reference: &Lab, sample: &Lab, k_l: f64, k1: f64, k2: f64) -> f64 {
+    let delta_l = reference.l() - sample.l();
+    let (c1, c2) = (reference.chroma(), sample.chroma());
+    let delta_c = c1 - c2;
+    let delta_h_squared = delta_hue_squared(reference, sample, delta_c);
+
+    let s_c = 1.0 + k1 * c1;
+    let s_h = 1.0 + k2 * c1;
+
+    ((delta_l / k_l).powi(2) + (delta_c / s_c).powi(2) + delta_h_squared / s_h.powi(2)).sqrt()
+}
+
+/// Calculates ΔE00 (CIEDE2000)
+///
+/// The implementation follows "The CIEDE2000 Color-Difference Formula:
+/// Implementation Notes, Supplementary Test Data, and Mathematical Observations"
+/// by Sharma, Wu and Dalal.
+pub fn delta_e2000(reference: &Lab, sample: &Lab) -> f64 {
+    let (l1, a1, b1) = reference.as_tuple();
+    let (l2, a2, b2) = sample.as_tuple();
+
+    let c_mean = (reference.chroma() + sample.chroma()) / 2.0;
+    let g = 0.5 * (1.0 - (c_mean.powi(7) / (c_mean.powi(7) + 25.0_f64.powi(7))).sqrt());
+    let (a1, a2) = ((1.0 + g) * a1, (1.0 + g) * a2);
+    let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+    let hue = |a: f64, b: f64| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let (h1, h2) = (hue(a1, b1), hue(a2, b2));
+
+    let delta_l = l2 - l1;
+    let delta_c = c2 - c1;
+    let delta_h = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else {
+        h2 - h1 + 360.0
+    };
+    let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h / 2.0).to_radians().sin();
+
+    let l_mean = (l1 + l2) / 2.0;
+    let c_mean = (c1 + c2) / 2.0;
+    let h_mean = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let t = 1.0 - 0.17 * (h_mean - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_mean).to_radians().cos()
+        + 0.32 * (3.0 * h_mean + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_mean - 63.0).to_radians().cos();
+    let delta_theta = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+    let r_c = 2.0 * (c_mean.powi(7) / (c_mean.powi(7) + 25.0_f64.powi(7))).sqrt();
+    let s_l = 1.0 + 0.015 * (l_mean - 50.0).powi(2) / (20.0 + (l_mean - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * c_mean;
+    let s_h = 1.0 + 0.015 * c_mean * t;
+    let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+    let (l, c, h) = (delta_l / s_l, delta_c / s_c, delta_h / s_h);
+    (l * l + c * c + h * h + r_t * c * h).sqrt()
+}
+
+/// Calculates ΔE CMC l:c (1984)
+///
+/// # Parameters
+/// - `reference`, `sample`: the colors to compare
+/// - `lightness`: the weight of lightness `l` (usually 2.0 for acceptability, 1.0 for perceptibility)
+/// - `chroma`: the weight of chroma `c` (usually 1.0)
+pub fn delta_e_cmc(reference: &Lab, sample: &Lab, lightness: f64, chroma: f64) -> f64 {
+    let (l1, c1, h1) = reference.to_lch();
+    let delta_l = l1 - sample.l();
+    let delta_c = c1 - sample.chroma();
+    let delta_h_squared = delta_hue_squared(reference, sample, delta_c);
+
+    let s_l = if l1 < 16.0 {
+        0.511
+    } else {
+        0.040975 * l1 / (1.0 + 0.01765 * l1)
+    };
+    let s_c = 0.0638 * c1 / (1.0 + 0.0131 * c1) + 0.638;
+    let f = (c1.powi(4) / (c1.powi(4) + 1900.0)).sqrt();
+    let t = if (164.0..=345.0).contains(&h1) {
+        0.56 + (0.2 * (h1 + 168.0).to_radians().cos()).abs()
+    } else {
+        0.36 + (0.4 * (h1 + 35.0).to_radians().cos()).abs()
+    };
+    let s_h = s_c * (f * t + 1.0 - f);
+
+    ((delta_l / (lightness * s_l)).powi(2)
+        + (delta_c / (chroma * s_c)).powi(2)
+        + delta_h_squared / s_h.powi(2))
+    .sqrt()
+}
+
+/// Calculates ΔEOK - the euclidean distance in Oklab
+pub fn delta_e_ok(reference: &Oklab, sample: &Oklab) -> f64 {
+    let (l1, a1, b1) = reference.as_tuple();
+    let (l2, a2, b2) = sample.as_tuple();
+    ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+}
+
+/// Calculates ΔEITP (ITU-R BT.2124) of the given [`Ictcp`] (PQ) colors
+///
+/// A difference of `1.0` is just noticeable.
+pub fn delta_e_itp(reference: &Ictcp, sample: &Ictcp) -> f64 {
+    itp_distance(reference.as_tuple(), sample.as_tuple())
+}
+
+/// Calculates ΔEz of the given [`Jzazbz`] colors
+pub fn delta_e_z(reference: &Jzazbz, sample: &Jzazbz) -> f64 {
+    z_distance(reference.as_tuple(), sample.as_tuple())
+}
+
+/// Calculates ΔEITP (ITU-R BT.2124) of the given ICtCp (PQ) values (I, Ct, Cp)
+fn itp_distance(reference: (f64, f64, f64), sample: (f64, f64, f64)) -> f64 {
+    let delta_i = reference.0 - sample.0;
+    let delta_t = 0.5 * (reference.1 - sample.1);
+    let delta_p = reference.2 - sample.2;
+    720.0 * (delta_i * delta_i + delta_t * delta_t + delta_p * delta_p).sqrt()
+}
+
+/// Calculates ΔEz of the given Jzazbz values (Jz, az, bz)
+fn z_distance(reference: (f64, f64, f64), sample: (f64, f64, f64)) -> f64 {
+    let (jz1, cz1, hz1) = (
+        reference.0,
+        reference.1.hypot(reference.2),
+        reference.2.atan2(reference.1),
+    );
+    let (jz2, cz2, hz2) = (sample.0, sample.1.hypot(sample.2), sample.2.atan2(sample.1));
+    let delta_hz = 2.0 * (cz1 * cz2).sqrt() * ((hz1 - hz2) / 2.0).sin();
+    ((jz1 - jz2).powi(2) + (cz1 - cz2).powi(2) + delta_hz * delta_hz).sqrt()
+}
+
+/// Places the given relative [`XYZ`] at the reference white luminance
+fn absolute(xyz: &XYZ) -> XYZ {
+    let (x, y, z) = xyz.as_tuple();
+    XYZ::new(
+        x * REFERENCE_WHITE_LUMINANCE,
+        y * REFERENCE_WHITE_LUMINANCE,
+        z * REFERENCE_WHITE_LUMINANCE,
+    )
+}
+
+/// Places the given absolute [`XYZ`] relative to the reference white luminance
+fn relative(xyz: &XYZ) -> XYZ {
+    let (x, y, z) = xyz.as_tuple();
+    XYZ::new(
+        x / REFERENCE_WHITE_LUMINANCE,
+        y / REFERENCE_WHITE_LUMINANCE,
+        z / REFERENCE_WHITE_LUMINANCE,
+    )
+}
+
+/// Returns ΔH² - the squared difference of hue calculated from ΔE76, ΔL and ΔC
+fn delta_hue_squared(reference: &Lab, sample: &Lab, delta_c: f64) -> f64 {
+    let delta_a = reference.a() - sample.a();
+    let delta_b = reference.b() - sample.b();
+    // may be slightly negative due to rounding errors
+    (delta_a * delta_a + delta_b * delta_b - delta_c * delta_c).max(0.0)
+}
+
+impl Comparable for Lab {
+    fn xyz_d65(&self) -> XYZ {
+        self.to_xyz()
+    }
+
+    fn lab(&self) -> Lab {
+        *self
+    }
+}
+
+impl Comparable for Oklab {
+    fn xyz_d65(&self) -> XYZ {
+        self.to_xyz()
+    }
+
+    fn oklab(&self) -> Oklab {
+        *self
+    }
+}
+
+impl Comparable for Ictcp {
+    fn xyz_d65(&self) -> XYZ {
+        relative(&self.to_xyz())
+    }
+
+    fn ictcp(&self) -> Ictcp {
+        *self
+    }
+}
+
+impl Comparable for Jzazbz {
+    fn xyz_d65(&self) -> XYZ {
+        relative(&self.to_xyz())
+    }
+
+    fn jzazbz(&self) -> Jzazbz {
+        *self
+    }
+}
+
+impl Comparable for XYZ {
+    fn xyz_d65(&self) -> XYZ {
+        *self
+    }
+}
+
+impl Comparable for LinearRgb {
+    fn xyz_d65(&self) -> XYZ {
+        RgbSpace::srgb().linear_to_xyz(self)
+    }
+}
+
+impl Comparable for RGB24 {
+    fn xyz_d65(&self) -> XYZ {
+        RgbSpace::srgb().to_xyz(self)
+    }
+}
+
+impl Comparable for RGB48 {
+    fn xyz_d65(&self) -> XYZ {
+        RgbSpace::srgb().to_xyz(self)
+    }
+}
+
+impl Comparable for HSV {
+    fn xyz_d65(&self) -> XYZ {
+        self.to_rgb48().xyz_d65()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::difference::{
+        delta_e2000, delta_e76, delta_e94, delta_e_cmc, delta_e_itp, delta_e_ok, delta_e_z,
+        itp_distance, z_distance, Comparable, DeltaE,
+    };
+    use crate::models::ictcp::Ictcp;
+    use crate::models::jzazbz::Jzazbz;
+    use crate::models::lab::Lab;
+    use crate::models::oklab::Oklab;
+    use crate::models::rgb::RGBColor;
+    use crate::models::xyz::XYZ;
+    use crate::space::pq_encode_nits;
+    use crate::RGB24;
+
+    /// The test data of Sharma, Wu and Dalal: (L1, a1, b1, L2, a2, b2, ΔE00)
+    const SHARMA: [(f64, f64, f64, f64, f64, f64, f64); 34] = [
+        (50.0000, 2.6772, -79.7751, 50.0000, 0.0000, -82.7485, 2.0425),
+        (50.0000, 3.1571, -77.2803, 50.0000, 0.0000, -82.7485, 2.8615),
+        (50.0000, 2.8361, -74.0200, 50.0000, 0.0000, -82.7485, 3.4412),
+        (
+            50.0000, -1.3802, -84.2814, 50.0000, 0.0000, -82.7485, 1.0000,
+        ),
+        (
+            50.0000, -1.1848, -84.8006, 50.0000, 0.0000, -82.7485, 1.0000,
+        ),
+        (
+            50.0000, -0.9009, -85.5211, 50.0000, 0.0000, -82.7485, 1.0000,
+        ),
+        (50.0000, 0.0000, 0.0000, 50.0000, -1.0000, 2.0000, 2.3669),
+        (50.0000, -1.0000, 2.0000, 50.0000, 0.0000, 0.0000, 2.3669),
+        (50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0009, 7.1792),
+        (50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0010, 7.1792),
+        (50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0011, 7.2195),
+        (50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0012, 7.2195),
+        (50.0000, -0.0010, 2.4900, 50.0000, 0.0009, -2.4900, 4.8045),
+        (50.0000, -0.0010, 2.4900, 50.0000, 0.0010, -2.4900, 4.8045),
+        (50.0000, -0.0010, 2.4900, 50.0000, 0.0011, -2.4900, 4.7461),
+        (50.0000, 2.5000, 0.0000, 50.0000, 0.0000, -2.5000, 4.3065),
+        (50.0000, 2.5000, 0.0000, 73.0000, 25.0000, -18.0000, 27.1492),
+        (50.0000, 2.5000, 0.0000, 61.0000, -5.0000, 29.0000, 22.8977),
+        (50.0000, 2.5000, 0.0000, 56.0000, -27.0000, -3.0000, 31.9030),
+        (50.0000, 2.5000, 0.0000, 58.0000, 24.0000, 15.0000, 19.4535),
+        (50.0000, 2.5000, 0.0000, 50.0000, 3.1736, 0.5854, 1.0000),
+        (50.0000, 2.5000, 0.0000, 50.0000, 3.2972, 0.0000, 1.0000),
+        (50.0000, 2.5000, 0.0000, 50.0000, 1.8634, 0.5757, 1.0000),
+        (50.0000, 2.5000, 0.0000, 50.0000, 3.2592, 0.3350, 1.0000),
+        (
+            60.2574, -34.0099, 36.2677, 60.4626, -34.1751, 39.4387, 1.2644,
+        ),
+        (
+            63.0109, -31.0961, -5.8663, 62.8187, -29.7946, -4.0864, 1.2630,
+        ),
+        (61.2901, 3.7196, -5.3901, 61.4292, 2.2480, -4.9620, 1.8731),
+        (35.0831, -44.1164, 3.7933, 35.0232, -40.0716, 1.5901, 1.8645),
+        (
+            22.7233, 20.0904, -46.6940, 23.0331, 14.9730, -42.5619, 2.0373,
+        ),
+        (36.4612, 47.8580, 18.3852, 36.2715, 50.5065, 21.2231, 1.4146),
+        (90.8027, -2.0831, 1.4410, 91.1528, -1.6435, 0.0447, 1.4441),
+        (90.9257, -0.5406, -0.9208, 88.6381, -0.8985, -0.7239, 1.5381),
+        (6.7747, -0.2908, -2.4247, 5.8714, -0.0985, -2.2286, 0.6377),
+        (2.0776, 0.0795, -1.1350, 0.9033, -0.0636, -0.5514, 0.9082),
+    ];
+
+    #[test]
+    fn ciede2000_sharma() {
+        for (l1, a1, b1, l2, a2, b2, expected) in SHARMA {
+            let (first, second) = (Lab::new(l1, a1, b1), Lab::new(l2, a2, b2));
+            let actual = delta_e2000(&first, &second);
+            assert!(
+                (expected - actual).abs() < 1e-4,
+                "{} {}: {}",
+                first,
+                second,
+                actual
+            );
+            // CIEDE2000 is symmetric
+            assert!((expected - delta_e2000(&second, &first)).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn cie76() {
+        let first = Lab::new(50.0, 10.0, -10.0);
+        assert_eq!(0.0, delta_e76(&first, &first));
+        assert_eq!(13.0, delta_e76(&first, &Lab::new(50.0, 15.0, 2.0)));
+    }
+
+    #[test]
+    fn cie94() {
+        // without chroma of the reference, only the lightness is weighted
+        let reference = Lab::new(50.0, 0.0, 0.0);
+        let sample = Lab::new(60.0, 3.0, 4.0);
+        assert!(
+            (delta_e94(&reference, &sample, 1.0, 0.045, 0.015) - 125.0_f64.sqrt()).abs() < 1e-12
+        );
+        assert!(
+            (delta_e94(&reference, &sample, 2.0, 0.048, 0.014) - 50.0_f64.sqrt()).abs() < 1e-12
+        );
+
+        // chroma and hue differences are weighted by the chroma of the reference
+        let reference = Lab::new(50.0, 30.0, 40.0);
+        let sample = Lab::new(50.0, 33.0, 44.0);
+        let expected = 5.0 / (1.0 + 0.045 * 50.0);
+        assert!((delta_e94(&reference, &sample, 1.0, 0.045, 0.015) - expected).abs() < 1e-12);
+        assert!(
+            DeltaE::Cie94.difference(&reference, &sample)
+                < DeltaE::Cie76.difference(&reference, &sample)
+        );
+    }
+
+    #[test]
+    fn cmc() {
+        let reference = Lab::new(50.0, 0.0, 0.0);
+        let s_l = 0.040975 * 50.0 / (1.0 + 0.01765 * 50.0);
+        let expected = 10.0 / (2.0 * s_l);
+        assert!(
+            (delta_e_cmc(&reference, &Lab::new(60.0, 0.0, 0.0), 2.0, 1.0) - expected).abs() < 1e-12
+        );
+        let expected = 5.0 / 0.638;
+        assert!(
+            (delta_e_cmc(&reference, &Lab::new(50.0, 3.0, 4.0), 2.0, 1.0) - expected).abs() < 1e-12
+        );
+
+        // CMC is not symmetric
+        let (first, second) = (Lab::new(40.0, 20.0, 10.0), Lab::new(45.0, 60.0, -30.0));
+        assert_ne!(
+            delta_e_cmc(&first, &second, 1.0, 1.0),
+            delta_e_cmc(&second, &first, 1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn ok() {
+        let (white, black) = (Oklab::new(1.0, 0.0, 0.0), Oklab::default());
+        assert_eq!(1.0, delta_e_ok(&white, &black));
+        assert_eq!(0.05, delta_e_ok(&black, &Oklab::new(0.0, 0.03, -0.04)));
+        assert!((DeltaE::Ok.difference(&RGB24::WHITE, &RGB24::BLACK) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn itp() {
+        assert_eq!(720.0, itp_distance((0.5, 0.0, 0.0), (0.5, 2.0, 0.0)));
+        assert_eq!(720.0, itp_distance((0.5, 0.0, 0.0), (0.5, 0.0, -1.0)));
+        assert_eq!(360.0, itp_distance((0.5, 0.0, 0.0), (1.0, 0.0, 0.0)));
+        let gray = Ictcp::new(0.5, 0.0, 0.0);
+        assert_eq!(720.0, delta_e_itp(&gray, &Ictcp::new(0.5, 2.0, 0.0)));
+
+        // grays differ in intensity only, the SDR white is at 203 cd/m²
+        let (white, gray) = (
+            XYZ::from_xyy(0.3127, 0.3290, 1.0),
+            XYZ::from_xyy(0.3127, 0.3290, 0.5),
+        );
+        let expected = 720.0 * (pq_encode_nits(203.0) - pq_encode_nits(101.5));
+        assert!((DeltaE::Itp.difference(&white, &gray) - expected).abs() < 1e-9);
+        assert!((DeltaE::Itp.difference(&gray, &white) - expected).abs() < 1e-9);
+
+        let absolute = Ictcp::from_xyz(&XYZ::from_xyy(0.3127, 0.3290, 203.0));
+        assert!(DeltaE::Itp.difference(&absolute, &white) < 1e-9);
+    }
+
+    #[test]
+    fn jz() {
+        // ΔEz is the euclidean distance in Jzazbz
+        let (first, second) = ((0.2, 0.01, -0.02), (0.15, -0.03, 0.01));
+        let expected = (0.05_f64.powi(2) + 0.04_f64.powi(2) + 0.03_f64.powi(2)).sqrt();
+        assert!((z_distance(first, second) - expected).abs() < 1e-12);
+        assert!((z_distance(second, first) - expected).abs() < 1e-12);
+        assert!(z_distance((0.1, 0.0, 0.0), (0.1, 0.0, 0.0)) == 0.0);
+        let (first, second) = (Jzazbz::from(first), Jzazbz::from(second));
+        assert!((delta_e_z(&first, &second) - expected).abs() < 1e-12);
+
+        let white = XYZ::from_xyy(0.3127, 0.3290, 1.0);
+        let absolute = Jzazbz::from_xyz(&XYZ::from_xyy(0.3127, 0.3290, 203.0));
+        assert!(DeltaE::Jz.difference(&absolute, &white) < 1e-9);
+
+        let red = RGB24::from_rgb(200, 30, 30);
+        let similar = DeltaE::Jz.difference(&red, &RGB24::from_rgb(198, 33, 31));
+        assert!(similar < DeltaE::Jz.difference(&red, &RGB24::from_rgb(30, 30, 200)));
+    }
+
+    #[test]
+    fn same_color() {
+        let metrics = [
+            DeltaE::Cie76,
+            DeltaE::Cie94,
+            DeltaE::Cie94Textiles,
+            DeltaE::Ciede2000,
+            DeltaE::Ok,
+            DeltaE::Itp,
+            DeltaE::Jz,
+            DeltaE::Cmc {
+                lightness: 2.0,
+                chroma: 1.0,
+            },
+        ];
+        let color = RGB24::from_rgb(120, 30, 200);
+        for metric in metrics {
+            assert_eq!(0.0, metric.difference(&color, &color));
+            assert!(metric.difference(&color, &Lab::from_rgb(&color)) < 1e-12);
+            assert!(metric.difference(&color, &color.to_rgb48()) < 1e-12);
+            assert!(metric.difference(&color, &color.to_hsv()) < 0.01);
+            assert!(metric.difference(&color, &color.to_linear_rgb()) < 0.01);
+        }
+    }
+
+    #[test]
+    fn comparable() {
+        let lab = Lab::new(50.0, 20.0, -30.0);
+        assert_eq!(lab, lab.lab());
+        let xyz = lab.xyz_d65();
+        let back = xyz.lab();
+        assert!(delta_e76(&lab, &back) < 1e-9);
+        assert!(DeltaE::Ciede2000.difference(&RGB24::WHITE, &RGB24::BLACK) > 99.0);
+    }
+}
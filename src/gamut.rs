@@ -0,0 +1,369 @@
+use crate::difference::delta_e2000;
+use crate::models::lab::Lab;
+use crate::models::oklab::Oklab;
+use crate::models::rgb::linear::LinearRgb;
+use crate::models::rgb::RGBColor;
+use crate::models::xyz::XYZ;
+use crate::space::{self, ChromaticAdaptation, RgbSpace};
+
+/// The tolerance of linear values still considered inside the gamut (rounding errors)
+const GAMUT_EPSILON: f64 = 1e-7;
+/// The just noticeable difference (ΔEOK) used by CSS Color 4
+const OKLCH_JND: f64 = 0.02;
+/// The precision of the chroma search in Oklch
+const OKLCH_EPSILON: f64 = 0.0001;
+/// The number of lightness steps scanned by MINDE before refining the minimum
+const MINDE_STEPS: usize = 50;
+/// The precision of the lightness and chroma searches in CIELCh for MINDE
+const MINDE_EPSILON: f64 = 0.0001;
+/// The ratio of the golden section search
+const GOLDEN_RATIO: f64 = 0.618_033_988_749_895;
+
+/// A strategy to map colors outside of the gamut of an [`RgbSpace`] into the gamut
+///
+/// All strategies take colors as [`XYZ`] relative to D65 (as returned by
+/// [`Lab::to_xyz`], [`Oklab::to_xyz`] or [`RgbSpace::to_xyz`] of spaces with a D65 white point).
+/// Target spaces with another white point are adapted using the Bradford transform.
+///
+/// # Example
+/// ```
+/// use colorful::gamut::GamutMapping;
+/// use colorful::space::RgbSpace;
+/// use colorful::{RGBColor, RGB24};
+///
+/// let p3_red = RgbSpace::display_p3().to_xyz(&RGB24::RED);
+/// let (red, was_mapped): (RGB24, bool) = GamutMapping::Oklch.map_to_rgb(&p3_red, RgbSpace::srgb());
+/// assert!(was_mapped);
+/// assert_eq!(255, red.r());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum GamutMapping {
+    /// Clamps the linear values to `0.0 - 1.0` (may shift hue and lightness)
+    Clip,
+    /// Reduces the chroma in Oklch until clipping is not noticeable (ΔEOK < 0.02)
+    /// as defined by [CSS Color 4](https://www.w3.org/TR/css-color-4/#binsearch), the default
+    #[default]
+    Oklch,
+    /// Maps to the color of the same hue (CIELCh) inside the gamut with the minimum ΔE00 (MINDE)
+    ///
+    /// Unlike chroma reduction the lightness may change.
+    Minde,
+}
+
+impl GamutMapping {
+    /// Maps the given color into the gamut of the `space`
+    ///
+    /// # Returns
+    /// The linear values in `space` (0.0 - 1.0) and if the color was outside of the gamut
+    pub fn map(&self, xyz: &XYZ, space: &RgbSpace) -> (LinearRgb, bool) {
+        let linear = xyz_to_linear(xyz, space);
+        if is_inside(&linear) {
+            return (clamp(&linear), false);
+        }
+
+        let mapped = match self {
+            GamutMapping::Clip => clamp(&linear),
+            GamutMapping::Oklch => map_oklch(xyz, space),
+            GamutMapping::Minde => map_minde(xyz, space),
+        };
+        (mapped, true)
+    }
+
+    /// Maps the given color into the gamut of the `space` and encodes it
+    ///
+    /// # Returns
+    /// The [`RGBColor`] encoded in `space` and if the color was outside of the gamut
+    pub fn map_to_rgb<S: RGBColor<U>, U>(&self, xyz: &XYZ, space: &RgbSpace) -> (S, bool) {
+        let (linear, was_mapped) = self.map(xyz, space);
+        let (r, g, b) = space.encode(&linear);
+        (S::from_rgb_f64(r, g, b), was_mapped)
+    }
+}
+
+/// Returns if the given color ([`XYZ`] relative to D65) is inside the gamut of the `space`
+///
+/// # Example
+/// ```
+/// use colorful::gamut;
+/// use colorful::space::RgbSpace;
+/// use colorful::{RGBColor, RGB24};
+///
+/// let p3_green = RgbSpace::display_p3().to_xyz(&RGB24::GREEN);
+/// assert!(!gamut::in_gamut(&p3_green, RgbSpace::srgb()));
+/// assert!(gamut::in_gamut(&p3_green, RgbSpace::rec2020()));
+/// ```
+pub fn in_gamut(xyz: &XYZ, space: &RgbSpace) -> bool {
+    is_inside(&xyz_to_linear(xyz, space))
+}
+
+/// The chroma reduction of CSS Color 4 in Oklch
+fn map_oklch(xyz: &XYZ, space: &RgbSpace) -> LinearRgb {
+    let (lightness, chroma, hue) = Oklab::from_xyz(xyz).to_lch();
+    if lightness >= 1.0 {
+        return LinearRgb::WHITE;
+    }
+    if lightness <= 0.0 {
+        return LinearRgb::BLACK;
+    }
+
+    let difference = |color: &Oklab, clipped: &LinearRgb| {
+        let clipped = Oklab::from_xyz(&linear_to_xyz(clipped, space));
+        let (l, a, b) = color.as_tuple();
+        ((l - clipped.l()).powi(2) + (a - clipped.a()).powi(2) + (b - clipped.b()).powi(2)).sqrt()
+    };
+
+    let mut clipped = clamp(&xyz_to_linear(xyz, space));
+    if difference(&Oklab::from_xyz(xyz), &clipped) < OKLCH_JND {
+        return clipped;
+    }
+
+    let (mut min, mut max) = (0.0, chroma);
+    let mut min_in_gamut = true;
+    while max - min > OKLCH_EPSILON {
+        let current = Oklab::from_lch(lightness, (min + max) / 2.0, hue);
+        let linear = xyz_to_linear(&current.to_xyz(), space);
+        if min_in_gamut && is_inside(&linear) {
+            min = (min + max) / 2.0;
+            continue;
+        }
+
+        clipped = clamp(&linear);
+        let delta = difference(&current, &clipped);
+        if delta < OKLCH_JND {
+            if OKLCH_JND - delta < OKLCH_EPSILON {
+                break;
+            }
+            min_in_gamut = false;
+            min = (min + max) / 2.0;
+        } else {
+            max = (min + max) / 2.0;
+        }
+    }
+    clipped
+}
+
+/// The color of the same hue in CIELCh inside the gamut with the minimum ΔE00
+fn map_minde(xyz: &XYZ, space: &RgbSpace) -> LinearRgb {
+    let target = Lab::from_xyz(xyz);
+    let (lightness, chroma, hue) = target.to_lch();
+    if lightness >= 100.0 {
+        return LinearRgb::WHITE;
+    }
+    if lightness <= 0.0 {
+        return LinearRgb::BLACK;
+    }
+
+    let to_linear = |l: f64, c: f64| xyz_to_linear(&Lab::from_lch(l, c, hue).to_xyz(), space);
+    // the closest color of the given lightness has the chroma of the target or of the gamut boundary
+    let closest = |l: f64| {
+        let (mut min, mut max) = (0.0, chroma);
+        if is_inside(&to_linear(l, max)) {
+            min = max;
+        }
+        while max - min > MINDE_EPSILON {
+            let current = (min + max) / 2.0;
+            if is_inside(&to_linear(l, current)) {
+                min = current;
+            } else {
+                max = current;
+            }
+        }
+        min
+    };
+    let difference = |l: f64| delta_e2000(&target, &Lab::from_lch(l, closest(l), hue));
+
+    // scan the lightness and refine around the minimum with a golden section search
+    let step = 100.0 / MINDE_STEPS as f64;
+    let (_, best) = (0..=MINDE_STEPS)
+        .map(|i| {
+            let l = i as f64 * step;
+            (difference(l), l)
+        })
+        .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal))
+        .expect("there is at least one step");
+    let (mut low, mut high) = ((best - step).max(0.0), (best + step).min(100.0));
+    while high - low > MINDE_EPSILON {
+        let lower = high - GOLDEN_RATIO * (high - low);
+        let upper = low + GOLDEN_RATIO * (high - low);
+        if difference(lower) < difference(upper) {
+            high = upper;
+        } else {
+            low = lower;
+        }
+    }
+
+    let l = (low + high) / 2.0;
+    clamp(&to_linear(l, closest(l)))
+}
+
+/// Converts the given [`XYZ`] (relative to D65) -> linear values of the `space`
+fn xyz_to_linear(xyz: &XYZ, space: &RgbSpace) -> LinearRgb {
+    let adapted = ChromaticAdaptation::Bradford.adapt(xyz, space::D65, space.white_point());
+    space.xyz_to_linear(&adapted)
+}
+
+/// Converts the linear values of the `space` -> [`XYZ`] (relative to D65)
+fn linear_to_xyz(linear: &LinearRgb, space: &RgbSpace) -> XYZ {
+    let xyz = space.linear_to_xyz(linear);
+    ChromaticAdaptation::Bradford.adapt(&xyz, space.white_point(), space::D65)
+}
+
+/// Returns if all linear values are within `0.0 - 1.0` (considering rounding errors)
+fn is_inside(linear: &LinearRgb) -> bool {
+    let (r, g, b) = linear.as_tuple();
+    [r, g, b]
+        .iter()
+        .all(|value| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(value))
+}
+
+/// Clamps the linear values to `0.0 - 1.0`
+fn clamp(linear: &LinearRgb) -> LinearRgb {
+    let (r, g, b) = linear.as_tuple();
+    LinearRgb::new(r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::difference::delta_e2000;
+    use crate::gamut::{in_gamut, GamutMapping};
+    use crate::models::lab::Lab;
+    use crate::models::oklab::Oklab;
+    use crate::models::rgb::linear::LinearRgb;
+    use crate::models::rgb::RGBColor;
+    use crate::space::RgbSpace;
+    use crate::RGB24;
+
+    fn all() -> [GamutMapping; 3] {
+        [GamutMapping::Clip, GamutMapping::Oklch, GamutMapping::Minde]
+    }
+
+    #[test]
+    fn in_gamut_() {
+        let srgb_red = RgbSpace::srgb().to_xyz(&RGB24::RED);
+        let p3_red = RgbSpace::display_p3().to_xyz(&RGB24::RED);
+        assert!(in_gamut(&srgb_red, RgbSpace::srgb()));
+        assert!(in_gamut(&srgb_red, RgbSpace::display_p3()));
+        assert!(!in_gamut(&p3_red, RgbSpace::srgb()));
+        assert!(in_gamut(&p3_red, RgbSpace::display_p3()));
+        let p3_green = RgbSpace::display_p3().to_xyz(&RGB24::GREEN);
+        assert!(!in_gamut(&p3_green, RgbSpace::srgb()));
+        assert!(in_gamut(&p3_green, RgbSpace::rec2020()));
+        assert!(in_gamut(&srgb_red, RgbSpace::prophoto()));
+        assert!(!in_gamut(
+            &Lab::new(50.0, 0.0, 150.0).to_xyz(),
+            RgbSpace::rec2020()
+        ));
+    }
+
+    #[test]
+    fn in_gamut_unchanged() {
+        let color = RgbSpace::srgb().to_xyz(&RGB24::from_rgb(20, 200, 120));
+        for mapping in all() {
+            let (rgb, was_mapped): (RGB24, bool) = mapping.map_to_rgb(&color, RgbSpace::srgb());
+            assert!(!was_mapped);
+            assert_eq!(RGB24::from_rgb(20, 200, 120), rgb);
+        }
+
+        let white = RgbSpace::srgb().to_xyz(&RGB24::WHITE);
+        let (linear, was_mapped) = GamutMapping::Oklch.map(&white, RgbSpace::prophoto());
+        assert!(!was_mapped);
+        assert!((1.0 - linear.r()).abs() < 1e-9 && (1.0 - linear.b()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn clip() {
+        let p3_red = RgbSpace::display_p3().to_xyz(&RGB24::RED);
+        let (rgb, was_mapped): (RGB24, bool) =
+            GamutMapping::Clip.map_to_rgb(&p3_red, RgbSpace::srgb());
+        assert!(was_mapped);
+        assert_eq!(RGB24::RED, rgb);
+    }
+
+    #[test]
+    fn oklch() {
+        let color = Oklab::from_lch(0.7, 0.3, 150.0);
+        let (linear, was_mapped) = GamutMapping::Oklch.map(&color.to_xyz(), RgbSpace::srgb());
+        assert!(was_mapped);
+        let mapped = Oklab::from_linear_rgb(&linear);
+        let (l, c, h) = mapped.to_lch();
+        assert!((l - 0.7).abs() < 0.02, "{}", mapped);
+        assert!((h - 150.0).abs() < 5.0, "{}", mapped);
+        assert!(c < 0.3);
+
+        // hue is kept better than by clipping
+        let (clipped, _) = GamutMapping::Clip.map(&color.to_xyz(), RgbSpace::srgb());
+        let clipped_hue = Oklab::from_linear_rgb(&clipped).hue();
+        assert!((h - 150.0).abs() < (clipped_hue - 150.0).abs());
+    }
+
+    #[test]
+    fn minde() {
+        // beyond the cusp of a hue the closest color is the cusp, e.g. the primaries of sRGB
+        for cusp in [RGB24::RED, RGB24::GREEN, RGB24::BLUE] {
+            let (l, c, h) = Lab::from_rgb(&cusp).to_lch();
+            let color = Lab::from_lch(l, 1.5 * c, h);
+            let (rgb, was_mapped): (RGB24, bool) =
+                GamutMapping::Minde.map_to_rgb(&color.to_xyz(), RgbSpace::srgb());
+            assert!(was_mapped);
+            assert_eq!(cusp, rgb);
+        }
+
+        let color = Lab::from_lch(60.0, 120.0, 250.0);
+        let (linear, was_mapped) = GamutMapping::Minde.map(&color.to_xyz(), RgbSpace::srgb());
+        assert!(was_mapped);
+        let mapped = Lab::from_xyz(&RgbSpace::srgb().linear_to_xyz(&linear));
+        let (l, c, h) = mapped.to_lch();
+        assert!((h - 250.0).abs() < 0.01, "{}", mapped);
+        assert!((l - 60.0).abs() > 1.0 && c < 120.0, "{}", mapped);
+
+        // on the boundary of the gamut and closer than reducing the chroma only
+        let (r, g, b) = linear.as_tuple();
+        assert!([r, g, b].iter().any(|v| *v < 1e-6 || *v > 1.0 - 1e-6));
+        let (reduced, _) = GamutMapping::Oklch.map(&color.to_xyz(), RgbSpace::srgb());
+        let reduced = Lab::from_xyz(&RgbSpace::srgb().linear_to_xyz(&reduced));
+        assert!(delta_e2000(&color, &mapped) < delta_e2000(&color, &reduced));
+    }
+
+    #[test]
+    fn extreme_lightness() {
+        let bright = Oklab::new(1.2, 0.1, 0.0).to_xyz();
+        let dark = Oklab::new(-0.1, 0.1, 0.0).to_xyz();
+        for mapping in [GamutMapping::Oklch, GamutMapping::Minde] {
+            assert_eq!(
+                (LinearRgb::WHITE, true),
+                mapping.map(&bright, RgbSpace::srgb())
+            );
+            assert_eq!(
+                (LinearRgb::BLACK, true),
+                mapping.map(&dark, RgbSpace::srgb())
+            );
+        }
+    }
+
+    #[test]
+    fn result_in_gamut() {
+        let colors = [
+            Oklab::from_lch(0.5, 0.4, 30.0).to_xyz(),
+            Oklab::from_lch(0.9, 0.2, 100.0).to_xyz(),
+            Lab::from_lch(30.0, 100.0, 300.0).to_xyz(),
+            RgbSpace::rec2020().to_xyz(&RGB24::GREEN),
+        ];
+        for mapping in all() {
+            for color in &colors {
+                for space in [
+                    RgbSpace::srgb(),
+                    RgbSpace::display_p3(),
+                    RgbSpace::prophoto(),
+                ] {
+                    let (linear, _) = mapping.map(color, space);
+                    let (r, g, b) = linear.as_tuple();
+                    assert!(
+                        [r, g, b].iter().all(|v| (0.0..=1.0).contains(v)),
+                        "{:?}",
+                        mapping
+                    );
+                }
+            }
+        }
+    }
+}
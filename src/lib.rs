@@ -3,7 +3,13 @@
 //! # Color models
 //!
 //! - RGB [(Wikipedia)](https://en.wikipedia.org/wiki/RGB_color_model) - based on *red, green, blue*
+//! - Linear RGB [(Wikipedia)](https://en.wikipedia.org/wiki/SRGB#Transfer_function_(%22gamma%22)) - RGB proportional to light intensity
 //! - HSV [(Wikipedia)](https://en.wikipedia.org/wiki/HSL_and_HSV) - based on *hue, saturation, value*
+//! - XYZ [(Wikipedia)](https://en.wikipedia.org/wiki/CIE_1931_color_space) - the CIE 1931 color space
+//! - CIELAB [(Wikipedia)](https://en.wikipedia.org/wiki/CIELAB_color_space) - based on *lightness, green-red, blue-yellow*
+//! - Oklab [(Wikipedia)](https://en.wikipedia.org/wiki/Oklab_color_space) - a perceptual color space
+//! - ICtCp [(Wikipedia)](https://en.wikipedia.org/wiki/ICtCp) - based on *intensity, blue-yellow, red-green* for HDR
+//! - Jzazbz [(Paper)](https://doi.org/10.1364/OE.25.015131) - a perceptual color space for HDR
 //!
 //! # Please note
 //!
@@ -13,29 +19,57 @@
 #[macro_use]
 extern crate serde;
 
-#[cfg(test)]
-extern crate strum;
-#[cfg(test)]
-extern crate strum_macros;
-
 /// Contains different color models
 mod models;
 
 pub use models::hsv::{HSVColor, HSV};
+pub use models::ictcp::Ictcp;
+pub use models::jzazbz::Jzazbz;
+pub use models::lab::Lab;
+pub use models::oklab::Oklab;
+pub use models::rgb::linear::LinearRgb;
 pub use models::rgb::rgb24::RGB24;
 pub use models::rgb::rgb48::RGB48;
 pub use models::rgb::RGBColor;
+pub use models::xyz::XYZ;
 pub use models::Color;
 
 /// Contains a set of common predefined colors
 pub mod presets;
 
+/// Contains RGB color spaces and the conversion between them
+pub mod space;
+
+/// Contains tone mapping operators for HDR -> SDR
+pub mod tonemap;
+
+/// Contains gamut checks and gamut mapping
+pub mod gamut;
+
+/// Contains color difference metrics (ΔE)
+pub mod difference;
+
+/// Contains color vision deficiency simulation
+pub mod cvd;
+
+/// Contains approximate equality of colors
+pub mod approx;
+
+/// Contains contrast calculations for accessibility
+pub mod contrast;
+
 /// Contains the calculations for conversion between color models
 mod converter;
 
 /// Contains various util methods for the work with numbers
 mod number_utils;
 
+/// Contains the calculations with 3x3 matrices
+mod matrix;
+
+/// Contains the normalization of names for lookups
+mod names;
+
 #[cfg(test)]
 mod tests {
     use crate::models::hsv::HSVColor;
@@ -0,0 +1,114 @@
+/// A 3x3 matrix stored row by row
+pub type Matrix3 = [[f64; 3]; 3];
+
+/// The identity matrix
+pub const IDENTITY: Matrix3 = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// Multiplies the matrix with the given (column) vector
+pub fn apply(m: &Matrix3, v: (f64, f64, f64)) -> (f64, f64, f64) {
+    (
+        m[0][0] * v.0 + m[0][1] * v.1 + m[0][2] * v.2,
+        m[1][0] * v.0 + m[1][1] * v.1 + m[1][2] * v.2,
+        m[2][0] * v.0 + m[2][1] * v.1 + m[2][2] * v.2,
+    )
+}
+
+/// Multiplies the two matrices (`a * b`)
+pub fn multiply(a: &Matrix3, b: &Matrix3) -> Matrix3 {
+    let mut result = [[0.0; 3]; 3];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    result
+}
+
+/// Creates a diagonal matrix from the given vector
+pub fn diagonal(v: (f64, f64, f64)) -> Matrix3 {
+    [[v.0, 0.0, 0.0], [0.0, v.1, 0.0], [0.0, 0.0, v.2]]
+}
+
+/// Calculates the inverse of the given matrix
+///
+/// # Returns
+/// `None` if the matrix is singular
+pub fn inverse(m: &Matrix3) -> Option<Matrix3> {
+    let cofactor =
+        |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+
+    let adjugate = [
+        [
+            cofactor(1, 2, 1, 2),
+            -cofactor(0, 2, 1, 2),
+            cofactor(0, 1, 1, 2),
+        ],
+        [
+            -cofactor(1, 2, 0, 2),
+            cofactor(0, 2, 0, 2),
+            -cofactor(0, 1, 0, 2),
+        ],
+        [
+            cofactor(1, 2, 0, 1),
+            -cofactor(0, 2, 0, 1),
+            cofactor(0, 1, 0, 1),
+        ],
+    ];
+    let determinant =
+        m[0][0] * adjugate[0][0] + m[0][1] * adjugate[1][0] + m[0][2] * adjugate[2][0];
+
+    if determinant == 0.0 || !determinant.is_finite() {
+        return None;
+    }
+
+    let mut result = adjugate;
+    for value in result.iter_mut().flat_map(|row| row.iter_mut()) {
+        *value /= determinant;
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::{apply, diagonal, inverse, multiply, IDENTITY};
+
+    const M: [[f64; 3]; 3] = [[2.0, 0.0, 1.0], [1.0, 3.0, 0.0], [0.0, 1.0, 4.0]];
+
+    fn assert_approx_equal(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) {
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((a[i][j] - b[i][j]).abs() < 1e-12, "{:?} !~ {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn apply_() {
+        assert_eq!((5.0, 7.0, 14.0), apply(&M, (1.0, 2.0, 3.0)));
+        assert_eq!((1.0, 2.0, 3.0), apply(&IDENTITY, (1.0, 2.0, 3.0)));
+    }
+
+    #[test]
+    fn multiply_() {
+        assert_eq!(M, multiply(&M, &IDENTITY));
+        assert_eq!(
+            [[4.0, 0.0, 3.0], [2.0, 6.0, 0.0], [0.0, 2.0, 12.0]],
+            multiply(&M, &diagonal((2.0, 2.0, 3.0)))
+        );
+    }
+
+    #[test]
+    fn inverse_() {
+        let inverted = inverse(&M).unwrap();
+        assert_approx_equal(&IDENTITY, &multiply(&M, &inverted));
+        assert_approx_equal(&IDENTITY, &multiply(&inverted, &M));
+    }
+
+    #[test]
+    fn inverse_singular() {
+        assert_eq!(
+            None,
+            inverse(&[[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 0.0]])
+        );
+    }
+}
@@ -2,11 +2,21 @@ use std::fmt::{Debug, Display};
 
 /// The HSV color model
 pub mod hsv;
+/// The ICtCp color model
+pub mod ictcp;
+/// The Jzazbz color model
+pub mod jzazbz;
+/// The CIELAB color model
+pub mod lab;
+/// The Oklab color model
+pub mod oklab;
 /// The RGB color model
 pub mod rgb;
+/// The CIE 1931 XYZ color model
+pub mod xyz;
 
 /// Collection of basic methods every color (regardless of model) should have
-pub trait Color: Clone + PartialEq + Debug + Display + Default  {
+pub trait Color: Clone + PartialEq + Debug + Display + Default {
     /// Returns if color is (absolute) white
     fn is_white(&self) -> bool;
 
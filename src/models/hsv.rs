@@ -7,7 +7,6 @@ use std::fmt::{Display, Formatter, Result};
 /// [RGBColor]: crate::models::rgb::RGB
 /// [RGB24]: crate::models::rgb::rgb24::RGB
 /// [RGB48]: crate::models::rgb::rgb48::RGB48
-
 /// HSV color - based on *hue, saturation, value*
 ///
 /// Suitable for different color depths
@@ -15,7 +14,11 @@ use std::fmt::{Display, Formatter, Result};
 /// # Type parameters
 /// - `T`: the base type for each channel
 pub trait HSVColor<T>: Color {
-    // Used for the precision of equality between to HSV Colors
+    /// A precision of the channels
+    ///
+    /// Not used by this crate, compare with [`ApproxEq`](crate::approx::ApproxEq) and a
+    /// [`Tolerance`](crate::approx::Tolerance) instead.
+    #[deprecated(note = "use `ApproxEq::approx_eq` with a `Tolerance` instead")]
     const EPSILON: T;
 
     /// 100% white
@@ -94,7 +97,7 @@ pub trait HSVColor<T>: Color {
 /// - `h`: **hue** in degrees (0.0 - 360.0)
 /// - `s`: **saturation** as fraction (0.0 - 1.0)
 /// - `v`: **value** as fraction (0.0 - 1.0)
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HSV {
     h: f64,
@@ -188,11 +191,11 @@ impl HSVColor<f64> for HSV {
     ///
     /// # Parameters
     /// - `h`: **hue**. Expects `0 <= h < 360`.
-    ///     Values outside of that range will be transformed using modulo.
+    ///   Values outside of that range will be transformed using modulo.
     /// - `s`: **saturation**. Expects `0 <= s <= 1`.
-    ///     Values greater than 1 will be straightened to 1. Values lower than 0 will be straightened to 0.
+    ///   Values greater than 1 will be straightened to 1. Values lower than 0 will be straightened to 0.
     /// - `v`: **value**. Expects `0 <= s <= 1`.
-    ///     Values greater than 1 will be straightened to 1. Values lower than 0 will be straightened to 0.
+    ///   Values greater than 1 will be straightened to 1. Values lower than 0 will be straightened to 0.
     ///
     /// # Panics
     /// - if one of the values is NaN
@@ -263,18 +266,6 @@ impl Color for HSV {
     }
 }
 
-impl PartialEq for HSV {
-    /// Checks if both colors are equal.
-    ///
-    /// Since this uses f64 it will check against [EPSILON](HSVColor::EPSILON)
-    fn eq(&self, other: &Self) -> bool {
-        // Compare floating points
-        number_utils::approx_equal_f64(self.h, other.h, HSV::EPSILON)
-            && number_utils::approx_equal_f64(self.s, other.s, HSV::EPSILON)
-            && number_utils::approx_equal_f64(self.v, other.v, HSV::EPSILON)
-    }
-}
-
 impl Display for HSV {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(f, "(H:{}, S:{}, V:{})", self.h, self.s, self.v)
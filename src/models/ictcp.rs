@@ -0,0 +1,198 @@
+use crate::converter;
+use crate::models::rgb::linear::LinearRgb;
+use crate::models::xyz::XYZ;
+use crate::models::Color;
+use std::fmt::{Display, Formatter, Result};
+
+/// [ICtCp](https://en.wikipedia.org/wiki/ICtCp) color (ITU-R BT.2100) - based on *intensity, blue-yellow, red-green*
+///
+/// Each channel is stored as `f64`
+///
+/// - `i`: **intensity** (0.0 - 1.0)
+/// - `ct`: **blue (negative) - yellow (positive)**, roughly -0.5 - 0.5
+/// - `cp`: **green (negative) - red (positive)**, roughly -0.5 - 0.5
+///
+/// The values are PQ encoded based on absolute luminance in cd/m² (nits) unless stated otherwise.
+/// The HLG variant (relative scene light) is available via [`Ictcp::from_rec2020_hlg`] and
+/// [`Ictcp::to_rec2020_hlg`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ictcp {
+    i: f64,
+    ct: f64,
+    cp: f64,
+}
+
+impl Ictcp {
+    /// Creates a new `Ictcp` from the given values
+    pub fn new(i: f64, ct: f64, cp: f64) -> Self {
+        Ictcp { i, ct, cp }
+    }
+
+    /// Converts the given [`XYZ`] (relative to D65, absolute in cd/m²) -> `Ictcp` (PQ)
+    ///
+    /// # Example
+    /// ```
+    /// use colorful::space::{pq_encode_nits, REFERENCE_WHITE_LUMINANCE};
+    /// use colorful::{Ictcp, XYZ};
+    ///
+    /// let white = XYZ::from_xyy(0.3127, 0.3290, REFERENCE_WHITE_LUMINANCE);
+    /// let ictcp = Ictcp::from_xyz(&white);
+    /// assert!((ictcp.i() - pq_encode_nits(REFERENCE_WHITE_LUMINANCE)).abs() < 1e-9);
+    /// assert!(ictcp.ct().abs() < 1e-9 && ictcp.cp().abs() < 1e-9);
+    /// ```
+    pub fn from_xyz(xyz: &XYZ) -> Self {
+        Ictcp::from(converter::xyz_to_ictcp(xyz))
+    }
+
+    /// Converts this (PQ) -> [`XYZ`] (relative to D65, absolute in cd/m²)
+    pub fn to_xyz(&self) -> XYZ {
+        converter::ictcp_to_xyz(self.as_tuple())
+    }
+
+    /// Converts the given linear Rec. 2020 values (absolute in cd/m²) -> `Ictcp` (PQ)
+    pub fn from_rec2020(linear: &LinearRgb) -> Self {
+        Ictcp::from(converter::rec2020_to_ictcp(linear.as_tuple()))
+    }
+
+    /// Converts this (PQ) -> linear Rec. 2020 values (absolute in cd/m²)
+    pub fn to_rec2020(&self) -> LinearRgb {
+        LinearRgb::from(converter::ictcp_to_rec2020(self.as_tuple()))
+    }
+
+    /// Converts the given linear Rec. 2020 scene light (0.0 - 1.0) -> `Ictcp` (HLG)
+    pub fn from_rec2020_hlg(linear: &LinearRgb) -> Self {
+        Ictcp::from(converter::rec2020_to_ictcp_hlg(linear.as_tuple()))
+    }
+
+    /// Converts this (HLG) -> linear Rec. 2020 scene light (0.0 - 1.0)
+    pub fn to_rec2020_hlg(&self) -> LinearRgb {
+        LinearRgb::from(converter::ictcp_hlg_to_rec2020(self.as_tuple()))
+    }
+
+    /// Returns the value of channel **I** (intensity)
+    pub fn i(&self) -> f64 {
+        self.i
+    }
+
+    /// Returns the value of channel **Ct** (blue - yellow)
+    pub fn ct(&self) -> f64 {
+        self.ct
+    }
+
+    /// Returns the value of channel **Cp** (green - red)
+    pub fn cp(&self) -> f64 {
+        self.cp
+    }
+
+    /// Converts values to tuple
+    ///
+    /// # Returns
+    /// Values as tuple (I, Ct, Cp)
+    pub fn as_tuple(&self) -> (f64, f64, f64) {
+        (self.i, self.ct, self.cp)
+    }
+}
+
+impl From<(f64, f64, f64)> for Ictcp {
+    fn from(ictcp: (f64, f64, f64)) -> Self {
+        Ictcp::new(ictcp.0, ictcp.1, ictcp.2)
+    }
+}
+
+impl Display for Ictcp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "(I:{}, Ct:{}, Cp:{})", self.i, self.ct, self.cp)
+    }
+}
+
+impl Color for Ictcp {
+    /// Returns if this is the peak white of the signal
+    fn is_white(&self) -> bool {
+        self == &Ictcp::new(1.0, 0.0, 0.0)
+    }
+
+    fn is_black(&self) -> bool {
+        self == &Ictcp::default()
+    }
+}
+
+impl Default for Ictcp {
+    /// Creates a new `Ictcp`, setting all values to zero
+    ///
+    /// This is *black*.
+    fn default() -> Self {
+        Ictcp::new(0.0, 0.0, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::ictcp::Ictcp;
+    use crate::models::rgb::linear::LinearRgb;
+    use crate::models::xyz::XYZ;
+    use crate::models::Color;
+    use crate::space::{pq_encode_nits, RgbSpace};
+
+    #[test]
+    fn from_rec2020_() {
+        // the reference white of ITU-R BT.2408: 58% PQ, 75% HLG
+        let white = Ictcp::from_rec2020(&LinearRgb::new(203.0, 203.0, 203.0));
+        assert!((white.i() - 0.5807).abs() < 1e-4);
+        assert!(white.ct().abs() < 1e-12 && white.cp().abs() < 1e-12);
+        let white = Ictcp::from_rec2020_hlg(&LinearRgb::new(0.2651, 0.2651, 0.2651));
+        assert!((white.i() - 0.75).abs() < 1e-3);
+
+        assert!(Ictcp::from_rec2020(&LinearRgb::new(10000.0, 10000.0, 10000.0)).is_white());
+        // PQ doesn't encode zero exactly
+        assert!(Ictcp::from_rec2020(&LinearRgb::default()).i() < 1e-6);
+
+        // red is positive Cp, blue is negative Ct
+        let red = Ictcp::from_rec2020(&LinearRgb::new(100.0, 0.0, 0.0));
+        assert!(red.cp() > 0.0);
+        let blue = Ictcp::from_rec2020(&LinearRgb::new(0.0, 0.0, 100.0));
+        assert!(blue.ct() > 0.0 && blue.cp() < 0.0);
+        let expected = pq_encode_nits(262.0 / 40.96);
+        assert!((blue.i() - (pq_encode_nits(462.0 / 40.96) + expected) / 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn round_trip() {
+        let linear = LinearRgb::new(400.0, 20.0, 1.0);
+        let back = Ictcp::from_rec2020(&linear).to_rec2020();
+        assert!((linear.r() - back.r()).abs() < 1e-9);
+        assert!((linear.g() - back.g()).abs() < 1e-9);
+        assert!((linear.b() - back.b()).abs() < 1e-9);
+
+        let linear = LinearRgb::new(0.4, 0.2, 0.01);
+        let back = Ictcp::from_rec2020_hlg(&linear).to_rec2020_hlg();
+        assert!((linear.r() - back.r()).abs() < 1e-12);
+        assert!((linear.g() - back.g()).abs() < 1e-12);
+        assert!((linear.b() - back.b()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn xyz() {
+        let linear = LinearRgb::new(80.0, 120.0, 30.0);
+        let xyz = RgbSpace::rec2020().linear_to_xyz(&linear);
+        let ictcp = Ictcp::from_xyz(&xyz);
+        let expected = Ictcp::from_rec2020(&linear);
+        assert!((ictcp.i() - expected.i()).abs() < 1e-12);
+        assert!((ictcp.ct() - expected.ct()).abs() < 1e-12);
+        assert!((ictcp.cp() - expected.cp()).abs() < 1e-12);
+
+        let back = ictcp.to_xyz();
+        assert!((xyz.x() - back.x()).abs() < 1e-9);
+        assert!((xyz.y() - back.y()).abs() < 1e-9);
+        assert!((xyz.z() - back.z()).abs() < 1e-9);
+        assert!(Ictcp::from_xyz(&XYZ::default()).i() < 1e-6);
+    }
+
+    #[test]
+    fn fmt_() {
+        assert_eq!(
+            "(I:0.5, Ct:0, Cp:-0.1)",
+            format!("{}", Ictcp::new(0.5, 0.0, -0.1))
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use crate::converter;
+use crate::models::rgb::linear::LinearRgb;
+use crate::models::xyz::XYZ;
+use crate::models::Color;
+use crate::space::RgbSpace;
+use std::fmt::{Display, Formatter, Result};
+
+/// [Jzazbz](https://doi.org/10.1364/OE.25.015131) color - a perceptual color model for HDR
+///
+/// Each channel is stored as `f64`
+///
+/// - `jz`: **lightness** (0.0 - 1.0, white of 203 cd/m² is about 0.22)
+/// - `az`: **green (negative) - red (positive)**, roughly -0.2 - 0.2
+/// - `bz`: **blue (negative) - yellow (positive)**, roughly -0.2 - 0.2
+///
+/// The values are based on absolute luminance in cd/m² (nits) relative to D65.
+/// The polar form JzCzhz (*lightness, chroma, hue*) is available via [`Jzazbz::from_jzczhz`]
+/// and [`Jzazbz::to_jzczhz`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Jzazbz {
+    jz: f64,
+    az: f64,
+    bz: f64,
+}
+
+impl Jzazbz {
+    /// Creates a new `Jzazbz` from the given values
+    pub fn new(jz: f64, az: f64, bz: f64) -> Self {
+        Jzazbz { jz, az, bz }
+    }
+
+    /// Creates a new `Jzazbz` from the given polar values (JzCzhz)
+    ///
+    /// # Parameters
+    /// - `jz`: **lightness**
+    /// - `cz`: **chroma** (distance to gray)
+    /// - `hz`: **hue** in degrees (0.0 - 360.0)
+    pub fn from_jzczhz(jz: f64, cz: f64, hz: f64) -> Self {
+        let (az, bz) = converter::from_polar(cz, hz);
+        Jzazbz::new(jz, az, bz)
+    }
+
+    /// Converts the given [`XYZ`] (relative to D65, absolute in cd/m²) -> `Jzazbz`
+    pub fn from_xyz(xyz: &XYZ) -> Self {
+        Jzazbz::from(converter::xyz_to_jzazbz(xyz))
+    }
+
+    /// Converts this -> [`XYZ`] (relative to D65, absolute in cd/m²)
+    pub fn to_xyz(&self) -> XYZ {
+        converter::jzazbz_to_xyz(self.as_tuple())
+    }
+
+    /// Converts the given linear Rec. 2020 values (absolute in cd/m²) -> `Jzazbz`
+    pub fn from_rec2020(linear: &LinearRgb) -> Self {
+        Jzazbz::from_xyz(&RgbSpace::rec2020().linear_to_xyz(linear))
+    }
+
+    /// Converts this -> linear Rec. 2020 values (absolute in cd/m²)
+    pub fn to_rec2020(&self) -> LinearRgb {
+        RgbSpace::rec2020().xyz_to_linear(&self.to_xyz())
+    }
+
+    /// Returns the value of channel **Jz** (lightness)
+    pub fn jz(&self) -> f64 {
+        self.jz
+    }
+
+    /// Returns the value of channel **az** (green - red)
+    pub fn az(&self) -> f64 {
+        self.az
+    }
+
+    /// Returns the value of channel **bz** (blue - yellow)
+    pub fn bz(&self) -> f64 {
+        self.bz
+    }
+
+    /// Returns the **chroma** Cz (distance to gray)
+    pub fn chroma(&self) -> f64 {
+        self.az.hypot(self.bz)
+    }
+
+    /// Returns the **hue** hz in degrees (0.0 - 360.0)
+    pub fn hue(&self) -> f64 {
+        converter::to_polar(self.az, self.bz).1
+    }
+
+    /// Converts values to tuple
+    ///
+    /// # Returns
+    /// Values as tuple (Jz, az, bz)
+    pub fn as_tuple(&self) -> (f64, f64, f64) {
+        (self.jz, self.az, self.bz)
+    }
+
+    /// Converts values to the polar form (JzCzhz)
+    ///
+    /// # Returns
+    /// Values as tuple (Jz, Cz, hz)
+    pub fn to_jzczhz(&self) -> (f64, f64, f64) {
+        let (cz, hz) = converter::to_polar(self.az, self.bz);
+        (self.jz, cz, hz)
+    }
+}
+
+impl From<(f64, f64, f64)> for Jzazbz {
+    fn from(jzazbz: (f64, f64, f64)) -> Self {
+        Jzazbz::new(jzazbz.0, jzazbz.1, jzazbz.2)
+    }
+}
+
+impl Display for Jzazbz {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "(Jz:{}, az:{}, bz:{})", self.jz, self.az, self.bz)
+    }
+}
+
+impl Color for Jzazbz {
+    /// Returns if this is the peak white of 10000 cd/m²
+    fn is_white(&self) -> bool {
+        self == &Jzazbz::from_xyz(&XYZ::from_xyy(0.3127, 0.3290, 10000.0))
+    }
+
+    fn is_black(&self) -> bool {
+        self == &Jzazbz::default()
+    }
+}
+
+impl Default for Jzazbz {
+    /// Creates a new `Jzazbz`, setting all values to zero
+    ///
+    /// This is *black*.
+    fn default() -> Self {
+        Jzazbz::new(0.0, 0.0, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::jzazbz::Jzazbz;
+    use crate::models::rgb::linear::LinearRgb;
+    use crate::models::xyz::XYZ;
+    use crate::models::Color;
+    use crate::space::REFERENCE_WHITE_LUMINANCE;
+
+    #[test]
+    fn from_xyz_() {
+        // reference values of colour-science (absolute XYZ in cd/m²)
+        let references = [
+            (
+                (0.206_540_08, 0.121_972_25, 0.051_369_52),
+                (0.005_350_48, 0.009_243_02, 0.005_260_07),
+            ),
+            (
+                (0.142_220_10, 0.230_427_68, 0.104_957_72),
+                (0.006_196_81, -0.006_084_26, 0.005_340_77),
+            ),
+            (
+                (0.078_187_80, 0.061_572_01, 0.280_993_26),
+                (0.003_093_45, -0.000_668_32, -0.009_429_48),
+            ),
+        ];
+        for (xyz, expected) in references {
+            let jzazbz = Jzazbz::from_xyz(&XYZ::from(xyz));
+            assert!((expected.0 - jzazbz.jz()).abs() < 1e-8, "{}", jzazbz);
+            assert!((expected.1 - jzazbz.az()).abs() < 1e-8, "{}", jzazbz);
+            assert!((expected.2 - jzazbz.bz()).abs() < 1e-8, "{}", jzazbz);
+        }
+
+        // D65 white is almost achromatic
+        let white = Jzazbz::from_xyz(&XYZ::from_xyy(0.3127, 0.3290, REFERENCE_WHITE_LUMINANCE));
+        assert!(white.chroma() < 5e-4);
+        assert!(Jzazbz::from_xyz(&XYZ::from_xyy(0.3127, 0.3290, 10000.0)).is_white());
+    }
+
+    #[test]
+    fn round_trip() {
+        let xyz = XYZ::new(50.0, 60.0, 20.0);
+        let back = Jzazbz::from_xyz(&xyz).to_xyz();
+        assert!((xyz.x() - back.x()).abs() < 1e-9);
+        assert!((xyz.y() - back.y()).abs() < 1e-9);
+        assert!((xyz.z() - back.z()).abs() < 1e-9);
+
+        let linear = LinearRgb::new(400.0, 20.0, 1.0);
+        let back = Jzazbz::from_rec2020(&linear).to_rec2020();
+        assert!((linear.r() - back.r()).abs() < 1e-9);
+        assert!((linear.g() - back.g()).abs() < 1e-9);
+        assert!((linear.b() - back.b()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn jzczhz() {
+        let jzazbz = Jzazbz::from_jzczhz(0.1, 0.05, 270.0);
+        assert!(jzazbz.az().abs() < 1e-12);
+        assert!((jzazbz.bz() + 0.05).abs() < 1e-12);
+        let (jz, cz, hz) = jzazbz.to_jzczhz();
+        assert_eq!(0.1, jz);
+        assert!((cz - 0.05).abs() < 1e-12);
+        assert!((hz - 270.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fmt_() {
+        assert_eq!(
+            "(Jz:0.5, az:0, bz:-0.1)",
+            format!("{}", Jzazbz::new(0.5, 0.0, -0.1))
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use crate::converter;
+use crate::models::rgb::RGBColor;
+use crate::models::xyz::XYZ;
+use crate::models::Color;
+use crate::space::{self, RgbSpace};
+use std::fmt::{Display, Formatter, Result};
+
+/// CIELAB color - based on *lightness, green-red, blue-yellow*
+///
+/// Each channel is stored as `f64`
+///
+/// - `l`: **lightness** (0.0 - 100.0)
+/// - `a`: **green (negative) - red (positive)**, roughly -128.0 - 128.0
+/// - `b`: **blue (negative) - yellow (positive)**, roughly -128.0 - 128.0
+///
+/// The values are relative to the white point D65 (the white of sRGB) unless stated otherwise.
+/// The polar form LCh (*lightness, chroma, hue*) is available via [`Lab::from_lch`] and [`Lab::to_lch`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Lab {
+    l: f64,
+    a: f64,
+    b: f64,
+}
+
+impl Lab {
+    /// Creates a new `Lab` from the given values
+    pub fn new(l: f64, a: f64, b: f64) -> Self {
+        Lab { l, a, b }
+    }
+
+    /// Creates a new `Lab` from the given polar values (LCh)
+    ///
+    /// # Parameters
+    /// - `l`: **lightness** (0.0 - 100.0)
+    /// - `c`: **chroma** (distance to gray)
+    /// - `h`: **hue** in degrees (0.0 - 360.0)
+    pub fn from_lch(l: f64, c: f64, h: f64) -> Self {
+        let (a, b) = converter::from_polar(c, h);
+        Lab::new(l, a, b)
+    }
+
+    /// Converts the given [`RGBColor`] (sRGB) -> `Lab`
+    pub fn from_rgb<T>(color: &impl RGBColor<T>) -> Self {
+        Lab::from_xyz(&RgbSpace::srgb().to_xyz(color))
+    }
+
+    /// Converts this to the given [`RGBColor`] (sRGB)
+    ///
+    /// Out of gamut values are clamped, see [`gamut`](crate::gamut) for better strategies.
+    pub fn to_rgb<S: RGBColor<U>, U>(&self) -> S {
+        RgbSpace::srgb().from_xyz(&self.to_xyz())
+    }
+
+    /// Converts the given [`XYZ`] (relative to D65) -> `Lab`
+    pub fn from_xyz(xyz: &XYZ) -> Self {
+        Lab::from_xyz_with_white(xyz, space::D65)
+    }
+
+    /// Converts the given [`XYZ`] -> `Lab` relative to the given white point `(x, y)`
+    pub fn from_xyz_with_white(xyz: &XYZ, white_point: (f64, f64)) -> Self {
+        Lab::from(converter::xyz_to_lab(xyz, white_point))
+    }
+
+    /// Converts this -> [`XYZ`] (relative to D65)
+    pub fn to_xyz(&self) -> XYZ {
+        self.to_xyz_with_white(space::D65)
+    }
+
+    /// Converts this (relative to the given white point `(x, y)`) -> [`XYZ`]
+    pub fn to_xyz_with_white(&self, white_point: (f64, f64)) -> XYZ {
+        converter::lab_to_xyz(self.as_tuple(), white_point)
+    }
+
+    /// Returns the value of channel **L** (lightness)
+    pub fn l(&self) -> f64 {
+        self.l
+    }
+
+    /// Returns the value of channel **a** (green - red)
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    /// Returns the value of channel **b** (blue - yellow)
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+
+    /// Returns the **chroma** (distance to gray)
+    pub fn chroma(&self) -> f64 {
+        self.a.hypot(self.b)
+    }
+
+    /// Returns the **hue** in degrees (0.0 - 360.0)
+    pub fn hue(&self) -> f64 {
+        converter::to_polar(self.a, self.b).1
+    }
+
+    /// Converts values to tuple
+    ///
+    /// # Returns
+    /// Values as tuple (L, a, b)
+    pub fn as_tuple(&self) -> (f64, f64, f64) {
+        (self.l, self.a, self.b)
+    }
+
+    /// Converts values to the polar form
+    ///
+    /// # Returns
+    /// Values as tuple (L, C, h)
+    pub fn to_lch(&self) -> (f64, f64, f64) {
+        let (c, h) = converter::to_polar(self.a, self.b);
+        (self.l, c, h)
+    }
+}
+
+impl From<(f64, f64, f64)> for Lab {
+    fn from(lab: (f64, f64, f64)) -> Self {
+        Lab::new(lab.0, lab.1, lab.2)
+    }
+}
+
+impl Display for Lab {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "(L:{}, a:{}, b:{})", self.l, self.a, self.b)
+    }
+}
+
+impl Color for Lab {
+    fn is_white(&self) -> bool {
+        self == &Lab::new(100.0, 0.0, 0.0)
+    }
+
+    fn is_black(&self) -> bool {
+        self == &Lab::default()
+    }
+}
+
+impl Default for Lab {
+    /// Creates a new `Lab`, setting all values to zero
+    ///
+    /// This is *black*.
+    fn default() -> Self {
+        Lab::new(0.0, 0.0, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::lab::Lab;
+    use crate::models::rgb::RGBColor;
+    use crate::models::xyz::XYZ;
+    use crate::models::Color;
+    use crate::{RGB24, RGB48};
+
+    #[test]
+    fn from_rgb_() {
+        let red = Lab::from_rgb(&RGB24::RED);
+        assert!((red.l() - 53.2371).abs() < 1e-3);
+        assert!((red.a() - 80.0901).abs() < 1e-3);
+        assert!((red.b() - 67.2033).abs() < 1e-3);
+        assert!(Lab::from_rgb(&RGB48::BLACK).is_black());
+
+        // the white of sRGB is exactly the reference white
+        let white = Lab::from_rgb(&RGB24::WHITE);
+        assert!((white.l() - 100.0).abs() < 1e-12);
+        assert!(white.a().abs() < 1e-12 && white.b().abs() < 1e-12);
+    }
+
+    #[test]
+    fn to_rgb_() {
+        for color in [RGB24::WHITE, RGB24::RED, RGB24::from_rgb(12, 200, 99)] {
+            assert_eq!(color, Lab::from_rgb(&color).to_rgb());
+        }
+        assert_eq!(RGB24::WHITE, Lab::new(120.0, 0.0, 0.0).to_rgb());
+    }
+
+    #[test]
+    fn xyz() {
+        let white = Lab::from_xyz(&XYZ::from_xyy(0.3127, 0.3290, 1.0));
+        assert!((white.l() - 100.0).abs() < 1e-12);
+        assert!(white.a().abs() < 1e-12 && white.b().abs() < 1e-12);
+
+        let d50 = (0.3457, 0.3585);
+        let xyz = XYZ::new(0.2, 0.3, 0.1);
+        let back = Lab::from_xyz_with_white(&xyz, d50).to_xyz_with_white(d50);
+        assert!((xyz.x() - back.x()).abs() < 1e-12);
+        assert!((xyz.z() - back.z()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn lch() {
+        let lab = Lab::from_lch(50.0, 20.0, 90.0);
+        assert!(lab.a().abs() < 1e-12);
+        assert!((lab.b() - 20.0).abs() < 1e-12);
+        let (l, c, h) = Lab::new(50.0, -10.0, 0.0).to_lch();
+        assert_eq!((50.0, 10.0, 180.0), (l, c, h));
+        assert_eq!(10.0, Lab::new(50.0, 6.0, -8.0).chroma());
+        assert!(Lab::new(50.0, 0.0, -8.0).hue() == 270.0);
+    }
+
+    #[test]
+    fn fmt_() {
+        assert_eq!(
+            "(L:50, a:-1.5, b:2)",
+            format!("{}", Lab::new(50.0, -1.5, 2.0))
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use crate::converter;
+use crate::models::rgb::linear::LinearRgb;
+use crate::models::rgb::RGBColor;
+use crate::models::xyz::XYZ;
+use crate::models::Color;
+use crate::space::RgbSpace;
+use std::fmt::{Display, Formatter, Result};
+
+/// [Oklab](https://bottosson.github.io/posts/oklab/) color - a perceptual color model
+///
+/// Each channel is stored as `f64`
+///
+/// - `l`: **lightness** (0.0 - 1.0)
+/// - `a`: **green (negative) - red (positive)**, roughly -0.4 - 0.4
+/// - `b`: **blue (negative) - yellow (positive)**, roughly -0.4 - 0.4
+///
+/// Compared to [`Lab`](crate::Lab), hue and lightness are more uniform, so Oklab is well
+/// suited for gradients and gamut mapping. The polar form Oklch (*lightness, chroma, hue*)
+/// is available via [`Oklab::from_lch`] and [`Oklab::to_lch`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Oklab {
+    l: f64,
+    a: f64,
+    b: f64,
+}
+
+impl Oklab {
+    /// Creates a new `Oklab` from the given values
+    pub fn new(l: f64, a: f64, b: f64) -> Self {
+        Oklab { l, a, b }
+    }
+
+    /// Creates a new `Oklab` from the given polar values (Oklch)
+    ///
+    /// # Parameters
+    /// - `l`: **lightness** (0.0 - 1.0)
+    /// - `c`: **chroma** (distance to gray)
+    /// - `h`: **hue** in degrees (0.0 - 360.0)
+    pub fn from_lch(l: f64, c: f64, h: f64) -> Self {
+        let (a, b) = converter::from_polar(c, h);
+        Oklab::new(l, a, b)
+    }
+
+    /// Converts the given [`RGBColor`] (sRGB) -> `Oklab`
+    pub fn from_rgb<T>(color: &impl RGBColor<T>) -> Self {
+        Oklab::from_xyz(&RgbSpace::srgb().to_xyz(color))
+    }
+
+    /// Converts this to the given [`RGBColor`] (sRGB)
+    ///
+    /// Out of gamut values are clamped, see [`gamut`](crate::gamut) for better strategies.
+    pub fn to_rgb<S: RGBColor<U>, U>(&self) -> S {
+        RgbSpace::srgb().from_xyz(&self.to_xyz())
+    }
+
+    /// Converts the given [`LinearRgb`] (sRGB primaries) -> `Oklab`
+    pub fn from_linear_rgb(linear: &LinearRgb) -> Self {
+        Oklab::from_xyz(&RgbSpace::srgb().linear_to_xyz(linear))
+    }
+
+    /// Converts this -> [`LinearRgb`] (sRGB primaries)
+    ///
+    /// Out of gamut colors result in values outside of `0.0 - 1.0`.
+    pub fn to_linear_rgb(&self) -> LinearRgb {
+        RgbSpace::srgb().xyz_to_linear(&self.to_xyz())
+    }
+
+    /// Converts the given [`XYZ`] (relative to D65) -> `Oklab`
+    pub fn from_xyz(xyz: &XYZ) -> Self {
+        Oklab::from(converter::xyz_to_oklab(xyz))
+    }
+
+    /// Converts this -> [`XYZ`] (relative to D65)
+    pub fn to_xyz(&self) -> XYZ {
+        converter::oklab_to_xyz(self.as_tuple())
+    }
+
+    /// Returns the value of channel **L** (lightness)
+    pub fn l(&self) -> f64 {
+        self.l
+    }
+
+    /// Returns the value of channel **a** (green - red)
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    /// Returns the value of channel **b** (blue - yellow)
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+
+    /// Returns the **chroma** (distance to gray)
+    pub fn chroma(&self) -> f64 {
+        self.a.hypot(self.b)
+    }
+
+    /// Returns the **hue** in degrees (0.0 - 360.0)
+    pub fn hue(&self) -> f64 {
+        converter::to_polar(self.a, self.b).1
+    }
+
+    /// Converts values to tuple
+    ///
+    /// # Returns
+    /// Values as tuple (L, a, b)
+    pub fn as_tuple(&self) -> (f64, f64, f64) {
+        (self.l, self.a, self.b)
+    }
+
+    /// Converts values to the polar form (Oklch)
+    ///
+    /// # Returns
+    /// Values as tuple (L, C, h)
+    pub fn to_lch(&self) -> (f64, f64, f64) {
+        let (c, h) = converter::to_polar(self.a, self.b);
+        (self.l, c, h)
+    }
+}
+
+impl From<(f64, f64, f64)> for Oklab {
+    fn from(oklab: (f64, f64, f64)) -> Self {
+        Oklab::new(oklab.0, oklab.1, oklab.2)
+    }
+}
+
+impl Display for Oklab {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "(L:{}, a:{}, b:{})", self.l, self.a, self.b)
+    }
+}
+
+impl Color for Oklab {
+    fn is_white(&self) -> bool {
+        self == &Oklab::new(1.0, 0.0, 0.0)
+    }
+
+    fn is_black(&self) -> bool {
+        self == &Oklab::default()
+    }
+}
+
+impl Default for Oklab {
+    /// Creates a new `Oklab`, setting all values to zero
+    ///
+    /// This is *black*.
+    fn default() -> Self {
+        Oklab::new(0.0, 0.0, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::oklab::Oklab;
+    use crate::models::rgb::linear::LinearRgb;
+    use crate::models::rgb::RGBColor;
+    use crate::models::Color;
+    use crate::RGB24;
+
+    #[test]
+    fn from_rgb_() {
+        let white = Oklab::from_rgb(&RGB24::WHITE);
+        assert!((white.l() - 1.0).abs() < 1e-6);
+        assert!(white.chroma() < 1e-6);
+        assert!(Oklab::from_rgb(&RGB24::BLACK).is_black());
+
+        // reference of CSS Color 4: oklch(62.8% 0.2577 29.23)
+        let (l, c, h) = Oklab::from_rgb(&RGB24::RED).to_lch();
+        assert!((l - 0.627_955).abs() < 1e-5);
+        assert!((c - 0.257_683).abs() < 1e-5);
+        assert!((h - 29.2339).abs() < 1e-3);
+    }
+
+    #[test]
+    fn to_rgb_() {
+        for color in [RGB24::WHITE, RGB24::BLUE, RGB24::from_rgb(12, 200, 99)] {
+            assert_eq!(color, Oklab::from_rgb(&color).to_rgb());
+        }
+    }
+
+    #[test]
+    fn linear_rgb() {
+        let linear = LinearRgb::new(0.2, 0.5, 0.9);
+        let back = Oklab::from_linear_rgb(&linear).to_linear_rgb();
+        assert!((linear.r() - back.r()).abs() < 1e-12);
+        assert!((linear.g() - back.g()).abs() < 1e-12);
+        assert!((linear.b() - back.b()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn lch() {
+        let oklab = Oklab::from_lch(0.5, 0.1, 180.0);
+        assert!((oklab.a() + 0.1).abs() < 1e-12);
+        assert!(oklab.b().abs() < 1e-12);
+        assert!((oklab.hue() - 180.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn fmt_() {
+        assert_eq!(
+            "(L:0.5, a:0, b:-0.1)",
+            format!("{}", Oklab::new(0.5, 0.0, -0.1))
+        );
+    }
+}
@@ -1,6 +1,9 @@
+use crate::converter;
 use crate::models::hsv::HSV;
 use crate::Color;
 
+/// The linear RGB color model
+pub mod linear;
 /// The RGB color model (24-bit)
 pub mod rgb24;
 /// The RGB color model (48-bit)
@@ -84,4 +87,15 @@ pub trait RGBColor<T>: Color {
 
     /// Converts this to `HSV`
     fn to_hsv(&self) -> HSV;
+
+    /// Returns the relative luminance as defined by WCAG 2.x (0.0 - 1.0)
+    ///
+    /// The channels are linearized with the sRGB transfer function,
+    /// see [`contrast`](crate::contrast) for contrast ratios.
+    fn relative_luminance(&self) -> f64
+    where
+        Self: Sized,
+    {
+        converter::rgb_to_linear(self).luminance()
+    }
 }
@@ -0,0 +1,272 @@
+use crate::models::rgb::RGBColor;
+use crate::models::Color;
+use crate::{converter, RGB24, RGB48};
+use std::fmt::{Display, Formatter, Result};
+
+/// Linear RGB color - based on *red, green, blue* light intensities
+///
+/// Unlike [`RGB24`] and [`RGB48`], which store gamma encoded
+/// [sRGB](https://en.wikipedia.org/wiki/SRGB) values, the channels are proportional
+/// to the amount of light. This is required for physically correct calculations
+/// like blending, scaling or luminance.
+///
+/// Each channel is stored as `f64` where `0.0` is no light and `1.0` is the maximum of sRGB.
+/// Values outside of this range are kept, so the color may be out of gamut.
+///
+/// Unless stated otherwise the channels refer to the sRGB primaries. An
+/// [`RgbSpace`](crate::space::RgbSpace) uses `LinearRgb` for the linear values of its own primaries.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinearRgb {
+    r: f64,
+    g: f64,
+    b: f64,
+}
+
+impl LinearRgb {
+    /// 100% white
+    pub const WHITE: LinearRgb = LinearRgb {
+        r: 1.0,
+        g: 1.0,
+        b: 1.0,
+    };
+
+    /// 100% black
+    pub const BLACK: LinearRgb = LinearRgb {
+        r: 0.0,
+        g: 0.0,
+        b: 0.0,
+    };
+
+    /// Creates a new `LinearRgb` from the given linear values
+    pub fn new(r: f64, g: f64, b: f64) -> Self {
+        LinearRgb { r, g, b }
+    }
+
+    /// Decodes the given sRGB color using the exact sRGB transfer function
+    pub fn from_rgb<T>(color: &impl RGBColor<T>) -> Self {
+        converter::rgb_to_linear(color)
+    }
+
+    /// Encodes this to the given [`RGBColor`] using the exact sRGB transfer function
+    ///
+    /// Out of gamut values are clamped.
+    pub fn to_rgb<S: RGBColor<U>, U>(&self) -> S {
+        converter::linear_to_rgb(self)
+    }
+
+    /// Encodes this to [`RGB24`]
+    ///
+    /// Uses a lookup table, so this is faster than [to_rgb](#method.to_rgb) but yields the same result.
+    pub fn to_rgb24(&self) -> RGB24 {
+        converter::linear_to_rgb24(self)
+    }
+
+    /// Encodes this to [`RGB48`]
+    pub fn to_rgb48(&self) -> RGB48 {
+        self.to_rgb()
+    }
+
+    /// Returns the value of channel **R** (red)
+    pub fn r(&self) -> f64 {
+        self.r
+    }
+
+    /// Returns the value of channel **G** (green)
+    pub fn g(&self) -> f64 {
+        self.g
+    }
+
+    /// Returns the value of channel **B** (blue)
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+
+    /// Sets the value of channel **R** (red)
+    pub fn set_r(&mut self, r: f64) {
+        self.r = r;
+    }
+
+    /// Sets the value of channel **G** (green)
+    pub fn set_g(&mut self, g: f64) {
+        self.g = g;
+    }
+
+    /// Sets the value of channel **B** (blue)
+    pub fn set_b(&mut self, b: f64) {
+        self.b = b;
+    }
+
+    /// Converts this to an RGB tuple
+    pub fn as_tuple(&self) -> (f64, f64, f64) {
+        (self.r, self.g, self.b)
+    }
+
+    /// Returns the relative luminance `Y` (0.0 - 1.0 for colors in gamut)
+    ///
+    /// Uses the coefficients of the sRGB (Rec. 709) primaries.
+    pub fn luminance(&self) -> f64 {
+        0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b
+    }
+
+    /// Mixes this with another color
+    ///
+    /// # Parameters
+    /// - `other`: the color to mix with
+    /// - `t`: the fraction of `other` (`0.0` => `self`, `1.0` => `other`)
+    pub fn mix(&self, other: &LinearRgb, t: f64) -> Self {
+        LinearRgb::new(
+            self.r + (other.r - self.r) * t,
+            self.g + (other.g - self.g) * t,
+            self.b + (other.b - self.b) * t,
+        )
+    }
+
+    /// Scales the intensity of all channels by the given factor
+    pub fn scale(&self, factor: f64) -> Self {
+        LinearRgb::new(self.r * factor, self.g * factor, self.b * factor)
+    }
+}
+
+impl From<RGB24> for LinearRgb {
+    /// Decodes the given color using a lookup table
+    fn from(rgb: RGB24) -> Self {
+        converter::rgb24_to_linear(&rgb)
+    }
+}
+
+impl From<RGB48> for LinearRgb {
+    /// Decodes the given color using a lookup table
+    fn from(rgb: RGB48) -> Self {
+        converter::rgb48_to_linear(&rgb)
+    }
+}
+
+impl From<(f64, f64, f64)> for LinearRgb {
+    fn from(rgb: (f64, f64, f64)) -> Self {
+        LinearRgb::new(rgb.0, rgb.1, rgb.2)
+    }
+}
+
+impl Display for LinearRgb {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "(R:{}, G:{}, B:{})", self.r, self.g, self.b)
+    }
+}
+
+impl Color for LinearRgb {
+    fn is_white(&self) -> bool {
+        self == &Self::WHITE
+    }
+
+    fn is_black(&self) -> bool {
+        self == &Self::BLACK
+    }
+}
+
+impl Default for LinearRgb {
+    /// Creates a new `LinearRgb`, setting all values to zero
+    ///
+    /// This is *black*.
+    fn default() -> Self {
+        Self::BLACK
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_() {
+        assert_eq!(LinearRgb::BLACK, LinearRgb::default());
+        assert_eq!((0.1, 0.2, 0.3), LinearRgb::new(0.1, 0.2, 0.3).as_tuple());
+    }
+
+    #[test]
+    fn from_rgb_presets() {
+        assert_eq!(LinearRgb::WHITE, LinearRgb::from_rgb(&RGB24::WHITE));
+        assert_eq!(LinearRgb::BLACK, LinearRgb::from_rgb(&RGB48::BLACK));
+        assert_eq!(LinearRgb::new(1.0, 0.0, 0.0), LinearRgb::from(RGB24::RED));
+    }
+
+    #[test]
+    fn from_rgb24_lut_is_exact() {
+        for value in 0..=255 {
+            let color = RGB24::from_rgb(value, value, value);
+            assert_eq!(LinearRgb::from_rgb(&color), LinearRgb::from(color));
+        }
+    }
+
+    #[test]
+    fn from_rgb48_lut_is_exact() {
+        for value in (0..=u16::MAX).step_by(97) {
+            let color = RGB48::from_rgb(value, value, value);
+            assert_eq!(LinearRgb::from_rgb(&color), LinearRgb::from(color));
+        }
+    }
+
+    #[test]
+    fn to_rgb24_lut_is_exact() {
+        for step in 0..=10_000 {
+            let value = step as f64 / 10_000.0;
+            let color = LinearRgb::new(value, value, value);
+            assert_eq!(color.to_rgb::<RGB24, u8>(), color.to_rgb24());
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for value in 0..=255 {
+            let color = RGB24::from_rgb(value, 255 - value, value / 2);
+            assert_eq!(color, LinearRgb::from(color).to_rgb24());
+            assert_eq!(color.to_rgb48(), LinearRgb::from(color).to_rgb48());
+        }
+    }
+
+    #[test]
+    fn to_rgb_out_of_gamut() {
+        assert_eq!(RGB24::RED, LinearRgb::new(1.5, -0.2, f64::NAN).to_rgb24());
+        assert_eq!(RGB24::RED, LinearRgb::new(1.5, -0.2, f64::NAN).to_rgb());
+    }
+
+    #[test]
+    fn luminance_() {
+        assert_eq!(1.0, LinearRgb::WHITE.luminance());
+        assert_eq!(0.0, LinearRgb::BLACK.luminance());
+        assert_eq!(0.7152, LinearRgb::from(RGB24::GREEN).luminance());
+    }
+
+    #[test]
+    fn mix_() {
+        let gray = LinearRgb::BLACK.mix(&LinearRgb::WHITE, 0.5);
+        assert_eq!(LinearRgb::new(0.5, 0.5, 0.5), gray);
+        assert_eq!(RGB24::from_hex("bcbcbc"), gray.to_rgb24());
+        assert_eq!(
+            LinearRgb::WHITE,
+            LinearRgb::BLACK.mix(&LinearRgb::WHITE, 1.0)
+        );
+    }
+
+    #[test]
+    fn scale_() {
+        assert_eq!(
+            LinearRgb::new(0.5, 0.25, 0.0),
+            LinearRgb::new(1.0, 0.5, 0.0).scale(0.5)
+        );
+    }
+
+    #[test]
+    fn fmt_() {
+        assert_eq!(
+            "(R:1, G:0.5, B:0)",
+            format!("{}", LinearRgb::new(1.0, 0.5, 0.0))
+        );
+    }
+
+    #[test]
+    fn is_white_black() {
+        assert!(LinearRgb::WHITE.is_white());
+        assert!(LinearRgb::BLACK.is_black());
+    }
+}
@@ -1,8 +1,8 @@
-use crate::{converter, RGB48};
 use crate::models::hsv::HSV;
 use crate::models::rgb::RGBColor;
 use crate::models::Color;
 use crate::number_utils;
+use crate::{converter, LinearRgb, RGB48};
 use std::fmt::{Display, Formatter, Result};
 
 /// 24-bit RGB color
@@ -29,7 +29,7 @@ impl RGB24 {
     ///     - `aabbcc` (`rrggbb`)
     ///     - `abc` (`rgb24`)
     /// 2. Make sure the Hex contains only valid (hexademical) digits:
-    /// `0123456789abcdef`
+    ///    `0123456789abcdef`
     ///
     /// It will `panic` otherwise!
     pub fn from_hex(hex: &str) -> Self {
@@ -74,6 +74,11 @@ impl RGB24 {
         converter::rgb24_to_rgb48(self)
     }
 
+    /// Converts [`RGB24`] -> [`LinearRgb`]
+    pub fn to_linear_rgb(&self) -> LinearRgb {
+        converter::rgb24_to_linear(self)
+    }
+
     /// Converts an integer to the corresponding RGB Color
     ///
     /// **Important:** Works only for specific bases:
@@ -1,7 +1,7 @@
 use crate::models::hsv::HSV;
 use crate::models::rgb::RGBColor;
 use crate::models::Color;
-use crate::{converter, number_utils, RGB24};
+use crate::{converter, number_utils, LinearRgb, RGB24};
 use std::fmt::{Display, Formatter, Result};
 
 /// 48-bit RGB color
@@ -24,6 +24,11 @@ impl RGB48 {
     pub fn to_rgb48(&self) -> RGB24 {
         converter::rgb48_to_rgb24(self)
     }
+
+    /// Converts [`RGB48`] -> [`LinearRgb`]
+    pub fn to_linear_rgb(&self) -> LinearRgb {
+        converter::rgb48_to_linear(self)
+    }
 }
 
 impl RGBColor<u16> for RGB48 {
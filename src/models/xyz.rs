@@ -0,0 +1,117 @@
+use std::fmt::{Display, Formatter, Result};
+
+/// CIE 1931 XYZ color - the basis of all colorimetric conversions
+///
+/// Each channel is stored as `f64`, where `Y` is the luminance relative to the
+/// reference white (`Y = 1.0`). The values don't carry their reference white,
+/// see [`RgbSpace`](crate::space::RgbSpace) for the white point of a color space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct XYZ {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl XYZ {
+    /// Creates a new `XYZ` from the given values
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        XYZ { x, y, z }
+    }
+
+    /// Creates a new `XYZ` from the given chromaticity coordinates and luminance
+    ///
+    /// # Parameters
+    /// - `x`, `y`: the [chromaticity](https://en.wikipedia.org/wiki/CIE_1931_color_space#CIE_xy_chromaticity_diagram_and_the_CIE_xyY_color_space)
+    /// - `luminance`: the value of `Y`
+    pub fn from_xyy(x: f64, y: f64, luminance: f64) -> Self {
+        if y == 0.0 {
+            XYZ::new(0.0, 0.0, 0.0)
+        } else {
+            XYZ::new(x * luminance / y, luminance, (1.0 - x - y) * luminance / y)
+        }
+    }
+
+    /// Returns the value of channel **X**
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    /// Returns the value of channel **Y** (luminance)
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    /// Returns the value of channel **Z**
+    pub fn z(&self) -> f64 {
+        self.z
+    }
+
+    /// Converts values to tuple
+    ///
+    /// # Returns
+    /// Values as tuple (X, Y, Z)
+    pub fn as_tuple(&self) -> (f64, f64, f64) {
+        (self.x, self.y, self.z)
+    }
+
+    /// Returns the chromaticity coordinates `(x, y)`
+    ///
+    /// Black has no chromaticity and returns `(0.0, 0.0)`.
+    pub fn chromaticity(&self) -> (f64, f64) {
+        let sum = self.x + self.y + self.z;
+        if sum == 0.0 {
+            (0.0, 0.0)
+        } else {
+            (self.x / sum, self.y / sum)
+        }
+    }
+}
+
+impl From<(f64, f64, f64)> for XYZ {
+    fn from(xyz: (f64, f64, f64)) -> Self {
+        XYZ::new(xyz.0, xyz.1, xyz.2)
+    }
+}
+
+impl Display for XYZ {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "(X:{}, Y:{}, Z:{})", self.x, self.y, self.z)
+    }
+}
+
+impl Default for XYZ {
+    /// Creates a new `XYZ`, setting all values to zero
+    ///
+    /// This is *black*.
+    fn default() -> Self {
+        XYZ::new(0.0, 0.0, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::xyz::XYZ;
+
+    #[test]
+    fn from_xyy_() {
+        let d65 = XYZ::from_xyy(0.3127, 0.3290, 1.0);
+        assert!((d65.x() - 0.950_456).abs() < 1e-6);
+        assert_eq!(1.0, d65.y());
+        assert!((d65.z() - 1.089_058).abs() < 1e-6);
+        assert_eq!(XYZ::default(), XYZ::from_xyy(0.3, 0.0, 1.0));
+    }
+
+    #[test]
+    fn chromaticity_() {
+        let (x, y) = XYZ::from_xyy(0.3457, 0.3585, 0.5).chromaticity();
+        assert!((x - 0.3457).abs() < 1e-12);
+        assert!((y - 0.3585).abs() < 1e-12);
+        assert_eq!((0.0, 0.0), XYZ::default().chromaticity());
+    }
+
+    #[test]
+    fn fmt_() {
+        assert_eq!("(X:0.5, Y:1, Z:0)", format!("{}", XYZ::new(0.5, 1.0, 0.0)));
+    }
+}
@@ -0,0 +1,24 @@
+/// Normalizes a name for lookup
+///
+/// Removes all whitespace, dashes and underscores, converts to lowercase
+/// and replaces `grey` by `gray`.
+pub fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect::<String>()
+        .replace("grey", "gray")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::names::normalize_name;
+
+    #[test]
+    fn normalize_name_() {
+        assert_eq!("aliceblue", normalize_name(" Alice Blue"));
+        assert_eq!("ledb3", normalize_name("LED-B3"));
+        assert_eq!("deeppurple", normalize_name("deep_purple"));
+        assert_eq!("slategray", normalize_name("Slate Grey"));
+    }
+}
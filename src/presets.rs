@@ -1,12 +1,37 @@
 use crate::models::hsv::{HSVColor, HSV};
+use crate::models::rgb::rgb24::RGB24;
 use crate::models::rgb::RGBColor;
+use crate::names::normalize_name;
 
-#[cfg(test)]
-use strum_macros::EnumIter;
+/// The [Material Design 2](https://material.io/design/color/the-color-system.html) color palette
+pub mod material;
+/// The RAL Classic color collection
+pub mod ral;
+/// Palettes of retro hardware like CGA, NES or the Game Boy
+pub mod retro;
+/// Tables of named colors loaded at runtime
+pub mod table;
+/// The [Tailwind CSS](https://tailwindcss.com/docs/customizing-colors) color palette
+pub mod tailwind;
+/// The ANSI and xterm palettes of terminal emulators
+pub mod terminal;
+/// The web-safe palette
+pub mod web;
+/// The color names of the xkcd color survey
+pub mod xkcd;
+
+/// An indexed set of colors
+mod palette;
+
+pub use material::MaterialColor;
+pub use palette::Palette;
+pub use ral::RalColor;
+pub use table::{ColorTable, ParseError};
+pub use tailwind::TailwindColor;
+pub use xkcd::XkcdColor;
 
 /// Contains all standardized [X11 color names](https://en.wikipedia.org/wiki/X11_color_names)
-#[derive(Debug)]
-#[cfg_attr(test, derive(EnumIter))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum X11Color {
     AliceBlue,
     AntiqueWhite,
@@ -155,7 +180,338 @@ pub enum X11Color {
     YellowGreen,
 }
 
+/// All [`X11Color`]s in order of declaration
+const X11_COLORS: [X11Color; 145] = [
+    X11Color::AliceBlue,
+    X11Color::AntiqueWhite,
+    X11Color::Aqua,
+    X11Color::Aquamarine,
+    X11Color::Azure,
+    X11Color::Beige,
+    X11Color::Bisque,
+    X11Color::Black,
+    X11Color::BlanchedAlmond,
+    X11Color::Blue,
+    X11Color::BlueViolet,
+    X11Color::Brown,
+    X11Color::Burlywood,
+    X11Color::CadetBlue,
+    X11Color::Chartreuse,
+    X11Color::Chocolate,
+    X11Color::Coral,
+    X11Color::CornflowerBlue,
+    X11Color::Cornsilk,
+    X11Color::Crimson,
+    X11Color::Cyan,
+    X11Color::DarkBlue,
+    X11Color::DarkCyan,
+    X11Color::DarkGoldenrod,
+    X11Color::DarkGray,
+    X11Color::DarkGreen,
+    X11Color::DarkKhaki,
+    X11Color::DarkMagenta,
+    X11Color::DarkOliveGreen,
+    X11Color::DarkOrange,
+    X11Color::DarkOrchid,
+    X11Color::DarkRed,
+    X11Color::DarkSalmon,
+    X11Color::DarkSeaGreen,
+    X11Color::DarkSlateBlue,
+    X11Color::DarkSlateGray,
+    X11Color::DarkTurquoise,
+    X11Color::DarkViolet,
+    X11Color::DeepPink,
+    X11Color::DeepSkyBlue,
+    X11Color::DimGray,
+    X11Color::DodgerBlue,
+    X11Color::Firebrick,
+    X11Color::FloralWhite,
+    X11Color::ForestGreen,
+    X11Color::Fuchsia,
+    X11Color::Gainsboro,
+    X11Color::GhostWhite,
+    X11Color::Gold,
+    X11Color::Goldenrod,
+    X11Color::Gray,
+    X11Color::WebGray,
+    X11Color::Green,
+    X11Color::WebGreen,
+    X11Color::GreenYellow,
+    X11Color::Honeydew,
+    X11Color::HotPink,
+    X11Color::IndianRed,
+    X11Color::Indigo,
+    X11Color::Ivory,
+    X11Color::Khaki,
+    X11Color::Lavender,
+    X11Color::LavenderBlush,
+    X11Color::LawnGreen,
+    X11Color::LemonChiffon,
+    X11Color::LightBlue,
+    X11Color::LightCoral,
+    X11Color::LightCyan,
+    X11Color::LightGoldenrod,
+    X11Color::LightGray,
+    X11Color::LightGreen,
+    X11Color::LightPink,
+    X11Color::LightSalmon,
+    X11Color::LightSeaGreen,
+    X11Color::LightSkyBlue,
+    X11Color::LightSlateGray,
+    X11Color::LightSteelBlue,
+    X11Color::LightYellow,
+    X11Color::Lime,
+    X11Color::LimeGreen,
+    X11Color::Linen,
+    X11Color::Magenta,
+    X11Color::Maroon,
+    X11Color::WebMaroon,
+    X11Color::MediumAquamarine,
+    X11Color::MediumBlue,
+    X11Color::MediumOrchid,
+    X11Color::MediumPurple,
+    X11Color::MediumSeaGreen,
+    X11Color::MediumSlateBlue,
+    X11Color::MediumSpringGreen,
+    X11Color::MediumTurquoise,
+    X11Color::MediumVioletRed,
+    X11Color::MidnightBlue,
+    X11Color::MintCream,
+    X11Color::MistyRose,
+    X11Color::Moccasin,
+    X11Color::NavajoWhite,
+    X11Color::NavyBlue,
+    X11Color::OldLace,
+    X11Color::Olive,
+    X11Color::OliveDrab,
+    X11Color::Orange,
+    X11Color::OrangeRed,
+    X11Color::Orchid,
+    X11Color::PaleGoldenrod,
+    X11Color::PaleGreen,
+    X11Color::PaleTurquoise,
+    X11Color::PaleVioletRed,
+    X11Color::PapayaWhip,
+    X11Color::PeachPuff,
+    X11Color::Peru,
+    X11Color::Pink,
+    X11Color::Plum,
+    X11Color::PowderBlue,
+    X11Color::Purple,
+    X11Color::WebPurple,
+    X11Color::RebeccaPurple,
+    X11Color::Red,
+    X11Color::RosyBrown,
+    X11Color::RoyalBlue,
+    X11Color::SaddleBrown,
+    X11Color::Salmon,
+    X11Color::SandyBrown,
+    X11Color::SeaGreen,
+    X11Color::Seashell,
+    X11Color::Sienna,
+    X11Color::Silver,
+    X11Color::SkyBlue,
+    X11Color::SlateBlue,
+    X11Color::SlateGray,
+    X11Color::Snow,
+    X11Color::SpringGreen,
+    X11Color::SteelBlue,
+    X11Color::Tan,
+    X11Color::Teal,
+    X11Color::Thistle,
+    X11Color::Tomato,
+    X11Color::Turquoise,
+    X11Color::Violet,
+    X11Color::Wheat,
+    X11Color::White,
+    X11Color::WhiteSmoke,
+    X11Color::Yellow,
+    X11Color::YellowGreen,
+];
+
 impl X11Color {
+    /// Returns an iterator over all [`X11Color`]s
+    pub fn all() -> impl Iterator<Item = X11Color> {
+        X11_COLORS.iter().copied()
+    }
+
+    /// Looks up the [`X11Color`] with the given name.
+    ///
+    /// The lookup ignores case, whitespace, dashes and underscores, so `"AliceBlue"`,
+    /// `"alice blue"` and `"ALICE_BLUE"` all refer to the same color.
+    /// Both spellings `"gray"` and `"grey"` are accepted.
+    ///
+    /// # Returns
+    /// `None` if there is no color with the given name
+    pub fn from_name(name: &str) -> Option<X11Color> {
+        let normalized = normalize_name(name);
+        X11Color::all().find(|color| normalize_name(color.name()) == normalized)
+    }
+
+    /// Returns the name of this color
+    ///
+    /// e.g. `X11Color::AliceBlue` => `"AliceBlue"`
+    pub fn name(&self) -> &'static str {
+        match self {
+            X11Color::AliceBlue => "AliceBlue",
+            X11Color::AntiqueWhite => "AntiqueWhite",
+            X11Color::Aqua => "Aqua",
+            X11Color::Aquamarine => "Aquamarine",
+            X11Color::Azure => "Azure",
+            X11Color::Beige => "Beige",
+            X11Color::Bisque => "Bisque",
+            X11Color::Black => "Black",
+            X11Color::BlanchedAlmond => "BlanchedAlmond",
+            X11Color::Blue => "Blue",
+            X11Color::BlueViolet => "BlueViolet",
+            X11Color::Brown => "Brown",
+            X11Color::Burlywood => "Burlywood",
+            X11Color::CadetBlue => "CadetBlue",
+            X11Color::Chartreuse => "Chartreuse",
+            X11Color::Chocolate => "Chocolate",
+            X11Color::Coral => "Coral",
+            X11Color::CornflowerBlue => "CornflowerBlue",
+            X11Color::Cornsilk => "Cornsilk",
+            X11Color::Crimson => "Crimson",
+            X11Color::Cyan => "Cyan",
+            X11Color::DarkBlue => "DarkBlue",
+            X11Color::DarkCyan => "DarkCyan",
+            X11Color::DarkGoldenrod => "DarkGoldenrod",
+            X11Color::DarkGray => "DarkGray",
+            X11Color::DarkGreen => "DarkGreen",
+            X11Color::DarkKhaki => "DarkKhaki",
+            X11Color::DarkMagenta => "DarkMagenta",
+            X11Color::DarkOliveGreen => "DarkOliveGreen",
+            X11Color::DarkOrange => "DarkOrange",
+            X11Color::DarkOrchid => "DarkOrchid",
+            X11Color::DarkRed => "DarkRed",
+            X11Color::DarkSalmon => "DarkSalmon",
+            X11Color::DarkSeaGreen => "DarkSeaGreen",
+            X11Color::DarkSlateBlue => "DarkSlateBlue",
+            X11Color::DarkSlateGray => "DarkSlateGray",
+            X11Color::DarkTurquoise => "DarkTurquoise",
+            X11Color::DarkViolet => "DarkViolet",
+            X11Color::DeepPink => "DeepPink",
+            X11Color::DeepSkyBlue => "DeepSkyBlue",
+            X11Color::DimGray => "DimGray",
+            X11Color::DodgerBlue => "DodgerBlue",
+            X11Color::Firebrick => "Firebrick",
+            X11Color::FloralWhite => "FloralWhite",
+            X11Color::ForestGreen => "ForestGreen",
+            X11Color::Fuchsia => "Fuchsia",
+            X11Color::Gainsboro => "Gainsboro",
+            X11Color::GhostWhite => "GhostWhite",
+            X11Color::Gold => "Gold",
+            X11Color::Goldenrod => "Goldenrod",
+            X11Color::Gray => "Gray",
+            X11Color::WebGray => "WebGray",
+            X11Color::Green => "Green",
+            X11Color::WebGreen => "WebGreen",
+            X11Color::GreenYellow => "GreenYellow",
+            X11Color::Honeydew => "Honeydew",
+            X11Color::HotPink => "HotPink",
+            X11Color::IndianRed => "IndianRed",
+            X11Color::Indigo => "Indigo",
+            X11Color::Ivory => "Ivory",
+            X11Color::Khaki => "Khaki",
+            X11Color::Lavender => "Lavender",
+            X11Color::LavenderBlush => "LavenderBlush",
+            X11Color::LawnGreen => "LawnGreen",
+            X11Color::LemonChiffon => "LemonChiffon",
+            X11Color::LightBlue => "LightBlue",
+            X11Color::LightCoral => "LightCoral",
+            X11Color::LightCyan => "LightCyan",
+            X11Color::LightGoldenrod => "LightGoldenrod",
+            X11Color::LightGray => "LightGray",
+            X11Color::LightGreen => "LightGreen",
+            X11Color::LightPink => "LightPink",
+            X11Color::LightSalmon => "LightSalmon",
+            X11Color::LightSeaGreen => "LightSeaGreen",
+            X11Color::LightSkyBlue => "LightSkyBlue",
+            X11Color::LightSlateGray => "LightSlateGray",
+            X11Color::LightSteelBlue => "LightSteelBlue",
+            X11Color::LightYellow => "LightYellow",
+            X11Color::Lime => "Lime",
+            X11Color::LimeGreen => "LimeGreen",
+            X11Color::Linen => "Linen",
+            X11Color::Magenta => "Magenta",
+            X11Color::Maroon => "Maroon",
+            X11Color::WebMaroon => "WebMaroon",
+            X11Color::MediumAquamarine => "MediumAquamarine",
+            X11Color::MediumBlue => "MediumBlue",
+            X11Color::MediumOrchid => "MediumOrchid",
+            X11Color::MediumPurple => "MediumPurple",
+            X11Color::MediumSeaGreen => "MediumSeaGreen",
+            X11Color::MediumSlateBlue => "MediumSlateBlue",
+            X11Color::MediumSpringGreen => "MediumSpringGreen",
+            X11Color::MediumTurquoise => "MediumTurquoise",
+            X11Color::MediumVioletRed => "MediumVioletRed",
+            X11Color::MidnightBlue => "MidnightBlue",
+            X11Color::MintCream => "MintCream",
+            X11Color::MistyRose => "MistyRose",
+            X11Color::Moccasin => "Moccasin",
+            X11Color::NavajoWhite => "NavajoWhite",
+            X11Color::NavyBlue => "NavyBlue",
+            X11Color::OldLace => "OldLace",
+            X11Color::Olive => "Olive",
+            X11Color::OliveDrab => "OliveDrab",
+            X11Color::Orange => "Orange",
+            X11Color::OrangeRed => "OrangeRed",
+            X11Color::Orchid => "Orchid",
+            X11Color::PaleGoldenrod => "PaleGoldenrod",
+            X11Color::PaleGreen => "PaleGreen",
+            X11Color::PaleTurquoise => "PaleTurquoise",
+            X11Color::PaleVioletRed => "PaleVioletRed",
+            X11Color::PapayaWhip => "PapayaWhip",
+            X11Color::PeachPuff => "PeachPuff",
+            X11Color::Peru => "Peru",
+            X11Color::Pink => "Pink",
+            X11Color::Plum => "Plum",
+            X11Color::PowderBlue => "PowderBlue",
+            X11Color::Purple => "Purple",
+            X11Color::WebPurple => "WebPurple",
+            X11Color::RebeccaPurple => "RebeccaPurple",
+            X11Color::Red => "Red",
+            X11Color::RosyBrown => "RosyBrown",
+            X11Color::RoyalBlue => "RoyalBlue",
+            X11Color::SaddleBrown => "SaddleBrown",
+            X11Color::Salmon => "Salmon",
+            X11Color::SandyBrown => "SandyBrown",
+            X11Color::SeaGreen => "SeaGreen",
+            X11Color::Seashell => "Seashell",
+            X11Color::Sienna => "Sienna",
+            X11Color::Silver => "Silver",
+            X11Color::SkyBlue => "SkyBlue",
+            X11Color::SlateBlue => "SlateBlue",
+            X11Color::SlateGray => "SlateGray",
+            X11Color::Snow => "Snow",
+            X11Color::SpringGreen => "SpringGreen",
+            X11Color::SteelBlue => "SteelBlue",
+            X11Color::Tan => "Tan",
+            X11Color::Teal => "Teal",
+            X11Color::Thistle => "Thistle",
+            X11Color::Tomato => "Tomato",
+            X11Color::Turquoise => "Turquoise",
+            X11Color::Violet => "Violet",
+            X11Color::Wheat => "Wheat",
+            X11Color::White => "White",
+            X11Color::WhiteSmoke => "WhiteSmoke",
+            X11Color::Yellow => "Yellow",
+            X11Color::YellowGreen => "YellowGreen",
+        }
+    }
+
+    /// Returns the [`X11Color`] closest to the given color
+    ///
+    /// Uses a perceptual metric (the "redmean" approximation) instead of the plain
+    /// euclidean distance in RGB.
+    pub fn nearest<T>(color: &impl RGBColor<T>) -> X11Color {
+        nearest_by_key(color, X11Color::all(), |preset| {
+            preset.to_rgb::<RGB24, u8>()
+        })
+        .expect("there is at least one X11Color")
+    }
+
     pub fn to_rgb<T, U>(&self) -> T
     where
         T: RGBColor<U>,
@@ -459,3 +815,142 @@ impl X11Color {
         }
     }
 }
+
+/// Splits a shade name like `"deep-purple-500"` into its normalized family name and shade
+///
+/// # Returns
+/// `None` if the name does not end with a shade number
+fn split_shade_name(name: &str) -> Option<(String, u16)> {
+    let normalized = normalize_name(&name.replace(['-', '_'], " "));
+    let family_length = normalized
+        .trim_end_matches(|c: char| c.is_ascii_digit())
+        .len();
+    let shade = normalized[family_length..].parse().ok()?;
+    Some((normalized[..family_length].to_string(), shade))
+}
+
+/// Returns the indices of the levels directly below and above the given value
+///
+/// Expects `levels` to be sorted and not empty.
+/// If the value is outside of the levels both indices point to the closest level.
+fn level_neighbours(levels: &[u8], value: u8) -> [usize; 2] {
+    match levels.iter().position(|&level| level >= value) {
+        Some(0) => [0, 0],
+        Some(index) => [index - 1, index],
+        None => [levels.len() - 1, levels.len() - 1],
+    }
+}
+
+/// Creates a new [`RGB24`] from a hex value like `0xff8000`
+fn rgb24_from_hex(hex: u32) -> RGB24 {
+    RGB24::from_rgb((hex >> 16) as u8, (hex >> 8) as u8, hex as u8)
+}
+
+/// Calculates the perceptual distance between two RGB colors
+///
+/// Uses the ["redmean"](https://en.wikipedia.org/wiki/Color_difference#sRGB) approximation,
+/// which weights the channels according to the average amount of red.
+/// The result is not normalized and only meant for comparison.
+fn perceptual_distance<T, U>(a: &impl RGBColor<T>, b: &impl RGBColor<U>) -> f64 {
+    let (r1, g1, b1) = a.as_tuple_f64();
+    let (r2, g2, b2) = b.as_tuple_f64();
+    let r_mean = (r1 + r2) / 2.0;
+
+    ((2.0 + r_mean) * (r1 - r2).powi(2)
+        + 4.0 * (g1 - g2).powi(2)
+        + (3.0 - r_mean) * (b1 - b2).powi(2))
+    .sqrt()
+}
+
+/// Returns the candidate whose color (given by `to_rgb`) is closest to `color`
+///
+/// # Returns
+/// `None` if there are no candidates
+fn nearest_by_key<T, C, F>(
+    color: &impl RGBColor<T>,
+    candidates: impl Iterator<Item = C>,
+    to_rgb: F,
+) -> Option<C>
+where
+    F: Fn(&C) -> RGB24,
+{
+    candidates
+        .map(|candidate| (perceptual_distance(color, &to_rgb(&candidate)), candidate))
+        .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(_, candidate)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::rgb::rgb24::RGB24;
+    use crate::models::rgb::rgb48::RGB48;
+    use crate::models::rgb::RGBColor;
+    use crate::presets::X11Color;
+
+    #[test]
+    fn all_() {
+        assert_eq!(145, X11Color::all().count());
+        assert_eq!(Some(X11Color::AliceBlue), X11Color::all().next());
+        assert_eq!(Some(X11Color::YellowGreen), X11Color::all().last());
+    }
+
+    #[test]
+    fn name_() {
+        assert_eq!("AliceBlue", X11Color::AliceBlue.name());
+        assert_eq!("WebGray", X11Color::WebGray.name());
+    }
+
+    #[test]
+    fn from_name_exact() {
+        for color in X11Color::all() {
+            assert_eq!(Some(color), X11Color::from_name(color.name()));
+        }
+    }
+
+    #[test]
+    fn from_name_case_and_space() {
+        assert_eq!(Some(X11Color::AliceBlue), X11Color::from_name("alice blue"));
+        assert_eq!(Some(X11Color::AliceBlue), X11Color::from_name("ALICEBLUE"));
+        assert_eq!(
+            Some(X11Color::DarkSlateGray),
+            X11Color::from_name(" Dark Slate  Gray ")
+        );
+    }
+
+    #[test]
+    fn from_name_grey() {
+        assert_eq!(Some(X11Color::Gray), X11Color::from_name("grey"));
+        assert_eq!(
+            Some(X11Color::LightSlateGray),
+            X11Color::from_name("light slate grey")
+        );
+    }
+
+    #[test]
+    fn from_name_unknown() {
+        assert_eq!(None, X11Color::from_name("not a color"));
+        assert_eq!(None, X11Color::from_name(""));
+    }
+
+    #[test]
+    fn nearest_exact() {
+        assert_eq!(X11Color::Red, X11Color::nearest(&RGB24::RED));
+        assert_eq!(X11Color::Black, X11Color::nearest(&RGB48::BLACK));
+        assert_eq!(
+            X11Color::Tomato,
+            X11Color::nearest(&X11Color::Tomato.to_rgb::<RGB24, u8>())
+        );
+    }
+
+    #[test]
+    fn nearest_close() {
+        assert_eq!(
+            X11Color::Gold,
+            X11Color::nearest(&RGB24::from_rgb(250, 210, 10))
+        );
+        assert_eq!(
+            X11Color::RebeccaPurple,
+            X11Color::nearest(&RGB24::from_hex("663398"))
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use crate::models::rgb::rgb24::RGB24;
+use crate::names::normalize_name;
+use crate::presets::{rgb24_from_hex, split_shade_name};
+
+/// The hue families of the [Material Design 2](https://material.io/design/color/the-color-system.html) color palette
+///
+/// Every family consists of the numbered shades in [`MaterialColor::SHADES`] with `500` being the primary shade.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MaterialColor {
+    Red,
+    Pink,
+    Purple,
+    DeepPurple,
+    Indigo,
+    Blue,
+    LightBlue,
+    Cyan,
+    Teal,
+    Green,
+    LightGreen,
+    Lime,
+    Yellow,
+    Amber,
+    Orange,
+    DeepOrange,
+    Brown,
+    Grey,
+    BlueGrey,
+}
+
+/// All [`MaterialColor`]s in order of declaration
+const MATERIAL_COLORS: [MaterialColor; 19] = [
+    MaterialColor::Red,
+    MaterialColor::Pink,
+    MaterialColor::Purple,
+    MaterialColor::DeepPurple,
+    MaterialColor::Indigo,
+    MaterialColor::Blue,
+    MaterialColor::LightBlue,
+    MaterialColor::Cyan,
+    MaterialColor::Teal,
+    MaterialColor::Green,
+    MaterialColor::LightGreen,
+    MaterialColor::Lime,
+    MaterialColor::Yellow,
+    MaterialColor::Amber,
+    MaterialColor::Orange,
+    MaterialColor::DeepOrange,
+    MaterialColor::Brown,
+    MaterialColor::Grey,
+    MaterialColor::BlueGrey,
+];
+
+/// The hex values of all shades, indexed by [`MaterialColor`] and [`MaterialColor::SHADES`]
+static MATERIAL_VALUES: [[u32; 10]; 19] = [
+    // Red
+    [
+        0xffebee, 0xffcdd2, 0xef9a9a, 0xe57373, 0xef5350, 0xf44336, 0xe53935, 0xd32f2f, 0xc62828,
+        0xb71c1c,
+    ],
+    // Pink
+    [
+        0xfce4ec, 0xf8bbd0, 0xf48fb1, 0xf06292, 0xec407a, 0xe91e63, 0xd81b60, 0xc2185b, 0xad1457,
+        0x880e4f,
+    ],
+    // Purple
+    [
+        0xf3e5f5, 0xe1bee7, 0xce93d8, 0xba68c8, 0xab47bc, 0x9c27b0, 0x8e24aa, 0x7b1fa2, 0x6a1b9a,
+        0x4a148c,
+    ],
+    // Deep Purple
+    [
+        0xede7f6, 0xd1c4e9, 0xb39ddb, 0x9575cd, 0x7e57c2, 0x673ab7, 0x5e35b1, 0x512da8, 0x4527a0,
+        0x311b92,
+    ],
+    // Indigo
+    [
+        0xe8eaf6, 0xc5cae9, 0x9fa8da, 0x7986cb, 0x5c6bc0, 0x3f51b5, 0x3949ab, 0x303f9f, 0x283593,
+        0x1a237e,
+    ],
+    // Blue
+    [
+        0xe3f2fd, 0xbbdefb, 0x90caf9, 0x64b5f6, 0x42a5f5, 0x2196f3, 0x1e88e5, 0x1976d2, 0x1565c0,
+        0x0d47a1,
+    ],
+    // Light Blue
+    [
+        0xe1f5fe, 0xb3e5fc, 0x81d4fa, 0x4fc3f7, 0x29b6f6, 0x03a9f4, 0x039be5, 0x0288d1, 0x0277bd,
+        0x01579b,
+    ],
+    // Cyan
+    [
+        0xe0f7fa, 0xb2ebf2, 0x80deea, 0x4dd0e1, 0x26c6da, 0x00bcd4, 0x00acc1, 0x0097a7, 0x00838f,
+        0x006064,
+    ],
+    // Teal
+    [
+        0xe0f2f1, 0xb2dfdb, 0x80cbc4, 0x4db6ac, 0x26a69a, 0x009688, 0x00897b, 0x00796b, 0x00695c,
+        0x004d40,
+    ],
+    // Green
+    [
+        0xe8f5e9, 0xc8e6c9, 0xa5d6a7, 0x81c784, 0x66bb6a, 0x4caf50, 0x43a047, 0x388e3c, 0x2e7d32,
+        0x1b5e20,
+    ],
+    // Light Green
+    [
+        0xf1f8e9, 0xdcedc8, 0xc5e1a5, 0xaed581, 0x9ccc65, 0x8bc34a, 0x7cb342, 0x689f38, 0x558b2f,
+        0x33691e,
+    ],
+    // Lime
+    [
+        0xf9fbe7, 0xf0f4c3, 0xe6ee9c, 0xdce775, 0xd4e157, 0xcddc39, 0xc0ca33, 0xafb42b, 0x9e9d24,
+        0x827717,
+    ],
+    // Yellow
+    [
+        0xfffde7, 0xfff9c4, 0xfff59d, 0xfff176, 0xffee58, 0xffeb3b, 0xfdd835, 0xfbc02d, 0xf9a825,
+        0xf57f17,
+    ],
+    // Amber
+    [
+        0xfff8e1, 0xffecb3, 0xffe082, 0xffd54f, 0xffca28, 0xffc107, 0xffb300, 0xffa000, 0xff8f00,
+        0xff6f00,
+    ],
+    // Orange
+    [
+        0xfff3e0, 0xffe0b2, 0xffcc80, 0xffb74d, 0xffa726, 0xff9800, 0xfb8c00, 0xf57c00, 0xef6c00,
+        0xe65100,
+    ],
+    // Deep Orange
+    [
+        0xfbe9e7, 0xffccbc, 0xffab91, 0xff8a65, 0xff7043, 0xff5722, 0xf4511e, 0xe64a19, 0xd84315,
+        0xbf360c,
+    ],
+    // Brown
+    [
+        0xefebe9, 0xd7ccc8, 0xbcaaa4, 0xa1887f, 0x8d6e63, 0x795548, 0x6d4c41, 0x5d4037, 0x4e342e,
+        0x3e2723,
+    ],
+    // Grey
+    [
+        0xfafafa, 0xf5f5f5, 0xeeeeee, 0xe0e0e0, 0xbdbdbd, 0x9e9e9e, 0x757575, 0x616161, 0x424242,
+        0x212121,
+    ],
+    // Blue Grey
+    [
+        0xeceff1, 0xcfd8dc, 0xb0bec5, 0x90a4ae, 0x78909c, 0x607d8b, 0x546e7a, 0x455a64, 0x37474f,
+        0x263238,
+    ],
+];
+
+impl MaterialColor {
+    /// The numbered shades available for every hue family (from light to dark)
+    pub const SHADES: [u16; 10] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900];
+
+    /// Returns an iterator over all [`MaterialColor`]s
+    pub fn all() -> impl Iterator<Item = MaterialColor> {
+        MATERIAL_COLORS.iter().copied()
+    }
+
+    /// Looks up the hue family with the given name.
+    ///
+    /// The lookup ignores case and whitespace (see [`X11Color::from_name`](crate::presets::X11Color::from_name)).
+    ///
+    /// # Returns
+    /// `None` if there is no hue family with the given name
+    pub fn from_name(name: &str) -> Option<MaterialColor> {
+        let normalized = normalize_name(name);
+        MaterialColor::all().find(|color| normalize_name(color.name()) == normalized)
+    }
+
+    /// Looks up a single shade by its full name, e.g. `"deep-purple-500"` or `"deep purple 500"`
+    ///
+    /// # Returns
+    /// `None` if there is no hue family with the given name or it has no such shade
+    pub fn from_shade_name(name: &str) -> Option<RGB24> {
+        let (family, shade) = split_shade_name(name)?;
+        MaterialColor::all()
+            .find(|color| normalize_name(color.name()) == family)?
+            .shade(shade)
+    }
+
+    /// Returns the name of this hue family
+    ///
+    /// e.g. `MaterialColor::DeepPurple` => `"Deep Purple"`
+    pub fn name(&self) -> &'static str {
+        match self {
+            MaterialColor::Red => "Red",
+            MaterialColor::Pink => "Pink",
+            MaterialColor::Purple => "Purple",
+            MaterialColor::DeepPurple => "Deep Purple",
+            MaterialColor::Indigo => "Indigo",
+            MaterialColor::Blue => "Blue",
+            MaterialColor::LightBlue => "Light Blue",
+            MaterialColor::Cyan => "Cyan",
+            MaterialColor::Teal => "Teal",
+            MaterialColor::Green => "Green",
+            MaterialColor::LightGreen => "Light Green",
+            MaterialColor::Lime => "Lime",
+            MaterialColor::Yellow => "Yellow",
+            MaterialColor::Amber => "Amber",
+            MaterialColor::Orange => "Orange",
+            MaterialColor::DeepOrange => "Deep Orange",
+            MaterialColor::Brown => "Brown",
+            MaterialColor::Grey => "Grey",
+            MaterialColor::BlueGrey => "Blue Grey",
+        }
+    }
+
+    /// Returns the given shade of this hue family
+    ///
+    /// # Returns
+    /// `None` if `shade` is not one of [`MaterialColor::SHADES`]
+    pub fn shade(&self, shade: u16) -> Option<RGB24> {
+        MaterialColor::SHADES
+            .iter()
+            .position(|&s| s == shade)
+            .map(|index| rgb24_from_hex(MATERIAL_VALUES[*self as usize][index]))
+    }
+
+    /// Returns an iterator over all shades of this hue family as `(shade, color)`
+    pub fn shades(&self) -> impl Iterator<Item = (u16, RGB24)> {
+        let values = &MATERIAL_VALUES[*self as usize];
+        MaterialColor::SHADES
+            .iter()
+            .zip(values.iter())
+            .map(|(&shade, &hex)| (shade, rgb24_from_hex(hex)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::rgb::rgb24::RGB24;
+    use crate::presets::material::MaterialColor;
+
+    #[test]
+    fn all_() {
+        assert_eq!(19, MaterialColor::all().count());
+        for color in MaterialColor::all() {
+            assert_eq!(MaterialColor::SHADES.len(), color.shades().count());
+        }
+    }
+
+    #[test]
+    fn from_name_() {
+        for color in MaterialColor::all() {
+            assert_eq!(Some(color), MaterialColor::from_name(color.name()));
+        }
+        assert_eq!(
+            Some(MaterialColor::DeepPurple),
+            MaterialColor::from_name("deep purple")
+        );
+        assert_eq!(
+            Some(MaterialColor::BlueGrey),
+            MaterialColor::from_name("Blue Gray")
+        );
+        assert_eq!(None, MaterialColor::from_name("Emerald"));
+    }
+
+    #[test]
+    fn shade_() {
+        assert_eq!(
+            Some(RGB24::from_hex("f44336")),
+            MaterialColor::Red.shade(500)
+        );
+        assert_eq!(
+            Some(RGB24::from_hex("e3f2fd")),
+            MaterialColor::Blue.shade(50)
+        );
+        assert_eq!(
+            Some(RGB24::from_hex("263238")),
+            MaterialColor::BlueGrey.shade(900)
+        );
+        assert_eq!(None, MaterialColor::Red.shade(42));
+    }
+
+    #[test]
+    fn from_shade_name_() {
+        assert_eq!(
+            Some(RGB24::from_hex("673ab7")),
+            MaterialColor::from_shade_name("deep-purple-500")
+        );
+        assert_eq!(
+            Some(RGB24::from_hex("ffc107")),
+            MaterialColor::from_shade_name("Amber 500")
+        );
+        assert_eq!(None, MaterialColor::from_shade_name("red"));
+        assert_eq!(None, MaterialColor::from_shade_name("foo-500"));
+    }
+}
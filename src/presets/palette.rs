@@ -0,0 +1,114 @@
+use crate::models::rgb::rgb24::RGB24;
+use crate::models::rgb::RGBColor;
+use crate::presets::{nearest_by_key, rgb24_from_hex};
+
+/// An ordered set of colors which are accessed by their index
+///
+/// This is how the hardware of many (retro) systems and terminals handles colors:
+/// instead of storing the color itself only the index within the palette is stored.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Palette {
+    name: &'static str,
+    colors: &'static [u32],
+}
+
+impl Palette {
+    /// Creates a new `Palette` from the given hex values (e.g. `0xff8000`)
+    pub(crate) const fn new(name: &'static str, colors: &'static [u32]) -> Self {
+        Palette { name, colors }
+    }
+
+    /// Returns the name of this palette
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the number of colors in this palette
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Returns `true` if this palette contains no colors
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Returns the color at the given index
+    ///
+    /// # Returns
+    /// `None` if the index is out of bounds
+    pub fn get(&self, index: usize) -> Option<RGB24> {
+        self.colors.get(index).map(|&hex| rgb24_from_hex(hex))
+    }
+
+    /// Returns an iterator over all colors in order of their index
+    pub fn colors(&self) -> impl Iterator<Item = RGB24> + 'static {
+        self.colors.iter().map(|&hex| rgb24_from_hex(hex))
+    }
+
+    /// Returns the (first) index of exactly the given color
+    ///
+    /// # Returns
+    /// `None` if the color is not part of this palette
+    pub fn index_of(&self, color: &RGB24) -> Option<usize> {
+        self.colors().position(|c| &c == color)
+    }
+
+    /// Returns the index of the color closest to the given color
+    ///
+    /// Uses the same perceptual metric as [`X11Color::nearest`](crate::presets::X11Color::nearest).
+    ///
+    /// # Panics
+    /// If the palette is empty
+    pub fn nearest_index<T>(&self, color: &impl RGBColor<T>) -> usize {
+        nearest_by_key(color, 0..self.len(), |&index| {
+            rgb24_from_hex(self.colors[index])
+        })
+        .expect("palette must not be empty")
+    }
+
+    /// Returns the color of this palette closest to the given color
+    ///
+    /// # Panics
+    /// If the palette is empty
+    pub fn nearest<T>(&self, color: &impl RGBColor<T>) -> RGB24 {
+        rgb24_from_hex(self.colors[self.nearest_index(color)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::rgb::rgb24::RGB24;
+    use crate::models::rgb::RGBColor;
+    use crate::presets::Palette;
+
+    const PALETTE: Palette = Palette::new("Test", &[0x000000, 0xff0000, 0x00ff00, 0xff0000]);
+
+    #[test]
+    fn get_() {
+        assert_eq!(4, PALETTE.len());
+        assert!(!PALETTE.is_empty());
+        assert_eq!(Some(RGB24::BLACK), PALETTE.get(0));
+        assert_eq!(Some(RGB24::GREEN), PALETTE.get(2));
+        assert_eq!(None, PALETTE.get(4));
+    }
+
+    #[test]
+    fn index_of_() {
+        assert_eq!(Some(1), PALETTE.index_of(&RGB24::RED));
+        assert_eq!(None, PALETTE.index_of(&RGB24::BLUE));
+    }
+
+    #[test]
+    fn nearest_() {
+        assert_eq!(0, PALETTE.nearest_index(&RGB24::from_rgb(20, 10, 30)));
+        assert_eq!(1, PALETTE.nearest_index(&RGB24::from_rgb(200, 30, 10)));
+        assert_eq!(RGB24::GREEN, PALETTE.nearest(&RGB24::from_rgb(10, 220, 30)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn nearest_empty() {
+        Palette::new("Empty", &[]).nearest_index(&RGB24::BLACK);
+    }
+}
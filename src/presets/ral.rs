@@ -0,0 +1,393 @@
+use crate::models::lab::Lab;
+use crate::models::rgb::rgb24::RGB24;
+use crate::models::rgb::RGBColor;
+use crate::names::normalize_name;
+use crate::presets::rgb24_from_hex;
+use std::fmt::{Display, Formatter, Result};
+
+/// A color of the [RAL Classic](https://en.wikipedia.org/wiki/List_of_RAL_colours) collection
+///
+/// RAL colors are defined by physical samples, so there are no official RGB values.
+/// The sRGB values are the commonly published approximations,
+/// the CIELAB values are the references published by RAL (D65, 10° observer).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RalColor {
+    code: u16,
+    name: &'static str,
+    hex: u32,
+    lab: (f64, f64, f64),
+}
+
+impl RalColor {
+    const fn new(code: u16, name: &'static str, hex: u32, lab: (f64, f64, f64)) -> Self {
+        RalColor {
+            code,
+            name,
+            hex,
+            lab,
+        }
+    }
+
+    /// Returns an iterator over all RAL Classic colors ordered by code
+    pub fn all() -> impl Iterator<Item = RalColor> {
+        RAL_CLASSIC.iter().copied()
+    }
+
+    /// Looks up the color with the given code
+    ///
+    /// Accepts the code with or without the `RAL` prefix,
+    /// e.g. `"RAL 3020"`, `"ral3020"` or `"3020"`.
+    ///
+    /// # Returns
+    /// `None` if there is no RAL Classic color with the given code
+    pub fn from_code(code: &str) -> Option<RalColor> {
+        let normalized = normalize_name(code);
+        let number = normalized.strip_prefix("ral").unwrap_or(&normalized);
+        let code: u16 = number.parse().ok()?;
+        RalColor::all().find(|color| color.code == code)
+    }
+
+    /// Looks up the color with the given name, e.g. `"Traffic red"`
+    ///
+    /// The lookup ignores case and whitespace (see [`X11Color::from_name`](crate::presets::X11Color::from_name)).
+    ///
+    /// # Returns
+    /// `None` if there is no RAL Classic color with the given name
+    pub fn from_name(name: &str) -> Option<RalColor> {
+        let normalized = normalize_name(name);
+        RalColor::all().find(|color| normalize_name(color.name) == normalized)
+    }
+
+    /// Returns the RAL Classic color closest to the given color
+    ///
+    /// Compares the colors by their euclidean distance in CIELAB (ΔE*76)
+    /// to the published references, see [`RalColor::lab`].
+    pub fn nearest<T>(color: &impl RGBColor<T>) -> RalColor {
+        let (l, a, b) = Lab::from_rgb(color).as_tuple();
+        let distance = |ral: &RalColor| {
+            (ral.lab.0 - l).powi(2) + (ral.lab.1 - a).powi(2) + (ral.lab.2 - b).powi(2)
+        };
+
+        RalColor::all()
+            .min_by(|x, y| {
+                distance(x)
+                    .partial_cmp(&distance(y))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .expect("there is at least one RAL color")
+    }
+
+    /// Returns the numeric code, e.g. `3020` for *RAL 3020*
+    pub fn code(&self) -> u16 {
+        self.code
+    }
+
+    /// Returns the (english) name, e.g. `"Traffic red"`
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the (approximate) sRGB value
+    pub fn to_rgb24(&self) -> RGB24 {
+        rgb24_from_hex(self.hex)
+    }
+
+    /// Returns the CIELAB reference published by RAL (D65, 10° observer) as tuple (L, a, b)
+    pub fn lab(&self) -> (f64, f64, f64) {
+        self.lab
+    }
+}
+
+impl Display for RalColor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "RAL {} {}", self.code, self.name)
+    }
+}
+
+/// All colors of the RAL Classic collection ordered by code
+static RAL_CLASSIC: [RalColor; 213] = [
+    RalColor::new(1000, "Green beige", 0xbebd7f, (76.0, -0.4, 27.6)),
+    RalColor::new(1001, "Beige", 0xc2b078, (73.6, 5.5, 27.0)),
+    RalColor::new(1002, "Sand yellow", 0xc6a664, (71.9, 5.8, 34.5)),
+    RalColor::new(1003, "Signal yellow", 0xe5be01, (75.0, 18.2, 96.7)),
+    RalColor::new(1004, "Golden yellow", 0xcda434, (70.2, 16.3, 89.1)),
+    RalColor::new(1005, "Honey yellow", 0xa98307, (63.4, 13.3, 73.2)),
+    RalColor::new(1006, "Maize yellow", 0xe4a010, (68.2, 21.9, 80.7)),
+    RalColor::new(1007, "Daffodil yellow", 0xdc9d00, (65.6, 27.2, 79.3)),
+    RalColor::new(1011, "Brown beige", 0x8a6642, (55.9, 11.6, 29.4)),
+    RalColor::new(1012, "Lemon yellow", 0xc7b446, (74.3, 4.6, 68.1)),
+    RalColor::new(1013, "Oyster white", 0xeae6ca, (89.5, 0.4, 12.2)),
+    RalColor::new(1014, "Ivory", 0xe1cc4f, (81.4, 2.6, 24.6)),
+    RalColor::new(1015, "Light ivory", 0xe6d690, (86.8, 1.3, 18.3)),
+    RalColor::new(1016, "Sulfur yellow", 0xedff21, (88.2, -6.5, 85.1)),
+    RalColor::new(1017, "Saffron yellow", 0xf5d033, (77.6, 16.3, 64.4)),
+    RalColor::new(1018, "Zinc yellow", 0xf8f32b, (83.4, 3.5, 83.6)),
+    RalColor::new(1019, "Grey beige", 0x9e9764, (62.5, 3.0, 13.3)),
+    RalColor::new(1020, "Olive yellow", 0x999950, (61.4, 2.1, 25.8)),
+    RalColor::new(1021, "Rape yellow", 0xf3da0b, (80.9, 9.9, 95.4)),
+    RalColor::new(1023, "Traffic yellow", 0xfad201, (81.0, 11.2, 93.6)),
+    RalColor::new(1024, "Ochre yellow", 0xaea04b, (63.8, 11.7, 45.6)),
+    RalColor::new(1026, "Luminous yellow", 0xffff00, (95.8, -19.6, 112.5)),
+    RalColor::new(1027, "Curry", 0x9d9101, (57.9, 10.4, 56.2)),
+    RalColor::new(1028, "Melon yellow", 0xf4a900, (73.5, 27.0, 86.5)),
+    RalColor::new(1032, "Broom yellow", 0xd6ae01, (72.6, 16.2, 73.9)),
+    RalColor::new(1033, "Dahlia yellow", 0xf3a505, (72.5, 25.3, 72.1)),
+    RalColor::new(1034, "Pastel yellow", 0xefa94a, (72.2, 22.3, 50.7)),
+    RalColor::new(1035, "Pearl beige", 0x6a5d4d, (59.6, 2.2, 11.4)),
+    RalColor::new(1036, "Pearl gold", 0x705335, (51.0, 6.5, 26.3)),
+    RalColor::new(1037, "Sun yellow", 0xf39f18, (70.0, 31.1, 76.7)),
+    RalColor::new(2000, "Yellow orange", 0xed760e, (61.5, 41.3, 68.7)),
+    RalColor::new(2001, "Red orange", 0xc93c20, (49.3, 45.5, 44.8)),
+    RalColor::new(2002, "Vermilion", 0xcb2821, (46.8, 51.7, 38.9)),
+    RalColor::new(2003, "Pastel orange", 0xff7514, (64.9, 44.8, 66.7)),
+    RalColor::new(2004, "Pure orange", 0xf44611, (56.5, 53.6, 64.5)),
+    RalColor::new(2005, "Luminous orange", 0xff2301, (64.9, 76.3, 96.0)),
+    RalColor::new(
+        2007,
+        "Luminous bright orange",
+        0xffa420,
+        (77.6, 37.5, 106.6),
+    ),
+    RalColor::new(2008, "Bright red orange", 0xf75e25, (60.2, 48.4, 63.5)),
+    RalColor::new(2009, "Traffic orange", 0xf54021, (55.7, 52.0, 59.6)),
+    RalColor::new(2010, "Signal orange", 0xd84b20, (54.8, 45.7, 50.1)),
+    RalColor::new(2011, "Deep orange", 0xec7c26, (60.7, 42.7, 70.2)),
+    RalColor::new(2012, "Salmon orange", 0xe55137, (56.4, 39.0, 37.2)),
+    RalColor::new(2013, "Pearl orange", 0xc35831, (43.4, 36.0, 42.3)),
+    RalColor::new(3000, "Flame red", 0xaf2b1e, (40.4, 49.0, 33.6)),
+    RalColor::new(3001, "Signal red", 0xa52019, (37.3, 49.0, 30.5)),
+    RalColor::new(3002, "Carmine red", 0xa2231d, (36.9, 48.9, 29.8)),
+    RalColor::new(3003, "Ruby red", 0x9b111e, (31.5, 43.4, 20.5)),
+    RalColor::new(3004, "Purple red", 0x75151e, (28.3, 30.3, 11.7)),
+    RalColor::new(3005, "Wine red", 0x5e2129, (26.4, 22.0, 6.2)),
+    RalColor::new(3007, "Black red", 0x412227, (23.3, 8.7, 3.2)),
+    RalColor::new(3009, "Oxide red", 0x642424, (31.4, 23.9, 13.8)),
+    RalColor::new(3011, "Brown red", 0x781f19, (32.0, 35.8, 19.9)),
+    RalColor::new(3012, "Beige red", 0xc1876b, (61.0, 20.0, 20.7)),
+    RalColor::new(3013, "Tomato red", 0xa12312, (37.6, 42.6, 28.4)),
+    RalColor::new(3014, "Antique pink", 0xd36e70, (55.4, 34.4, 13.5)),
+    RalColor::new(3015, "Light pink", 0xea899a, (73.3, 20.6, 5.2)),
+    RalColor::new(3016, "Coral red", 0xb32821, (42.5, 44.3, 30.6)),
+    RalColor::new(3017, "Rose", 0xe63244, (49.3, 50.0, 22.9)),
+    RalColor::new(3018, "Strawberry red", 0xd53032, (45.1, 53.2, 28.1)),
+    RalColor::new(3020, "Traffic red", 0xcc0605, (41.2, 60.1, 44.0)),
+    RalColor::new(3022, "Salmon pink", 0xd95030, (52.2, 41.5, 32.3)),
+    RalColor::new(3024, "Luminous red", 0xf80000, (52.9, 77.1, 55.9)),
+    RalColor::new(3026, "Luminous bright red", 0xfe0000, (56.3, 77.5, 56.3)),
+    RalColor::new(3027, "Raspberry red", 0xc51d34, (40.6, 55.0, 22.8)),
+    RalColor::new(3028, "Pure red", 0xcb3234, (45.3, 58.1, 41.3)),
+    RalColor::new(3031, "Orient red", 0xb32428, (39.6, 46.7, 24.6)),
+    RalColor::new(3032, "Pearl ruby red", 0x721422, (28.6, 36.4, 17.9)),
+    RalColor::new(3033, "Pearl pink", 0xb44c43, (44.0, 38.7, 25.5)),
+    RalColor::new(4001, "Red lilac", 0x6d3f5b, (40.8, 24.0, -11.1)),
+    RalColor::new(4002, "Red violet", 0x922b3e, (35.4, 34.6, 8.0)),
+    RalColor::new(4003, "Heather violet", 0xde4c8a, (49.8, 43.3, -6.5)),
+    RalColor::new(4004, "Claret violet", 0x641c34, (27.5, 26.7, 2.1)),
+    RalColor::new(4005, "Blue lilac", 0x6c4675, (44.3, 13.3, -22.0)),
+    RalColor::new(4006, "Traffic purple", 0xa03472, (38.3, 46.4, -12.4)),
+    RalColor::new(4007, "Purple violet", 0x4a192c, (25.3, 14.9, -3.6)),
+    RalColor::new(4008, "Signal violet", 0x924e7d, (39.9, 28.0, -18.9)),
+    RalColor::new(4009, "Pastel violet", 0xa18594, (59.4, 9.0, -4.4)),
+    RalColor::new(4010, "Telemagenta", 0xcf3476, (45.7, 51.4, -4.8)),
+    RalColor::new(4011, "Pearl violet", 0x8673a1, (47.8, 14.1, -17.8)),
+    RalColor::new(4012, "Pearl blackberry", 0x6c6874, (38.6, 5.6, -9.3)),
+    RalColor::new(5000, "Violet blue", 0x354d73, (34.5, -0.6, -20.1)),
+    RalColor::new(5001, "Green blue", 0x1f3438, (31.1, -5.7, -17.8)),
+    RalColor::new(5002, "Ultramarine blue", 0x20214f, (26.5, 12.8, -42.9)),
+    RalColor::new(5003, "Sapphire blue", 0x1d1e33, (26.3, 1.0, -20.7)),
+    RalColor::new(5004, "Black blue", 0x18171c, (24.2, 0.2, -3.2)),
+    RalColor::new(5005, "Signal blue", 0x1e2460, (30.8, -0.3, -34.8)),
+    RalColor::new(5007, "Brilliant blue", 0x3e5f8a, (42.7, -4.2, -24.4)),
+    RalColor::new(5008, "Grey blue", 0x26252d, (30.7, -1.8, -7.8)),
+    RalColor::new(5009, "Azure blue", 0x025669, (40.2, -7.5, -20.2)),
+    RalColor::new(5010, "Gentian blue", 0x0e294b, (30.0, -0.2, -33.9)),
+    RalColor::new(5011, "Steel blue", 0x231a24, (25.9, -0.8, -10.1)),
+    RalColor::new(5012, "Light blue", 0x3b83bd, (52.0, -11.7, -36.8)),
+    RalColor::new(5013, "Cobalt blue", 0x1e213d, (26.3, 3.4, -18.5)),
+    RalColor::new(5014, "Pigeon blue", 0x606e8c, (51.1, -1.4, -13.4)),
+    RalColor::new(5015, "Sky blue", 0x2271b3, (46.8, -9.9, -38.2)),
+    RalColor::new(5017, "Traffic blue", 0x063971, (35.0, -6.5, -36.6)),
+    RalColor::new(5018, "Turquoise blue", 0x3f888f, (52.2, -28.4, -8.8)),
+    RalColor::new(5019, "Capri blue", 0x1b5583, (38.4, -8.2, -24.9)),
+    RalColor::new(5020, "Ocean blue", 0x1d334a, (31.5, -11.0, -10.6)),
+    RalColor::new(5021, "Water blue", 0x256d7b, (42.6, -27.4, -7.7)),
+    RalColor::new(5022, "Night blue", 0x252850, (27.8, 8.6, -25.1)),
+    RalColor::new(5023, "Distant blue", 0x49678d, (47.3, -4.1, -20.4)),
+    RalColor::new(5024, "Pastel blue", 0x5d9b9b, (59.3, -8.4, -16.1)),
+    RalColor::new(5025, "Pearl gentian blue", 0x2a6478, (39.5, -13.4, -18.0)),
+    RalColor::new(5026, "Pearl night blue", 0x102c54, (27.1, 3.9, -30.4)),
+    RalColor::new(6000, "Patina green", 0x316650, (45.6, -21.3, 2.7)),
+    RalColor::new(6001, "Emerald green", 0x287233, (42.3, -26.3, 18.0)),
+    RalColor::new(6002, "Leaf green", 0x2d572c, (39.3, -25.2, 20.1)),
+    RalColor::new(6003, "Olive green", 0x424632, (40.4, -4.3, 11.3)),
+    RalColor::new(6004, "Blue green", 0x1f3a3d, (30.2, -10.8, -4.0)),
+    RalColor::new(6005, "Moss green", 0x2f4538, (30.4, -12.0, 1.2)),
+    RalColor::new(6006, "Grey olive", 0x3e3b32, (31.0, -0.3, 5.4)),
+    RalColor::new(6007, "Bottle green", 0x343b29, (28.3, -5.3, 7.1)),
+    RalColor::new(6008, "Brown green", 0x39352a, (28.9, 0.5, 6.1)),
+    RalColor::new(6009, "Fir green", 0x31372b, (27.7, -5.2, 3.3)),
+    RalColor::new(6010, "Grass green", 0x35682d, (44.0, -20.6, 24.1)),
+    RalColor::new(6011, "Reseda green", 0x587246, (51.9, -12.4, 15.0)),
+    RalColor::new(6012, "Black green", 0x343e40, (29.1, -5.0, -0.3)),
+    RalColor::new(6013, "Reed green", 0x6c7156, (51.9, -2.9, 15.6)),
+    RalColor::new(6014, "Yellow olive", 0x47402e, (34.8, -0.2, 8.6)),
+    RalColor::new(6015, "Black olive", 0x3b3c36, (31.2, -1.3, 3.3)),
+    RalColor::new(6016, "Turquoise green", 0x1e5945, (39.0, -33.1, 5.8)),
+    RalColor::new(6017, "May green", 0x4c9141, (51.0, -27.8, 33.1)),
+    RalColor::new(6018, "Yellow green", 0x57a639, (60.6, -35.8, 46.4)),
+    RalColor::new(6019, "Pastel green", 0xbdecb6, (80.2, -15.2, 14.4)),
+    RalColor::new(6020, "Chrome green", 0x2e3a23, (32.6, -9.8, 8.6)),
+    RalColor::new(6021, "Pale green", 0x89ac76, (64.0, -14.3, 17.6)),
+    RalColor::new(6022, "Olive drab", 0x25221b, (29.8, 0.3, 7.9)),
+    RalColor::new(6024, "Traffic green", 0x308446, (50.1, -39.6, 18.6)),
+    RalColor::new(6025, "Fern green", 0x3d642d, (46.8, -19.7, 26.3)),
+    RalColor::new(6026, "Opal green", 0x015d52, (37.1, -31.5, 3.9)),
+    RalColor::new(6027, "Light green", 0x84c3be, (70.2, -24.6, -4.0)),
+    RalColor::new(6028, "Pine green", 0x2c5545, (37.8, -14.8, 6.9)),
+    RalColor::new(6029, "Mint green", 0x20603d, (41.0, -35.3, 13.4)),
+    RalColor::new(6032, "Signal green", 0x317f43, (48.8, -38.8, 16.7)),
+    RalColor::new(6033, "Mint turquoise", 0x497e76, (54.3, -21.6, -0.9)),
+    RalColor::new(6034, "Pastel turquoise", 0x7fb5b5, (68.3, -18.5, -6.6)),
+    RalColor::new(6035, "Pearl green", 0x1c542d, (31.3, -26.3, 11.0)),
+    RalColor::new(6036, "Pearl opal green", 0x193737, (33.1, -20.0, -2.7)),
+    RalColor::new(6037, "Pure green", 0x008f39, (51.1, -55.3, 40.3)),
+    RalColor::new(6038, "Luminous green", 0x00bb2d, (63.9, -73.8, 52.7)),
+    RalColor::new(7000, "Squirrel grey", 0x78858b, (57.7, -3.5, -5.2)),
+    RalColor::new(7001, "Silver grey", 0x8a9597, (63.4, -2.9, -3.5)),
+    RalColor::new(7002, "Olive grey", 0x7e7b52, (55.2, -0.3, 13.8)),
+    RalColor::new(7003, "Moss grey", 0x6c7059, (52.6, -1.0, 10.3)),
+    RalColor::new(7004, "Signal grey", 0x969992, (66.1, -0.8, -0.5)),
+    RalColor::new(7005, "Mouse grey", 0x646b63, (49.4, -2.1, 1.7)),
+    RalColor::new(7006, "Beige grey", 0x6d6552, (48.0, 1.7, 8.0)),
+    RalColor::new(7008, "Khaki grey", 0x6a5f31, (44.1, 4.6, 21.0)),
+    RalColor::new(7009, "Green grey", 0x4d5645, (41.3, -3.5, 4.4)),
+    RalColor::new(7010, "Tarpaulin grey", 0x4c514a, (40.6, -2.7, 2.8)),
+    RalColor::new(7011, "Iron grey", 0x434b4d, (39.5, -2.4, -2.9)),
+    RalColor::new(7012, "Basalt grey", 0x4e5754, (41.1, -2.2, -2.2)),
+    RalColor::new(7013, "Brown grey", 0x464531, (38.8, 1.5, 8.3)),
+    RalColor::new(7015, "Slate grey", 0x434750, (37.6, -0.6, -4.4)),
+    RalColor::new(7016, "Anthracite grey", 0x293133, (31.6, -1.5, -3.0)),
+    RalColor::new(7021, "Black grey", 0x23282b, (28.4, -0.7, -1.6)),
+    RalColor::new(7022, "Umbra grey", 0x332f2c, (32.0, -0.3, 3.2)),
+    RalColor::new(7023, "Concrete grey", 0x686c5e, (51.5, -1.6, 3.6)),
+    RalColor::new(7024, "Graphite grey", 0x474a51, (36.2, -0.7, -2.9)),
+    RalColor::new(7026, "Granite grey", 0x2f353b, (31.9, -3.4, -2.6)),
+    RalColor::new(7030, "Stone grey", 0x8b8c7a, (58.6, -0.2, 4.9)),
+    RalColor::new(7031, "Blue grey", 0x474b4e, (45.8, -3.4, -5.0)),
+    RalColor::new(7032, "Pebble grey", 0xb8b799, (72.9, -0.6, 7.3)),
+    RalColor::new(7033, "Cement grey", 0x7d8471, (56.3, -4.1, 6.0)),
+    RalColor::new(7034, "Yellow grey", 0x8f8b66, (59.5, 0.4, 13.0)),
+    RalColor::new(7035, "Light grey", 0xd7d7d7, (80.4, -1.3, 1.4)),
+    RalColor::new(7036, "Platinum grey", 0x7f7679, (60.4, 1.6, -0.1)),
+    RalColor::new(7037, "Dusty grey", 0x7d7f7d, (54.3, -0.8, 0.7)),
+    RalColor::new(7038, "Agate grey", 0xb5b8b1, (73.7, -1.9, 3.2)),
+    RalColor::new(7039, "Quartz grey", 0x6c6960, (47.5, 0.3, 4.3)),
+    RalColor::new(7040, "Window grey", 0x9da1aa, (66.4, -1.5, -2.7)),
+    RalColor::new(7042, "Traffic grey A", 0x8d948d, (60.9, -1.4, -0.4)),
+    RalColor::new(7043, "Traffic grey B", 0x4e5452, (38.5, -1.7, 0.2)),
+    RalColor::new(7044, "Silk grey", 0xcac4b0, (77.6, -0.5, 6.9)),
+    RalColor::new(7045, "Telegrey 1", 0x909090, (62.4, -1.6, -2.0)),
+    RalColor::new(7046, "Telegrey 2", 0x82898f, (56.5, -2.0, -3.4)),
+    RalColor::new(7047, "Telegrey 4", 0xd0d0d0, (80.3, 0.0, -0.1)),
+    RalColor::new(7048, "Pearl mouse grey", 0x898176, (54.9, 0.6, 5.5)),
+    RalColor::new(8000, "Green brown", 0x826c34, (52.2, 8.9, 31.6)),
+    RalColor::new(8001, "Ochre brown", 0x955f20, (49.5, 19.6, 34.4)),
+    RalColor::new(8002, "Signal brown", 0x6c3b2a, (37.1, 17.5, 14.4)),
+    RalColor::new(8003, "Clay brown", 0x734222, (40.0, 17.5, 24.2)),
+    RalColor::new(8004, "Copper brown", 0x8e402a, (39.6, 24.7, 23.2)),
+    RalColor::new(8007, "Fawn brown", 0x59351f, (35.3, 13.3, 18.7)),
+    RalColor::new(8008, "Olive brown", 0x6f4f28, (39.7, 9.5, 21.5)),
+    RalColor::new(8011, "Nut brown", 0x5b3a29, (32.7, 10.0, 12.9)),
+    RalColor::new(8012, "Red brown", 0x592321, (31.7, 18.6, 9.7)),
+    RalColor::new(8014, "Sepia brown", 0x382c1e, (29.3, 5.4, 6.9)),
+    RalColor::new(8015, "Chestnut brown", 0x633a34, (31.6, 16.0, 8.3)),
+    RalColor::new(8016, "Mahogany brown", 0x4c2f27, (28.5, 10.1, 6.1)),
+    RalColor::new(8017, "Chocolate brown", 0x45322e, (28.8, 6.0, 3.9)),
+    RalColor::new(8019, "Grey brown", 0x403a3a, (29.0, 1.8, 1.4)),
+    RalColor::new(8022, "Black brown", 0x212121, (24.7, 0.6, 0.0)),
+    RalColor::new(8023, "Orange brown", 0xa65e2e, (47.6, 25.7, 32.8)),
+    RalColor::new(8024, "Beige brown", 0x79553d, (40.6, 12.2, 16.6)),
+    RalColor::new(8025, "Pale brown", 0x755c48, (42.9, 7.8, 11.0)),
+    RalColor::new(8028, "Terra brown", 0x4e3b31, (33.6, 5.0, 8.5)),
+    RalColor::new(8029, "Pearl copper", 0x763c28, (38.5, 22.8, 22.0)),
+    RalColor::new(9001, "Cream", 0xfdf4e3, (90.5, 0.4, 9.5)),
+    RalColor::new(9002, "Grey white", 0xe7ebda, (87.5, -0.6, 4.6)),
+    RalColor::new(9003, "Signal white", 0xf4f4f4, (94.6, -0.5, 2.6)),
+    RalColor::new(9004, "Signal black", 0x282828, (25.6, 0.0, -0.6)),
+    RalColor::new(9005, "Jet black", 0x0a0a0a, (25.2, 0.2, -0.5)),
+    RalColor::new(9006, "White aluminium", 0xa5a5a5, (68.9, -0.6, 0.7)),
+    RalColor::new(9007, "Grey aluminium", 0x8f8f8f, (56.9, 0.1, 2.7)),
+    RalColor::new(9010, "Pure white", 0xffffff, (94.5, -0.5, 4.7)),
+    RalColor::new(9011, "Graphite black", 0x1c1c1c, (26.4, -0.1, -1.4)),
+    RalColor::new(9016, "Traffic white", 0xf6f6f6, (95.7, -0.8, 2.6)),
+    RalColor::new(9017, "Traffic black", 0x1e1e1e, (25.5, 0.4, -0.3)),
+    RalColor::new(9018, "Papyrus white", 0xcfd3cd, (85.8, -2.6, 3.9)),
+    RalColor::new(9022, "Pearl light grey", 0x9c9c9c, (65.3, -0.2, -0.9)),
+    RalColor::new(9023, "Pearl dark grey", 0x828282, (56.2, -0.6, -1.9)),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::models::lab::Lab;
+    use crate::models::rgb::rgb24::RGB24;
+    use crate::models::rgb::RGBColor;
+    use crate::presets::ral::RalColor;
+
+    #[test]
+    fn all_() {
+        assert_eq!(213, RalColor::all().count());
+        let codes: Vec<u16> = RalColor::all().map(|color| color.code()).collect();
+        assert!(codes.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn from_code_() {
+        let traffic_red = RalColor::from_code("RAL 3020").unwrap();
+        assert_eq!(3020, traffic_red.code());
+        assert_eq!("Traffic red", traffic_red.name());
+        assert_eq!(RGB24::from_hex("cc0605"), traffic_red.to_rgb24());
+        assert_eq!(Some(traffic_red), RalColor::from_code("ral3020"));
+        assert_eq!(Some(traffic_red), RalColor::from_code("3020"));
+        assert_eq!(None, RalColor::from_code("RAL 3021"));
+        assert_eq!(None, RalColor::from_code("RAL"));
+    }
+
+    #[test]
+    fn from_name_() {
+        assert_eq!(9005, RalColor::from_name("jet black").unwrap().code());
+        assert_eq!(None, RalColor::from_name("jet blue"));
+    }
+
+    #[test]
+    fn lab() {
+        let lab = |code| RalColor::from_code(code).unwrap().lab();
+        assert_eq!((76.0, -0.4, 27.6), lab("1000"));
+        assert_eq!((41.2, 60.1, 44.0), lab("3020"));
+        assert_eq!((35.0, -6.5, -36.6), lab("5017"));
+        assert_eq!((80.4, -1.3, 1.4), lab("7035"));
+        assert_eq!((95.7, -0.8, 2.6), lab("9016"));
+    }
+
+    #[test]
+    fn nearest_() {
+        for code in ["1000", "3020", "5017", "6018", "7035", "8017", "9016"] {
+            let color = RalColor::from_code(code).unwrap();
+            let (l, a, b) = color.lab();
+            let rgb: RGB24 = Lab::new(l, a, b).to_rgb();
+            assert_eq!(color, RalColor::nearest(&rgb));
+        }
+        assert_eq!(9016, RalColor::nearest(&RGB24::WHITE).code());
+        assert_eq!(
+            3020,
+            RalColor::nearest(&RGB24::from_rgb(200, 10, 10)).code()
+        );
+    }
+
+    #[test]
+    fn fmt_() {
+        assert_eq!(
+            "RAL 3020 Traffic red",
+            format!("{}", RalColor::from_code("3020").unwrap())
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use crate::presets::Palette;
+
+/// The 16 colors of the [CGA](https://en.wikipedia.org/wiki/Color_Graphics_Adapter) (with the "brown" fix of the IBM 5153 monitor)
+pub const CGA: Palette = Palette::new("CGA", &CGA_COLORS);
+
+/// The 64 colors of the [EGA](https://en.wikipedia.org/wiki/Enhanced_Graphics_Adapter)
+///
+/// The index is the 6-bit `rgbRGB` value (secondary bits in the upper half).
+/// The default EGA palette maps the 16 [`CGA`] colors onto these.
+pub const EGA: Palette = Palette::new("EGA", &EGA_COLORS);
+
+/// The 256 colors of the default [VGA](https://en.wikipedia.org/wiki/Video_Graphics_Array) palette (mode `13h`)
+///
+/// Consists of the 16 [`CGA`] colors, a 16-step grayscale ramp, 9 hue rings of 24 colors
+/// (3 intensities times 3 saturations) and 8 times black.
+pub const VGA: Palette = Palette::new("VGA", &VGA_COLORS);
+
+/// The 64 colors of the [NES](https://en.wikipedia.org/wiki/Nintendo_Entertainment_System) (2C02 PPU)
+///
+/// The NES generates its colors from an NTSC signal, so there is no exact RGB equivalent.
+/// This is the widespread palette used by most emulators and pixel art tools.
+pub const NES: Palette = Palette::new("NES", &NES_COLORS);
+
+/// The 16 colors of the [Commodore 64](https://en.wikipedia.org/wiki/Commodore_64) (VIC-II) as measured by Pepto
+pub const C64: Palette = Palette::new("C64", &C64_COLORS);
+
+/// The 16 colors of the [ZX Spectrum](https://en.wikipedia.org/wiki/ZX_Spectrum_graphic_modes)
+///
+/// The indices `0-7` are the normal colors, `8-15` the *bright* variants.
+pub const ZX_SPECTRUM: Palette = Palette::new("ZX Spectrum", &ZX_SPECTRUM_COLORS);
+
+/// The 4 shades of green of the original [Game Boy](https://en.wikipedia.org/wiki/Game_Boy) (DMG) from lightest to darkest
+pub const GAME_BOY: Palette = Palette::new("Game Boy", &GAME_BOY_COLORS);
+
+static CGA_COLORS: [u32; 16] = [
+    0x000000, 0x0000aa, 0x00aa00, 0x00aaaa, 0xaa0000, 0xaa00aa, 0xaa5500, 0xaaaaaa, 0x555555,
+    0x5555ff, 0x55ff55, 0x55ffff, 0xff5555, 0xff55ff, 0xffff55, 0xffffff,
+];
+
+static EGA_COLORS: [u32; 64] = [
+    0x000000, 0x0000aa, 0x00aa00, 0x00aaaa, 0xaa0000, 0xaa00aa, 0xaaaa00, 0xaaaaaa, 0x000055,
+    0x0000ff, 0x00aa55, 0x00aaff, 0xaa0055, 0xaa00ff, 0xaaaa55, 0xaaaaff, 0x005500, 0x0055aa,
+    0x00ff00, 0x00ffaa, 0xaa5500, 0xaa55aa, 0xaaff00, 0xaaffaa, 0x005555, 0x0055ff, 0x00ff55,
+    0x00ffff, 0xaa5555, 0xaa55ff, 0xaaff55, 0xaaffff, 0x550000, 0x5500aa, 0x55aa00, 0x55aaaa,
+    0xff0000, 0xff00aa, 0xffaa00, 0xffaaaa, 0x550055, 0x5500ff, 0x55aa55, 0x55aaff, 0xff0055,
+    0xff00ff, 0xffaa55, 0xffaaff, 0x555500, 0x5555aa, 0x55ff00, 0x55ffaa, 0xff5500, 0xff55aa,
+    0xffff00, 0xffffaa, 0x555555, 0x5555ff, 0x55ff55, 0x55ffff, 0xff5555, 0xff55ff, 0xffff55,
+    0xffffff,
+];
+
+static VGA_COLORS: [u32; 256] = [
+    0x000000, 0x0000aa, 0x00aa00, 0x00aaaa, 0xaa0000, 0xaa00aa, 0xaa5500, 0xaaaaaa, 0x555555,
+    0x5555ff, 0x55ff55, 0x55ffff, 0xff5555, 0xff55ff, 0xffff55, 0xffffff, 0x000000, 0x141414,
+    0x202020, 0x2c2c2c, 0x383838, 0x454545, 0x515151, 0x616161, 0x717171, 0x828282, 0x929292,
+    0xa2a2a2, 0xb6b6b6, 0xcbcbcb, 0xe3e3e3, 0xffffff, 0x0000ff, 0x4100ff, 0x7d00ff, 0xbe00ff,
+    0xff00ff, 0xff00be, 0xff007d, 0xff0041, 0xff0000, 0xff4100, 0xff7d00, 0xffbe00, 0xffff00,
+    0xbeff00, 0x7dff00, 0x41ff00, 0x00ff00, 0x00ff41, 0x00ff7d, 0x00ffbe, 0x00ffff, 0x00beff,
+    0x007dff, 0x0041ff, 0x7d7dff, 0x9e7dff, 0xbe7dff, 0xdf7dff, 0xff7dff, 0xff7ddf, 0xff7dbe,
+    0xff7d9e, 0xff7d7d, 0xff9e7d, 0xffbe7d, 0xffdf7d, 0xffff7d, 0xdfff7d, 0xbeff7d, 0x9eff7d,
+    0x7dff7d, 0x7dff9e, 0x7dffbe, 0x7dffdf, 0x7dffff, 0x7ddfff, 0x7dbeff, 0x7d9eff, 0xb6b6ff,
+    0xc7b6ff, 0xdbb6ff, 0xebb6ff, 0xffb6ff, 0xffb6eb, 0xffb6db, 0xffb6c7, 0xffb6b6, 0xffc7b6,
+    0xffdbb6, 0xffebb6, 0xffffb6, 0xebffb6, 0xdbffb6, 0xc7ffb6, 0xb6ffb6, 0xb6ffc7, 0xb6ffdb,
+    0xb6ffeb, 0xb6ffff, 0xb6ebff, 0xb6dbff, 0xb6c7ff, 0x000071, 0x1c0071, 0x380071, 0x550071,
+    0x710071, 0x710055, 0x710038, 0x71001c, 0x710000, 0x711c00, 0x713800, 0x715500, 0x717100,
+    0x557100, 0x387100, 0x1c7100, 0x007100, 0x00711c, 0x007138, 0x007155, 0x007171, 0x005571,
+    0x003871, 0x001c71, 0x383871, 0x453871, 0x553871, 0x613871, 0x713871, 0x713861, 0x713855,
+    0x713845, 0x713838, 0x714538, 0x715538, 0x716138, 0x717138, 0x617138, 0x557138, 0x457138,
+    0x387138, 0x387145, 0x387155, 0x387161, 0x387171, 0x386171, 0x385571, 0x384571, 0x515171,
+    0x595171, 0x615171, 0x695171, 0x715171, 0x715169, 0x715161, 0x715159, 0x715151, 0x715951,
+    0x716151, 0x716951, 0x717151, 0x697151, 0x617151, 0x597151, 0x517151, 0x517159, 0x517161,
+    0x517169, 0x517171, 0x516971, 0x516171, 0x515971, 0x000041, 0x100041, 0x200041, 0x300041,
+    0x410041, 0x410030, 0x410020, 0x410010, 0x410000, 0x411000, 0x412000, 0x413000, 0x414100,
+    0x304100, 0x204100, 0x104100, 0x004100, 0x004110, 0x004120, 0x004130, 0x004141, 0x003041,
+    0x002041, 0x001041, 0x202041, 0x282041, 0x302041, 0x382041, 0x412041, 0x412038, 0x412030,
+    0x412028, 0x412020, 0x412820, 0x413020, 0x413820, 0x414120, 0x384120, 0x304120, 0x284120,
+    0x204120, 0x204128, 0x204130, 0x204138, 0x204141, 0x203841, 0x203041, 0x202841, 0x2c2c41,
+    0x302c41, 0x342c41, 0x3c2c41, 0x412c41, 0x412c3c, 0x412c34, 0x412c30, 0x412c2c, 0x41302c,
+    0x41342c, 0x413c2c, 0x41412c, 0x3c412c, 0x34412c, 0x30412c, 0x2c412c, 0x2c4130, 0x2c4134,
+    0x2c413c, 0x2c4141, 0x2c3c41, 0x2c3441, 0x2c3041, 0x000000, 0x000000, 0x000000, 0x000000,
+    0x000000, 0x000000, 0x000000, 0x000000,
+];
+
+static NES_COLORS: [u32; 64] = [
+    0x7c7c7c, 0x0000fc, 0x0000bc, 0x4428bc, 0x940084, 0xa80020, 0xa81000, 0x881400, 0x503000,
+    0x007800, 0x006800, 0x005800, 0x004058, 0x000000, 0x000000, 0x000000, 0xbcbcbc, 0x0078f8,
+    0x0058f8, 0x6844fc, 0xd800cc, 0xe40058, 0xf83800, 0xe45c10, 0xac7c00, 0x00b800, 0x00a800,
+    0x00a844, 0x008888, 0x000000, 0x000000, 0x000000, 0xf8f8f8, 0x3cbcfc, 0x6888fc, 0x9878f8,
+    0xf878f8, 0xf85898, 0xf87858, 0xfca044, 0xf8b800, 0xb8f818, 0x58d854, 0x58f898, 0x00e8d8,
+    0x787878, 0x000000, 0x000000, 0xfcfcfc, 0xa4e4fc, 0xb8b8f8, 0xd8b8f8, 0xf8b8f8, 0xf8a4c0,
+    0xf0d0b0, 0xfce0a8, 0xf8d878, 0xd8f878, 0xb8f8b8, 0xb8f8d8, 0x00fcfc, 0xf8d8f8, 0x000000,
+    0x000000,
+];
+
+static C64_COLORS: [u32; 16] = [
+    0x000000, 0xffffff, 0x68372b, 0x70a4b2, 0x6f3d86, 0x588d43, 0x352879, 0xb8c76f, 0x6f4f25,
+    0x433900, 0x9a6759, 0x444444, 0x6c6c6c, 0x9ad284, 0x6c5eb5, 0x959595,
+];
+
+static ZX_SPECTRUM_COLORS: [u32; 16] = [
+    0x000000, 0x0000d7, 0xd70000, 0xd700d7, 0x00d700, 0x00d7d7, 0xd7d700, 0xd7d7d7, 0x000000,
+    0x0000ff, 0xff0000, 0xff00ff, 0x00ff00, 0x00ffff, 0xffff00, 0xffffff,
+];
+
+static GAME_BOY_COLORS: [u32; 4] = [0x9bbc0f, 0x8bac0f, 0x306230, 0x0f380f];
+
+#[cfg(test)]
+mod tests {
+    use crate::models::rgb::rgb24::RGB24;
+    use crate::models::rgb::RGBColor;
+    use crate::presets::retro::{C64, CGA, EGA, GAME_BOY, NES, VGA, ZX_SPECTRUM};
+
+    #[test]
+    fn len_() {
+        assert_eq!(16, CGA.len());
+        assert_eq!(64, EGA.len());
+        assert_eq!(256, VGA.len());
+        assert_eq!(64, NES.len());
+        assert_eq!(16, C64.len());
+        assert_eq!(16, ZX_SPECTRUM.len());
+        assert_eq!(4, GAME_BOY.len());
+    }
+
+    #[test]
+    fn cga_is_part_of_ega_and_vga() {
+        for (index, color) in CGA.colors().enumerate() {
+            assert!(EGA.index_of(&color).is_some());
+            assert_eq!(Some(color), VGA.get(index));
+        }
+        assert_eq!(Some(RGB24::from_hex("aa5500")), CGA.get(6));
+        assert_eq!(Some(20), EGA.index_of(&RGB24::from_hex("aa5500")));
+    }
+
+    #[test]
+    fn vga_() {
+        assert_eq!(Some(RGB24::from_hex("141414")), VGA.get(17));
+        assert_eq!(Some(RGB24::BLUE), VGA.get(32));
+        assert_eq!(Some(RGB24::from_hex("4100ff")), VGA.get(33));
+        assert_eq!(Some(RGB24::RED), VGA.get(40));
+        assert_eq!(Some(RGB24::BLACK), VGA.get(255));
+    }
+
+    #[test]
+    fn nearest_index_() {
+        assert_eq!(15, CGA.nearest_index(&RGB24::from_rgb(250, 250, 240)));
+        assert_eq!(3, GAME_BOY.nearest_index(&RGB24::BLACK));
+        assert_eq!(0, GAME_BOY.nearest_index(&RGB24::WHITE));
+        assert_eq!(10, ZX_SPECTRUM.nearest_index(&RGB24::RED));
+        assert_eq!(2, C64.nearest_index(&RGB24::from_hex("68372b")));
+    }
+}
@@ -0,0 +1,280 @@
+use crate::models::rgb::rgb24::RGB24;
+use crate::models::rgb::RGBColor;
+use crate::names::normalize_name;
+use crate::presets::nearest_by_key;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+
+/// A table of named colors which is built at runtime
+///
+/// Unlike the built-in presets the colors can be loaded from text,
+/// e.g. an X11 `rgb.txt` file (see [`ColorTable::parse_rgb_txt`])
+/// or a simple `name,#hex` CSV file (see [`ColorTable::parse_csv`]).
+///
+/// Names are looked up the same way as [`X11Color::from_name`](crate::presets::X11Color::from_name):
+/// ignoring case, whitespace, dashes and underscores and treating `grey` and `gray` alike.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColorTable {
+    entries: Vec<(String, RGB24)>,
+    index: HashMap<String, usize>,
+}
+
+/// The error returned if a line of a color table could not be parsed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    content: String,
+}
+
+impl ColorTable {
+    /// Creates a new, empty `ColorTable`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a table in the format of the X11 `rgb.txt`
+    ///
+    /// Every line consists of the decimal values for red, green and blue followed by the name,
+    /// e.g. `240 248 255  alice blue`.
+    /// Empty lines and comments (starting with `!` or `#`) are ignored.
+    ///
+    /// # Errors
+    /// If a line does not match the format
+    pub fn parse_rgb_txt(text: &str) -> std::result::Result<Self, ParseError> {
+        let mut table = ColorTable::new();
+        for (number, line) in content_lines(text) {
+            let error = || ParseError::new(number, line);
+            let mut parts = line.split_whitespace();
+            let mut channel = || -> std::result::Result<u8, ParseError> {
+                parts.next().and_then(|c| c.parse().ok()).ok_or_else(error)
+            };
+            let color = RGB24::from_rgb(channel()?, channel()?, channel()?);
+            let name = parts.collect::<Vec<_>>().join(" ");
+            if name.is_empty() {
+                return Err(error());
+            }
+            table.insert(&name, color);
+        }
+        Ok(table)
+    }
+
+    /// Parses a table consisting of lines like `name,#hex`
+    ///
+    /// The hex value may have 3 or 6 digits and the `#` is optional
+    /// (see [`RGB24::from_hex`]).
+    /// Empty lines and comments (starting with `!` or `#`) are ignored.
+    ///
+    /// # Errors
+    /// If a line does not match the format
+    pub fn parse_csv(text: &str) -> std::result::Result<Self, ParseError> {
+        let mut table = ColorTable::new();
+        for (number, line) in content_lines(text) {
+            let error = || ParseError::new(number, line);
+            let (name, hex) = line.rsplit_once(',').ok_or_else(error)?;
+            let name = name.trim();
+            let hex = hex.trim();
+            let hex = hex.strip_prefix('#').unwrap_or(hex);
+            let is_valid_hex =
+                (hex.len() == 3 || hex.len() == 6) && hex.chars().all(|c| c.is_ascii_hexdigit());
+            if name.is_empty() || !is_valid_hex {
+                return Err(error());
+            }
+            table.insert(name, RGB24::from_hex(hex));
+        }
+        Ok(table)
+    }
+
+    /// Inserts a named color
+    ///
+    /// If there already is a color with the same (normalized) name,
+    /// its color is replaced but the original name is kept.
+    pub fn insert(&mut self, name: &str, color: RGB24) {
+        match self.index.get(&normalize_name(name)) {
+            Some(&index) => self.entries[index].1 = color,
+            None => {
+                self.index.insert(normalize_name(name), self.entries.len());
+                self.entries.push((name.to_string(), color));
+            }
+        }
+    }
+
+    /// Returns the number of colors in this table
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if this table contains no colors
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over all `(name, color)` pairs in order of insertion
+    pub fn iter(&self) -> impl Iterator<Item = (&str, RGB24)> {
+        self.entries
+            .iter()
+            .map(|(name, color)| (name.as_str(), *color))
+    }
+
+    /// Looks up the color with the given name
+    ///
+    /// # Returns
+    /// `None` if there is no color with the given name
+    pub fn get(&self, name: &str) -> Option<RGB24> {
+        self.index
+            .get(&normalize_name(name))
+            .map(|&index| self.entries[index].1)
+    }
+
+    /// Returns the (first) name of exactly the given color
+    ///
+    /// # Returns
+    /// `None` if the color is not part of this table
+    pub fn name_of(&self, color: &RGB24) -> Option<&str> {
+        self.iter().find(|(_, c)| c == color).map(|(name, _)| name)
+    }
+
+    /// Returns the `(name, color)` pair closest to the given color
+    ///
+    /// Uses the same perceptual metric as [`X11Color::nearest`](crate::presets::X11Color::nearest).
+    ///
+    /// # Returns
+    /// `None` if the table is empty
+    pub fn nearest<T>(&self, color: &impl RGBColor<T>) -> Option<(&str, RGB24)> {
+        nearest_by_key(color, self.iter(), |&(_, c)| c)
+    }
+}
+
+impl ParseError {
+    fn new(line: usize, content: &str) -> Self {
+        ParseError {
+            line,
+            content: content.to_string(),
+        }
+    }
+
+    /// Returns the number of the invalid line (starting at 1)
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "invalid color in line {}: {}", self.line, self.content)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Returns all lines which are neither empty nor comments together with their line number
+fn content_lines(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('!') && !line.starts_with('#'))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::rgb::rgb24::RGB24;
+    use crate::models::rgb::RGBColor;
+    use crate::presets::table::ColorTable;
+
+    const RGB_TXT: &str = "! $Xorg: rgb.txt,v 1.3 2000/08/17 19:54:00 cpqbld Exp $
+255 250 250		snow
+248 248 255		ghost white
+248 248 255		GhostWhite
+
+  0   0   0		black
+190 190 190		gray
+255   0   0		red
+";
+
+    #[test]
+    fn parse_rgb_txt_() {
+        let table = ColorTable::parse_rgb_txt(RGB_TXT).unwrap();
+        assert_eq!(5, table.len());
+        assert_eq!(Some(RGB24::from_rgb(255, 250, 250)), table.get("snow"));
+        assert_eq!(
+            Some(RGB24::from_rgb(248, 248, 255)),
+            table.get("GhostWhite")
+        );
+        assert_eq!(Some(RGB24::from_rgb(190, 190, 190)), table.get("Grey"));
+        assert_eq!(None, table.get("blue"));
+        assert_eq!(
+            vec!["snow", "ghost white", "black", "gray", "red"],
+            table.iter().map(|(name, _)| name).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn parse_rgb_txt_invalid() {
+        assert_eq!(
+            2,
+            ColorTable::parse_rgb_txt("0 0 0 black\n0 0 blue")
+                .unwrap_err()
+                .line()
+        );
+        assert_eq!(
+            1,
+            ColorTable::parse_rgb_txt("0 0 256 blue")
+                .unwrap_err()
+                .line()
+        );
+        assert_eq!(1, ColorTable::parse_rgb_txt("0 0 255").unwrap_err().line());
+    }
+
+    #[test]
+    fn parse_csv_() {
+        let table =
+            ColorTable::parse_csv("brand red,#e30613\nbrand gray, 333\n\nwhite,ffffff").unwrap();
+        assert_eq!(3, table.len());
+        assert_eq!(Some(RGB24::from_hex("e30613")), table.get("Brand Red"));
+        assert_eq!(Some(RGB24::from_hex("333333")), table.get("brand grey"));
+        assert_eq!(Some("white"), table.name_of(&RGB24::WHITE));
+    }
+
+    #[test]
+    fn parse_csv_invalid() {
+        assert_eq!(1, ColorTable::parse_csv("red;#ff0000").unwrap_err().line());
+        assert_eq!(1, ColorTable::parse_csv("red,#ff00").unwrap_err().line());
+        assert_eq!(
+            2,
+            ColorTable::parse_csv("red,#ff0000\n,#00ff00")
+                .unwrap_err()
+                .line()
+        );
+        assert_eq!(
+            "invalid color in line 1: red,#xyz",
+            format!("{}", ColorTable::parse_csv("red,#xyz").unwrap_err())
+        );
+    }
+
+    #[test]
+    fn insert_() {
+        let mut table = ColorTable::new();
+        assert!(table.is_empty());
+        table.insert("Sky", RGB24::BLUE);
+        table.insert("sky", RGB24::from_hex("87ceeb"));
+        assert_eq!(1, table.len());
+        assert_eq!(
+            Some(("Sky", RGB24::from_hex("87ceeb"))),
+            table.iter().next()
+        );
+    }
+
+    #[test]
+    fn nearest_() {
+        let table = ColorTable::parse_rgb_txt(RGB_TXT).unwrap();
+        assert_eq!(
+            Some(("red", RGB24::RED)),
+            table.nearest(&RGB24::from_rgb(230, 20, 10))
+        );
+        assert_eq!(
+            "black",
+            table.nearest(&RGB24::from_rgb(20, 20, 20)).unwrap().0
+        );
+        assert_eq!(None, ColorTable::new().nearest(&RGB24::BLACK));
+    }
+}
@@ -0,0 +1,180 @@
+use crate::models::rgb::rgb24::RGB24;
+use crate::models::rgb::RGBColor;
+use crate::presets::{level_neighbours, nearest_by_key, Palette};
+
+/// The 16 ANSI colors (8 normal, 8 bright) with the default values of xterm
+///
+/// Most terminals allow to configure these, so the actual colors may differ.
+pub const ANSI_16: Palette = Palette::new("ANSI", &ANSI_COLORS);
+
+/// The 256 colors of [xterm](https://en.wikipedia.org/wiki/Xterm#/media/File:Xterm_256color_chart.svg)
+///
+/// - `0-15`: the [`ANSI_16`] colors
+/// - `16-231`: a 6x6x6 color cube (`16 + 36 * r + 6 * g + b`)
+/// - `232-255`: a grayscale ramp from dark to light
+pub const XTERM_256: Palette = Palette::new("xterm", &XTERM_COLORS);
+
+/// The channel values of the xterm color cube
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The values of the xterm grayscale ramp
+const GRAY_LEVELS: [u8; 24] = [
+    8, 18, 28, 38, 48, 58, 68, 78, 88, 98, 108, 118, 128, 138, 148, 158, 168, 178, 188, 198, 208,
+    218, 228, 238,
+];
+
+/// Returns the color of the given xterm index
+///
+/// This is the inverse of [`nearest_xterm_index`].
+pub fn xterm_color(index: u8) -> RGB24 {
+    XTERM_256
+        .get(index as usize)
+        .expect("XTERM_256 has a color for every u8")
+}
+
+/// Returns the index of the xterm color closest to the given color
+///
+/// Only the color cube and the grayscale ramp (`16-255`) are considered,
+/// since the [`ANSI_16`] colors are usually customized by the user.
+///
+/// Instead of comparing against all colors of the palette only the neighbouring cube
+/// and grayscale values are compared using the perceptual metric of [`Palette::nearest_index`].
+pub fn nearest_xterm_index<T>(color: &impl RGBColor<T>) -> u8 {
+    let rgb = RGB24::from(color.as_tuple_f64());
+    let gray = weighted_gray(color);
+
+    let mut candidates = Vec::with_capacity(12);
+    for &r in level_neighbours(&CUBE_LEVELS, rgb.r()).iter() {
+        for &g in level_neighbours(&CUBE_LEVELS, rgb.g()).iter() {
+            for &b in level_neighbours(&CUBE_LEVELS, rgb.b()).iter() {
+                candidates.push(16 + 36 * r as u8 + 6 * g as u8 + b as u8);
+            }
+        }
+    }
+    // the weighted gray only approximates the metric, so the next ramp entries are checked as well
+    let [lower, upper] = level_neighbours(&GRAY_LEVELS, gray);
+    for index in lower.saturating_sub(1)..=(upper + 1).min(GRAY_LEVELS.len() - 1) {
+        candidates.push(232 + index as u8);
+    }
+
+    nearest_by_key(color, candidates.into_iter(), |&index| xterm_color(index))
+        .expect("there is at least one candidate")
+}
+
+/// Returns the gray value closest to the given color
+///
+/// Weights the channels like the perceptual metric of [`Palette::nearest_index`],
+/// using the red channel of the color in place of the red mean.
+fn weighted_gray<T>(color: &impl RGBColor<T>) -> u8 {
+    let (r, g, b) = color.as_tuple_f64();
+    let (r_weight, g_weight, b_weight) = (2.0 + r, 4.0, 3.0 - r);
+    let gray = (r_weight * r + g_weight * g + b_weight * b) / (r_weight + g_weight + b_weight);
+    (gray * 255.0).round() as u8
+}
+
+/// Returns the index of the [`ANSI_16`] color closest to the given color
+pub fn nearest_ansi_index<T>(color: &impl RGBColor<T>) -> u8 {
+    ANSI_16.nearest_index(color) as u8
+}
+
+static ANSI_COLORS: [u32; 16] = [
+    0x000000, 0xcd0000, 0x00cd00, 0xcdcd00, 0x0000ee, 0xcd00cd, 0x00cdcd, 0xe5e5e5, 0x7f7f7f,
+    0xff0000, 0x00ff00, 0xffff00, 0x5c5cff, 0xff00ff, 0x00ffff, 0xffffff,
+];
+
+static XTERM_COLORS: [u32; 256] = [
+    0x000000, 0xcd0000, 0x00cd00, 0xcdcd00, 0x0000ee, 0xcd00cd, 0x00cdcd, 0xe5e5e5, 0x7f7f7f,
+    0xff0000, 0x00ff00, 0xffff00, 0x5c5cff, 0xff00ff, 0x00ffff, 0xffffff, 0x000000, 0x00005f,
+    0x000087, 0x0000af, 0x0000d7, 0x0000ff, 0x005f00, 0x005f5f, 0x005f87, 0x005faf, 0x005fd7,
+    0x005fff, 0x008700, 0x00875f, 0x008787, 0x0087af, 0x0087d7, 0x0087ff, 0x00af00, 0x00af5f,
+    0x00af87, 0x00afaf, 0x00afd7, 0x00afff, 0x00d700, 0x00d75f, 0x00d787, 0x00d7af, 0x00d7d7,
+    0x00d7ff, 0x00ff00, 0x00ff5f, 0x00ff87, 0x00ffaf, 0x00ffd7, 0x00ffff, 0x5f0000, 0x5f005f,
+    0x5f0087, 0x5f00af, 0x5f00d7, 0x5f00ff, 0x5f5f00, 0x5f5f5f, 0x5f5f87, 0x5f5faf, 0x5f5fd7,
+    0x5f5fff, 0x5f8700, 0x5f875f, 0x5f8787, 0x5f87af, 0x5f87d7, 0x5f87ff, 0x5faf00, 0x5faf5f,
+    0x5faf87, 0x5fafaf, 0x5fafd7, 0x5fafff, 0x5fd700, 0x5fd75f, 0x5fd787, 0x5fd7af, 0x5fd7d7,
+    0x5fd7ff, 0x5fff00, 0x5fff5f, 0x5fff87, 0x5fffaf, 0x5fffd7, 0x5fffff, 0x870000, 0x87005f,
+    0x870087, 0x8700af, 0x8700d7, 0x8700ff, 0x875f00, 0x875f5f, 0x875f87, 0x875faf, 0x875fd7,
+    0x875fff, 0x878700, 0x87875f, 0x878787, 0x8787af, 0x8787d7, 0x8787ff, 0x87af00, 0x87af5f,
+    0x87af87, 0x87afaf, 0x87afd7, 0x87afff, 0x87d700, 0x87d75f, 0x87d787, 0x87d7af, 0x87d7d7,
+    0x87d7ff, 0x87ff00, 0x87ff5f, 0x87ff87, 0x87ffaf, 0x87ffd7, 0x87ffff, 0xaf0000, 0xaf005f,
+    0xaf0087, 0xaf00af, 0xaf00d7, 0xaf00ff, 0xaf5f00, 0xaf5f5f, 0xaf5f87, 0xaf5faf, 0xaf5fd7,
+    0xaf5fff, 0xaf8700, 0xaf875f, 0xaf8787, 0xaf87af, 0xaf87d7, 0xaf87ff, 0xafaf00, 0xafaf5f,
+    0xafaf87, 0xafafaf, 0xafafd7, 0xafafff, 0xafd700, 0xafd75f, 0xafd787, 0xafd7af, 0xafd7d7,
+    0xafd7ff, 0xafff00, 0xafff5f, 0xafff87, 0xafffaf, 0xafffd7, 0xafffff, 0xd70000, 0xd7005f,
+    0xd70087, 0xd700af, 0xd700d7, 0xd700ff, 0xd75f00, 0xd75f5f, 0xd75f87, 0xd75faf, 0xd75fd7,
+    0xd75fff, 0xd78700, 0xd7875f, 0xd78787, 0xd787af, 0xd787d7, 0xd787ff, 0xd7af00, 0xd7af5f,
+    0xd7af87, 0xd7afaf, 0xd7afd7, 0xd7afff, 0xd7d700, 0xd7d75f, 0xd7d787, 0xd7d7af, 0xd7d7d7,
+    0xd7d7ff, 0xd7ff00, 0xd7ff5f, 0xd7ff87, 0xd7ffaf, 0xd7ffd7, 0xd7ffff, 0xff0000, 0xff005f,
+    0xff0087, 0xff00af, 0xff00d7, 0xff00ff, 0xff5f00, 0xff5f5f, 0xff5f87, 0xff5faf, 0xff5fd7,
+    0xff5fff, 0xff8700, 0xff875f, 0xff8787, 0xff87af, 0xff87d7, 0xff87ff, 0xffaf00, 0xffaf5f,
+    0xffaf87, 0xffafaf, 0xffafd7, 0xffafff, 0xffd700, 0xffd75f, 0xffd787, 0xffd7af, 0xffd7d7,
+    0xffd7ff, 0xffff00, 0xffff5f, 0xffff87, 0xffffaf, 0xffffd7, 0xffffff, 0x080808, 0x121212,
+    0x1c1c1c, 0x262626, 0x303030, 0x3a3a3a, 0x444444, 0x4e4e4e, 0x585858, 0x626262, 0x6c6c6c,
+    0x767676, 0x808080, 0x8a8a8a, 0x949494, 0x9e9e9e, 0xa8a8a8, 0xb2b2b2, 0xbcbcbc, 0xc6c6c6,
+    0xd0d0d0, 0xdadada, 0xe4e4e4, 0xeeeeee,
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::models::rgb::rgb24::RGB24;
+    use crate::models::rgb::rgb48::RGB48;
+    use crate::models::rgb::RGBColor;
+    use crate::presets::terminal::{
+        nearest_ansi_index, nearest_xterm_index, xterm_color, ANSI_16, XTERM_256,
+    };
+    use crate::presets::Palette;
+
+    #[test]
+    fn xterm_color_() {
+        assert_eq!(RGB24::BLACK, xterm_color(0));
+        assert_eq!(RGB24::from_hex("5fd7ff"), xterm_color(81));
+        assert_eq!(RGB24::from_hex("080808"), xterm_color(232));
+        assert_eq!(RGB24::from_hex("eeeeee"), xterm_color(255));
+    }
+
+    #[test]
+    fn ansi_is_part_of_xterm() {
+        for (index, color) in ANSI_16.colors().enumerate() {
+            assert_eq!(color, xterm_color(index as u8));
+        }
+    }
+
+    #[test]
+    fn nearest_xterm_index_exact() {
+        for index in 16..=255 {
+            assert_eq!(index, nearest_xterm_index(&xterm_color(index)));
+        }
+    }
+
+    #[test]
+    fn nearest_xterm_index_matches_exhaustive_search() {
+        let cube_and_grays = Palette::new("cube and grays", &super::XTERM_COLORS[16..]);
+        for r in (0..=255).step_by(5) {
+            for g in (0..=255).step_by(5) {
+                for b in (0..=255).step_by(5) {
+                    let color = RGB24::from_rgb(r, g, b);
+                    assert_eq!(
+                        16 + cube_and_grays.nearest_index(&color),
+                        nearest_xterm_index(&color) as usize,
+                        "{}",
+                        color
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn nearest_xterm_index_rgb48() {
+        assert_eq!(231, nearest_xterm_index(&RGB48::WHITE));
+        assert_eq!(196, nearest_xterm_index(&RGB48::RED));
+    }
+
+    #[test]
+    fn nearest_ansi_index_() {
+        assert_eq!(0, nearest_ansi_index(&RGB24::from_rgb(10, 10, 10)));
+        assert_eq!(9, nearest_ansi_index(&RGB24::RED));
+        assert_eq!(15, nearest_ansi_index(&RGB24::WHITE));
+        assert_eq!(256, XTERM_256.len());
+    }
+}
@@ -0,0 +1,100 @@
+use crate::models::rgb::rgb24::RGB24;
+use crate::models::rgb::RGBColor;
+use crate::presets::{level_neighbours, nearest_by_key, Palette};
+
+/// The 216 [web-safe colors](https://en.wikipedia.org/wiki/Web_colors#Web-safe_colors)
+///
+/// Every channel is one of `00, 33, 66, 99, cc, ff`, the index is `36 * r + 6 * g + b`.
+pub const WEB_SAFE: Palette = Palette::new("Web-safe", &WEB_SAFE_COLORS);
+
+/// The channel values of the web-safe colors
+const LEVELS: [u8; 6] = [0x00, 0x33, 0x66, 0x99, 0xcc, 0xff];
+
+/// Returns the index of the [`WEB_SAFE`] color closest to the given color
+///
+/// Only the neighbouring values of each channel are compared using the perceptual metric of
+/// [`Palette::nearest_index`].
+pub fn nearest_web_safe_index<T>(color: &impl RGBColor<T>) -> u8 {
+    let rgb = RGB24::from(color.as_tuple_f64());
+
+    let mut candidates = Vec::with_capacity(8);
+    for &r in level_neighbours(&LEVELS, rgb.r()).iter() {
+        for &g in level_neighbours(&LEVELS, rgb.g()).iter() {
+            for &b in level_neighbours(&LEVELS, rgb.b()).iter() {
+                candidates.push(36 * r as u8 + 6 * g as u8 + b as u8);
+            }
+        }
+    }
+
+    nearest_by_key(color, candidates.into_iter(), |&index| {
+        WEB_SAFE
+            .get(index as usize)
+            .expect("index is within the cube")
+    })
+    .expect("there is at least one candidate")
+}
+
+static WEB_SAFE_COLORS: [u32; 216] = [
+    0x000000, 0x000033, 0x000066, 0x000099, 0x0000cc, 0x0000ff, 0x003300, 0x003333, 0x003366,
+    0x003399, 0x0033cc, 0x0033ff, 0x006600, 0x006633, 0x006666, 0x006699, 0x0066cc, 0x0066ff,
+    0x009900, 0x009933, 0x009966, 0x009999, 0x0099cc, 0x0099ff, 0x00cc00, 0x00cc33, 0x00cc66,
+    0x00cc99, 0x00cccc, 0x00ccff, 0x00ff00, 0x00ff33, 0x00ff66, 0x00ff99, 0x00ffcc, 0x00ffff,
+    0x330000, 0x330033, 0x330066, 0x330099, 0x3300cc, 0x3300ff, 0x333300, 0x333333, 0x333366,
+    0x333399, 0x3333cc, 0x3333ff, 0x336600, 0x336633, 0x336666, 0x336699, 0x3366cc, 0x3366ff,
+    0x339900, 0x339933, 0x339966, 0x339999, 0x3399cc, 0x3399ff, 0x33cc00, 0x33cc33, 0x33cc66,
+    0x33cc99, 0x33cccc, 0x33ccff, 0x33ff00, 0x33ff33, 0x33ff66, 0x33ff99, 0x33ffcc, 0x33ffff,
+    0x660000, 0x660033, 0x660066, 0x660099, 0x6600cc, 0x6600ff, 0x663300, 0x663333, 0x663366,
+    0x663399, 0x6633cc, 0x6633ff, 0x666600, 0x666633, 0x666666, 0x666699, 0x6666cc, 0x6666ff,
+    0x669900, 0x669933, 0x669966, 0x669999, 0x6699cc, 0x6699ff, 0x66cc00, 0x66cc33, 0x66cc66,
+    0x66cc99, 0x66cccc, 0x66ccff, 0x66ff00, 0x66ff33, 0x66ff66, 0x66ff99, 0x66ffcc, 0x66ffff,
+    0x990000, 0x990033, 0x990066, 0x990099, 0x9900cc, 0x9900ff, 0x993300, 0x993333, 0x993366,
+    0x993399, 0x9933cc, 0x9933ff, 0x996600, 0x996633, 0x996666, 0x996699, 0x9966cc, 0x9966ff,
+    0x999900, 0x999933, 0x999966, 0x999999, 0x9999cc, 0x9999ff, 0x99cc00, 0x99cc33, 0x99cc66,
+    0x99cc99, 0x99cccc, 0x99ccff, 0x99ff00, 0x99ff33, 0x99ff66, 0x99ff99, 0x99ffcc, 0x99ffff,
+    0xcc0000, 0xcc0033, 0xcc0066, 0xcc0099, 0xcc00cc, 0xcc00ff, 0xcc3300, 0xcc3333, 0xcc3366,
+    0xcc3399, 0xcc33cc, 0xcc33ff, 0xcc6600, 0xcc6633, 0xcc6666, 0xcc6699, 0xcc66cc, 0xcc66ff,
+    0xcc9900, 0xcc9933, 0xcc9966, 0xcc9999, 0xcc99cc, 0xcc99ff, 0xcccc00, 0xcccc33, 0xcccc66,
+    0xcccc99, 0xcccccc, 0xccccff, 0xccff00, 0xccff33, 0xccff66, 0xccff99, 0xccffcc, 0xccffff,
+    0xff0000, 0xff0033, 0xff0066, 0xff0099, 0xff00cc, 0xff00ff, 0xff3300, 0xff3333, 0xff3366,
+    0xff3399, 0xff33cc, 0xff33ff, 0xff6600, 0xff6633, 0xff6666, 0xff6699, 0xff66cc, 0xff66ff,
+    0xff9900, 0xff9933, 0xff9966, 0xff9999, 0xff99cc, 0xff99ff, 0xffcc00, 0xffcc33, 0xffcc66,
+    0xffcc99, 0xffcccc, 0xffccff, 0xffff00, 0xffff33, 0xffff66, 0xffff99, 0xffffcc, 0xffffff,
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::models::rgb::rgb24::RGB24;
+    use crate::models::rgb::RGBColor;
+    use crate::presets::web::{nearest_web_safe_index, WEB_SAFE};
+
+    #[test]
+    fn web_safe_() {
+        assert_eq!(216, WEB_SAFE.len());
+        assert_eq!(Some(RGB24::BLACK), WEB_SAFE.get(0));
+        assert_eq!(Some(RGB24::from_hex("336699")), WEB_SAFE.get(51));
+        assert_eq!(Some(RGB24::WHITE), WEB_SAFE.get(215));
+    }
+
+    #[test]
+    fn nearest_web_safe_index_() {
+        for (index, color) in WEB_SAFE.colors().enumerate() {
+            assert_eq!(index, nearest_web_safe_index(&color) as usize);
+        }
+        assert_eq!(51, nearest_web_safe_index(&RGB24::from_rgb(50, 100, 155)));
+    }
+
+    #[test]
+    fn nearest_web_safe_index_matches_exhaustive_search() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let color = RGB24::from_rgb(r, g, b);
+                    assert_eq!(
+                        WEB_SAFE.nearest_index(&color),
+                        nearest_web_safe_index(&color) as usize
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1086 @@
+use crate::models::rgb::rgb24::RGB24;
+use crate::models::rgb::RGBColor;
+use crate::names::normalize_name;
+use crate::presets::{nearest_by_key, rgb24_from_hex};
+use std::fmt::{Display, Formatter, Result};
+
+/// A color of the [xkcd color survey](https://xkcd.com/color/rgb/)
+///
+/// The survey asked participants to name randomly chosen colors,
+/// resulting in the 949 most common names together with their average sRGB value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct XkcdColor {
+    name: &'static str,
+    hex: u32,
+}
+
+impl XkcdColor {
+    const fn new(name: &'static str, hex: u32) -> Self {
+        XkcdColor { name, hex }
+    }
+
+    /// Returns an iterator over all colors of the survey ordered by name
+    pub fn all() -> impl Iterator<Item = XkcdColor> {
+        XKCD_COLORS.iter().copied()
+    }
+
+    /// Looks up the color with the given name, e.g. `"dusty rose"`
+    ///
+    /// The lookup ignores case. Since some names only differ by whitespace
+    /// (e.g. `"darkgreen"` and `"dark green"`) an exact match is preferred,
+    /// otherwise whitespace is ignored as well (see [`X11Color::from_name`](crate::presets::X11Color::from_name)).
+    ///
+    /// # Returns
+    /// `None` if there is no color with the given name
+    pub fn from_name(name: &str) -> Option<XkcdColor> {
+        let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+        XkcdColor::all()
+            .find(|color| color.name.eq_ignore_ascii_case(&name))
+            .or_else(|| {
+                let normalized = normalize_name(&name);
+                XkcdColor::all().find(|color| normalize_name(color.name) == normalized)
+            })
+    }
+
+    /// Returns the color of the survey closest to the given color
+    ///
+    /// Uses the same perceptual metric as [`X11Color::nearest`](crate::presets::X11Color::nearest).
+    pub fn nearest<T>(color: &impl RGBColor<T>) -> XkcdColor {
+        nearest_by_key(color, XkcdColor::all(), XkcdColor::to_rgb24)
+            .expect("there is at least one xkcd color")
+    }
+
+    /// Returns the name of this color, e.g. `"dusty rose"`
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the sRGB value of this color
+    pub fn to_rgb24(&self) -> RGB24 {
+        rgb24_from_hex(self.hex)
+    }
+}
+
+impl Display for XkcdColor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// All colors of the xkcd color survey ordered by name
+static XKCD_COLORS: [XkcdColor; 949] = [
+    XkcdColor::new("acid green", 0x8ffe09),
+    XkcdColor::new("adobe", 0xbd6c48),
+    XkcdColor::new("algae", 0x54ac68),
+    XkcdColor::new("algae green", 0x21c36f),
+    XkcdColor::new("almost black", 0x070d0d),
+    XkcdColor::new("amber", 0xfeb308),
+    XkcdColor::new("amethyst", 0x9b5fc0),
+    XkcdColor::new("apple", 0x6ecb3c),
+    XkcdColor::new("apple green", 0x76cd26),
+    XkcdColor::new("apricot", 0xffb16d),
+    XkcdColor::new("aqua", 0x13eac9),
+    XkcdColor::new("aqua blue", 0x02d8e9),
+    XkcdColor::new("aqua green", 0x12e193),
+    XkcdColor::new("aqua marine", 0x2ee8bb),
+    XkcdColor::new("aquamarine", 0x04d8b2),
+    XkcdColor::new("army green", 0x4b5d16),
+    XkcdColor::new("asparagus", 0x77ab56),
+    XkcdColor::new("aubergine", 0x3d0734),
+    XkcdColor::new("auburn", 0x9a3001),
+    XkcdColor::new("avocado", 0x90b134),
+    XkcdColor::new("avocado green", 0x87a922),
+    XkcdColor::new("azul", 0x1d5dec),
+    XkcdColor::new("azure", 0x069af3),
+    XkcdColor::new("baby blue", 0xa2cffe),
+    XkcdColor::new("baby green", 0x8cff9e),
+    XkcdColor::new("baby pink", 0xffb7ce),
+    XkcdColor::new("baby poo", 0xab9004),
+    XkcdColor::new("baby poop", 0x937c00),
+    XkcdColor::new("baby poop green", 0x8f9805),
+    XkcdColor::new("baby puke green", 0xb6c406),
+    XkcdColor::new("baby purple", 0xca9bf7),
+    XkcdColor::new("baby shit brown", 0xad900d),
+    XkcdColor::new("baby shit green", 0x889717),
+    XkcdColor::new("banana", 0xffff7e),
+    XkcdColor::new("banana yellow", 0xfafe4b),
+    XkcdColor::new("barbie pink", 0xfe46a5),
+    XkcdColor::new("barf green", 0x94ac02),
+    XkcdColor::new("barney", 0xac1db8),
+    XkcdColor::new("barney purple", 0xa00498),
+    XkcdColor::new("battleship grey", 0x6b7c85),
+    XkcdColor::new("beige", 0xe6daa6),
+    XkcdColor::new("berry", 0x990f4b),
+    XkcdColor::new("bile", 0xb5c306),
+    XkcdColor::new("black", 0x000000),
+    XkcdColor::new("bland", 0xafa88b),
+    XkcdColor::new("blood", 0x770001),
+    XkcdColor::new("blood orange", 0xfe4b03),
+    XkcdColor::new("blood red", 0x980002),
+    XkcdColor::new("blue", 0x0343df),
+    XkcdColor::new("blue blue", 0x2242c7),
+    XkcdColor::new("blue green", 0x137e6d),
+    XkcdColor::new("blue grey", 0x607c8e),
+    XkcdColor::new("blue purple", 0x5729ce),
+    XkcdColor::new("blue violet", 0x5d06e9),
+    XkcdColor::new("blue with a hint of purple", 0x533cc6),
+    XkcdColor::new("blue/green", 0x0f9b8e),
+    XkcdColor::new("blue/grey", 0x758da3),
+    XkcdColor::new("blue/purple", 0x5a06ef),
+    XkcdColor::new("blueberry", 0x464196),
+    XkcdColor::new("bluegreen", 0x017a79),
+    XkcdColor::new("bluegrey", 0x85a3b2),
+    XkcdColor::new("bluey green", 0x2bb179),
+    XkcdColor::new("bluey grey", 0x89a0b0),
+    XkcdColor::new("bluey purple", 0x6241c7),
+    XkcdColor::new("bluish", 0x2976bb),
+    XkcdColor::new("bluish green", 0x10a674),
+    XkcdColor::new("bluish grey", 0x748b97),
+    XkcdColor::new("bluish purple", 0x703be7),
+    XkcdColor::new("blurple", 0x5539cc),
+    XkcdColor::new("blush", 0xf29e8e),
+    XkcdColor::new("blush pink", 0xfe828c),
+    XkcdColor::new("booger", 0x9bb53c),
+    XkcdColor::new("booger green", 0x96b403),
+    XkcdColor::new("bordeaux", 0x7b002c),
+    XkcdColor::new("boring green", 0x63b365),
+    XkcdColor::new("bottle green", 0x044a05),
+    XkcdColor::new("brick", 0xa03623),
+    XkcdColor::new("brick orange", 0xc14a09),
+    XkcdColor::new("brick red", 0x8f1402),
+    XkcdColor::new("bright aqua", 0x0bf9ea),
+    XkcdColor::new("bright blue", 0x0165fc),
+    XkcdColor::new("bright cyan", 0x41fdfe),
+    XkcdColor::new("bright green", 0x01ff07),
+    XkcdColor::new("bright lavender", 0xc760ff),
+    XkcdColor::new("bright light blue", 0x26f7fd),
+    XkcdColor::new("bright light green", 0x2dfe54),
+    XkcdColor::new("bright lilac", 0xc95efb),
+    XkcdColor::new("bright lime", 0x87fd05),
+    XkcdColor::new("bright lime green", 0x65fe08),
+    XkcdColor::new("bright magenta", 0xff08e8),
+    XkcdColor::new("bright olive", 0x9cbb04),
+    XkcdColor::new("bright orange", 0xff5b00),
+    XkcdColor::new("bright pink", 0xfe01b1),
+    XkcdColor::new("bright purple", 0xbe03fd),
+    XkcdColor::new("bright red", 0xff000d),
+    XkcdColor::new("bright sea green", 0x05ffa6),
+    XkcdColor::new("bright sky blue", 0x02ccfe),
+    XkcdColor::new("bright teal", 0x01f9c6),
+    XkcdColor::new("bright turquoise", 0x0ffef9),
+    XkcdColor::new("bright violet", 0xad0afd),
+    XkcdColor::new("bright yellow", 0xfffd01),
+    XkcdColor::new("bright yellow green", 0x9dff00),
+    XkcdColor::new("british racing green", 0x05480d),
+    XkcdColor::new("bronze", 0xa87900),
+    XkcdColor::new("brown", 0x653700),
+    XkcdColor::new("brown green", 0x706c11),
+    XkcdColor::new("brown grey", 0x8d8468),
+    XkcdColor::new("brown orange", 0xb96902),
+    XkcdColor::new("brown red", 0x922b05),
+    XkcdColor::new("brown yellow", 0xb29705),
+    XkcdColor::new("brownish", 0x9c6d57),
+    XkcdColor::new("brownish green", 0x6a6e09),
+    XkcdColor::new("brownish grey", 0x86775f),
+    XkcdColor::new("brownish orange", 0xcb7723),
+    XkcdColor::new("brownish pink", 0xc27e79),
+    XkcdColor::new("brownish purple", 0x76424e),
+    XkcdColor::new("brownish red", 0x9e3623),
+    XkcdColor::new("brownish yellow", 0xc9b003),
+    XkcdColor::new("browny green", 0x6f6c0a),
+    XkcdColor::new("browny orange", 0xca6b02),
+    XkcdColor::new("bruise", 0x7e4071),
+    XkcdColor::new("bubble gum pink", 0xff69af),
+    XkcdColor::new("bubblegum", 0xff6cb5),
+    XkcdColor::new("bubblegum pink", 0xfe83cc),
+    XkcdColor::new("buff", 0xfef69e),
+    XkcdColor::new("burgundy", 0x610023),
+    XkcdColor::new("burnt orange", 0xc04e01),
+    XkcdColor::new("burnt red", 0x9f2305),
+    XkcdColor::new("burnt siena", 0xb75203),
+    XkcdColor::new("burnt sienna", 0xb04e0f),
+    XkcdColor::new("burnt umber", 0xa0450e),
+    XkcdColor::new("burnt yellow", 0xd5ab09),
+    XkcdColor::new("burple", 0x6832e3),
+    XkcdColor::new("butter", 0xffff81),
+    XkcdColor::new("butter yellow", 0xfffd74),
+    XkcdColor::new("butterscotch", 0xfdb147),
+    XkcdColor::new("cadet blue", 0x4e7496),
+    XkcdColor::new("camel", 0xc69f59),
+    XkcdColor::new("camo", 0x7f8f4e),
+    XkcdColor::new("camo green", 0x526525),
+    XkcdColor::new("camouflage green", 0x4b6113),
+    XkcdColor::new("canary", 0xfdff63),
+    XkcdColor::new("canary yellow", 0xfffe40),
+    XkcdColor::new("candy pink", 0xff63e9),
+    XkcdColor::new("caramel", 0xaf6f09),
+    XkcdColor::new("carmine", 0x9d0216),
+    XkcdColor::new("carnation", 0xfd798f),
+    XkcdColor::new("carnation pink", 0xff7fa7),
+    XkcdColor::new("carolina blue", 0x8ab8fe),
+    XkcdColor::new("celadon", 0xbefdb7),
+    XkcdColor::new("celery", 0xc1fd95),
+    XkcdColor::new("cement", 0xa5a391),
+    XkcdColor::new("cerise", 0xde0c62),
+    XkcdColor::new("cerulean", 0x0485d1),
+    XkcdColor::new("cerulean blue", 0x056eee),
+    XkcdColor::new("charcoal", 0x343837),
+    XkcdColor::new("charcoal grey", 0x3c4142),
+    XkcdColor::new("chartreuse", 0xc1f80a),
+    XkcdColor::new("cherry", 0xcf0234),
+    XkcdColor::new("cherry red", 0xf7022a),
+    XkcdColor::new("chestnut", 0x742802),
+    XkcdColor::new("chocolate", 0x3d1c02),
+    XkcdColor::new("chocolate brown", 0x411900),
+    XkcdColor::new("cinnamon", 0xac4f06),
+    XkcdColor::new("claret", 0x680018),
+    XkcdColor::new("clay", 0xb66a50),
+    XkcdColor::new("clay brown", 0xb2713d),
+    XkcdColor::new("clear blue", 0x247afd),
+    XkcdColor::new("cloudy blue", 0xacc2d9),
+    XkcdColor::new("cobalt", 0x1e488f),
+    XkcdColor::new("cobalt blue", 0x030aa7),
+    XkcdColor::new("cocoa", 0x875f42),
+    XkcdColor::new("coffee", 0xa6814c),
+    XkcdColor::new("cool blue", 0x4984b8),
+    XkcdColor::new("cool green", 0x33b864),
+    XkcdColor::new("cool grey", 0x95a3a6),
+    XkcdColor::new("copper", 0xb66325),
+    XkcdColor::new("coral", 0xfc5a50),
+    XkcdColor::new("coral pink", 0xff6163),
+    XkcdColor::new("cornflower", 0x6a79f7),
+    XkcdColor::new("cornflower blue", 0x5170d7),
+    XkcdColor::new("cranberry", 0x9e003a),
+    XkcdColor::new("cream", 0xffffc2),
+    XkcdColor::new("creme", 0xffffb6),
+    XkcdColor::new("crimson", 0x8c000f),
+    XkcdColor::new("custard", 0xfffd78),
+    XkcdColor::new("cyan", 0x00ffff),
+    XkcdColor::new("dandelion", 0xfedf08),
+    XkcdColor::new("dark", 0x1b2431),
+    XkcdColor::new("dark aqua", 0x05696b),
+    XkcdColor::new("dark aquamarine", 0x017371),
+    XkcdColor::new("dark beige", 0xac9362),
+    XkcdColor::new("dark blue", 0x00035b),
+    XkcdColor::new("dark blue green", 0x005249),
+    XkcdColor::new("dark blue grey", 0x1f3b4d),
+    XkcdColor::new("dark brown", 0x341c02),
+    XkcdColor::new("dark coral", 0xcf524e),
+    XkcdColor::new("dark cream", 0xfff39a),
+    XkcdColor::new("dark cyan", 0x0a888a),
+    XkcdColor::new("dark forest green", 0x002d04),
+    XkcdColor::new("dark fuchsia", 0x9d0759),
+    XkcdColor::new("dark gold", 0xb59410),
+    XkcdColor::new("dark grass green", 0x388004),
+    XkcdColor::new("dark green", 0x033500),
+    XkcdColor::new("dark green blue", 0x1f6357),
+    XkcdColor::new("dark grey", 0x363737),
+    XkcdColor::new("dark grey blue", 0x29465b),
+    XkcdColor::new("dark hot pink", 0xd90166),
+    XkcdColor::new("dark indigo", 0x1f0954),
+    XkcdColor::new("dark khaki", 0x9b8f55),
+    XkcdColor::new("dark lavender", 0x856798),
+    XkcdColor::new("dark lilac", 0x9c6da5),
+    XkcdColor::new("dark lime", 0x84b701),
+    XkcdColor::new("dark lime green", 0x7ebd01),
+    XkcdColor::new("dark magenta", 0x960056),
+    XkcdColor::new("dark maroon", 0x3c0008),
+    XkcdColor::new("dark mauve", 0x874c62),
+    XkcdColor::new("dark mint", 0x48c072),
+    XkcdColor::new("dark mint green", 0x20c073),
+    XkcdColor::new("dark mustard", 0xa88905),
+    XkcdColor::new("dark navy", 0x000435),
+    XkcdColor::new("dark navy blue", 0x00022e),
+    XkcdColor::new("dark olive", 0x373e02),
+    XkcdColor::new("dark olive green", 0x3c4d03),
+    XkcdColor::new("dark orange", 0xc65102),
+    XkcdColor::new("dark pastel green", 0x56ae57),
+    XkcdColor::new("dark peach", 0xde7e5d),
+    XkcdColor::new("dark periwinkle", 0x665fd1),
+    XkcdColor::new("dark pink", 0xcb416b),
+    XkcdColor::new("dark plum", 0x3f012c),
+    XkcdColor::new("dark purple", 0x35063e),
+    XkcdColor::new("dark red", 0x840000),
+    XkcdColor::new("dark rose", 0xb5485d),
+    XkcdColor::new("dark royal blue", 0x02066f),
+    XkcdColor::new("dark sage", 0x598556),
+    XkcdColor::new("dark salmon", 0xc85a53),
+    XkcdColor::new("dark sand", 0xa88f59),
+    XkcdColor::new("dark sea green", 0x11875d),
+    XkcdColor::new("dark seafoam", 0x1fb57a),
+    XkcdColor::new("dark seafoam green", 0x3eaf76),
+    XkcdColor::new("dark sky blue", 0x448ee4),
+    XkcdColor::new("dark slate blue", 0x214761),
+    XkcdColor::new("dark tan", 0xaf884a),
+    XkcdColor::new("dark taupe", 0x7f684e),
+    XkcdColor::new("dark teal", 0x014d4e),
+    XkcdColor::new("dark turquoise", 0x045c5a),
+    XkcdColor::new("dark violet", 0x34013f),
+    XkcdColor::new("dark yellow", 0xd5b60a),
+    XkcdColor::new("dark yellow green", 0x728f02),
+    XkcdColor::new("darkblue", 0x030764),
+    XkcdColor::new("darkgreen", 0x054907),
+    XkcdColor::new("darkish blue", 0x014182),
+    XkcdColor::new("darkish green", 0x287c37),
+    XkcdColor::new("darkish pink", 0xda467d),
+    XkcdColor::new("darkish purple", 0x751973),
+    XkcdColor::new("darkish red", 0xa90308),
+    XkcdColor::new("deep aqua", 0x08787f),
+    XkcdColor::new("deep blue", 0x040273),
+    XkcdColor::new("deep brown", 0x410200),
+    XkcdColor::new("deep green", 0x02590f),
+    XkcdColor::new("deep lavender", 0x8d5eb7),
+    XkcdColor::new("deep lilac", 0x966ebd),
+    XkcdColor::new("deep magenta", 0xa0025c),
+    XkcdColor::new("deep orange", 0xdc4d01),
+    XkcdColor::new("deep pink", 0xcb0162),
+    XkcdColor::new("deep purple", 0x36013f),
+    XkcdColor::new("deep red", 0x9a0200),
+    XkcdColor::new("deep rose", 0xc74767),
+    XkcdColor::new("deep sea blue", 0x015482),
+    XkcdColor::new("deep sky blue", 0x0d75f8),
+    XkcdColor::new("deep teal", 0x00555a),
+    XkcdColor::new("deep turquoise", 0x017374),
+    XkcdColor::new("deep violet", 0x490648),
+    XkcdColor::new("denim", 0x3b638c),
+    XkcdColor::new("denim blue", 0x3b5b92),
+    XkcdColor::new("desert", 0xccad60),
+    XkcdColor::new("diarrhea", 0x9f8303),
+    XkcdColor::new("dirt", 0x8a6e45),
+    XkcdColor::new("dirt brown", 0x836539),
+    XkcdColor::new("dirty blue", 0x3f829d),
+    XkcdColor::new("dirty green", 0x667e2c),
+    XkcdColor::new("dirty orange", 0xc87606),
+    XkcdColor::new("dirty pink", 0xca7b80),
+    XkcdColor::new("dirty purple", 0x734a65),
+    XkcdColor::new("dirty yellow", 0xcdc50a),
+    XkcdColor::new("dodger blue", 0x3e82fc),
+    XkcdColor::new("drab", 0x828344),
+    XkcdColor::new("drab green", 0x749551),
+    XkcdColor::new("dried blood", 0x4b0101),
+    XkcdColor::new("duck egg blue", 0xc3fbf4),
+    XkcdColor::new("dull blue", 0x49759c),
+    XkcdColor::new("dull brown", 0x876e4b),
+    XkcdColor::new("dull green", 0x74a662),
+    XkcdColor::new("dull orange", 0xd8863b),
+    XkcdColor::new("dull pink", 0xd5869d),
+    XkcdColor::new("dull purple", 0x84597e),
+    XkcdColor::new("dull red", 0xbb3f3f),
+    XkcdColor::new("dull teal", 0x5f9e8f),
+    XkcdColor::new("dull yellow", 0xeedc5b),
+    XkcdColor::new("dusk", 0x4e5481),
+    XkcdColor::new("dusk blue", 0x26538d),
+    XkcdColor::new("dusky blue", 0x475f94),
+    XkcdColor::new("dusky pink", 0xcc7a8b),
+    XkcdColor::new("dusky purple", 0x895b7b),
+    XkcdColor::new("dusky rose", 0xba6873),
+    XkcdColor::new("dust", 0xb2996e),
+    XkcdColor::new("dusty blue", 0x5a86ad),
+    XkcdColor::new("dusty green", 0x76a973),
+    XkcdColor::new("dusty lavender", 0xac86a8),
+    XkcdColor::new("dusty orange", 0xf0833a),
+    XkcdColor::new("dusty pink", 0xd58a94),
+    XkcdColor::new("dusty purple", 0x825f87),
+    XkcdColor::new("dusty red", 0xb9484e),
+    XkcdColor::new("dusty rose", 0xc0737a),
+    XkcdColor::new("dusty teal", 0x4c9085),
+    XkcdColor::new("earth", 0xa2653e),
+    XkcdColor::new("easter green", 0x8cfd7e),
+    XkcdColor::new("easter purple", 0xc071fe),
+    XkcdColor::new("ecru", 0xfeffca),
+    XkcdColor::new("egg shell", 0xfffcc4),
+    XkcdColor::new("eggplant", 0x380835),
+    XkcdColor::new("eggplant purple", 0x430541),
+    XkcdColor::new("eggshell", 0xffffd4),
+    XkcdColor::new("eggshell blue", 0xc4fff7),
+    XkcdColor::new("electric blue", 0x0652ff),
+    XkcdColor::new("electric green", 0x21fc0d),
+    XkcdColor::new("electric lime", 0xa8ff04),
+    XkcdColor::new("electric pink", 0xff0490),
+    XkcdColor::new("electric purple", 0xaa23ff),
+    XkcdColor::new("emerald", 0x01a049),
+    XkcdColor::new("emerald green", 0x028f1e),
+    XkcdColor::new("evergreen", 0x05472a),
+    XkcdColor::new("faded blue", 0x658cbb),
+    XkcdColor::new("faded green", 0x7bb274),
+    XkcdColor::new("faded orange", 0xf0944d),
+    XkcdColor::new("faded pink", 0xde9dac),
+    XkcdColor::new("faded purple", 0x916e99),
+    XkcdColor::new("faded red", 0xd3494e),
+    XkcdColor::new("faded yellow", 0xfeff7f),
+    XkcdColor::new("fawn", 0xcfaf7b),
+    XkcdColor::new("fern", 0x63a950),
+    XkcdColor::new("fern green", 0x548d44),
+    XkcdColor::new("fire engine red", 0xfe0002),
+    XkcdColor::new("flat blue", 0x3c73a8),
+    XkcdColor::new("flat green", 0x699d4c),
+    XkcdColor::new("fluorescent green", 0x08ff08),
+    XkcdColor::new("fluro green", 0x0aff02),
+    XkcdColor::new("foam green", 0x90fda9),
+    XkcdColor::new("forest", 0x0b5509),
+    XkcdColor::new("forest green", 0x06470c),
+    XkcdColor::new("forrest green", 0x154406),
+    XkcdColor::new("french blue", 0x436bad),
+    XkcdColor::new("fresh green", 0x69d84f),
+    XkcdColor::new("frog green", 0x58bc08),
+    XkcdColor::new("fuchsia", 0xed0dd9),
+    XkcdColor::new("gold", 0xdbb40c),
+    XkcdColor::new("golden", 0xf5bf03),
+    XkcdColor::new("golden brown", 0xb27a01),
+    XkcdColor::new("golden rod", 0xf9bc08),
+    XkcdColor::new("golden yellow", 0xfec615),
+    XkcdColor::new("goldenrod", 0xfac205),
+    XkcdColor::new("grape", 0x6c3461),
+    XkcdColor::new("grape purple", 0x5d1451),
+    XkcdColor::new("grapefruit", 0xfd5956),
+    XkcdColor::new("grass", 0x5cac2d),
+    XkcdColor::new("grass green", 0x3f9b0b),
+    XkcdColor::new("grassy green", 0x419c03),
+    XkcdColor::new("green", 0x15b01a),
+    XkcdColor::new("green apple", 0x5edc1f),
+    XkcdColor::new("green blue", 0x06b48b),
+    XkcdColor::new("green brown", 0x544e03),
+    XkcdColor::new("green grey", 0x77926f),
+    XkcdColor::new("green teal", 0x0cb577),
+    XkcdColor::new("green yellow", 0xc9ff27),
+    XkcdColor::new("green/blue", 0x01c08d),
+    XkcdColor::new("green/yellow", 0xb5ce08),
+    XkcdColor::new("greenblue", 0x23c48b),
+    XkcdColor::new("greenish", 0x40a368),
+    XkcdColor::new("greenish beige", 0xc9d179),
+    XkcdColor::new("greenish blue", 0x0b8b87),
+    XkcdColor::new("greenish brown", 0x696112),
+    XkcdColor::new("greenish cyan", 0x2afeb7),
+    XkcdColor::new("greenish grey", 0x96ae8d),
+    XkcdColor::new("greenish tan", 0xbccb7a),
+    XkcdColor::new("greenish teal", 0x32bf84),
+    XkcdColor::new("greenish turquoise", 0x00fbb0),
+    XkcdColor::new("greenish yellow", 0xcdfd02),
+    XkcdColor::new("greeny blue", 0x42b395),
+    XkcdColor::new("greeny brown", 0x696006),
+    XkcdColor::new("greeny grey", 0x7ea07a),
+    XkcdColor::new("greeny yellow", 0xc6f808),
+    XkcdColor::new("grey", 0x929591),
+    XkcdColor::new("grey blue", 0x6b8ba4),
+    XkcdColor::new("grey brown", 0x7f7053),
+    XkcdColor::new("grey green", 0x789b73),
+    XkcdColor::new("grey pink", 0xc3909b),
+    XkcdColor::new("grey purple", 0x826d8c),
+    XkcdColor::new("grey teal", 0x5e9b8a),
+    XkcdColor::new("grey/blue", 0x647d8e),
+    XkcdColor::new("grey/green", 0x86a17d),
+    XkcdColor::new("greyblue", 0x77a1b5),
+    XkcdColor::new("greyish", 0xa8a495),
+    XkcdColor::new("greyish blue", 0x5e819d),
+    XkcdColor::new("greyish brown", 0x7a6a4f),
+    XkcdColor::new("greyish green", 0x82a67d),
+    XkcdColor::new("greyish pink", 0xc88d94),
+    XkcdColor::new("greyish purple", 0x887191),
+    XkcdColor::new("greyish teal", 0x719f91),
+    XkcdColor::new("gross green", 0xa0bf16),
+    XkcdColor::new("gunmetal", 0x536267),
+    XkcdColor::new("hazel", 0x8e7618),
+    XkcdColor::new("heather", 0xa484ac),
+    XkcdColor::new("heliotrope", 0xd94ff5),
+    XkcdColor::new("highlighter green", 0x1bfc06),
+    XkcdColor::new("hospital green", 0x9be5aa),
+    XkcdColor::new("hot green", 0x25ff29),
+    XkcdColor::new("hot magenta", 0xf504c9),
+    XkcdColor::new("hot pink", 0xff028d),
+    XkcdColor::new("hot purple", 0xcb00f5),
+    XkcdColor::new("hunter green", 0x0b4008),
+    XkcdColor::new("ice", 0xd6fffa),
+    XkcdColor::new("ice blue", 0xd7fffe),
+    XkcdColor::new("icky green", 0x8fae22),
+    XkcdColor::new("indian red", 0x850e04),
+    XkcdColor::new("indigo", 0x380282),
+    XkcdColor::new("indigo blue", 0x3a18b1),
+    XkcdColor::new("iris", 0x6258c4),
+    XkcdColor::new("irish green", 0x019529),
+    XkcdColor::new("ivory", 0xffffcb),
+    XkcdColor::new("jade", 0x1fa774),
+    XkcdColor::new("jade green", 0x2baf6a),
+    XkcdColor::new("jungle green", 0x048243),
+    XkcdColor::new("kelley green", 0x009337),
+    XkcdColor::new("kelly green", 0x02ab2e),
+    XkcdColor::new("kermit green", 0x5cb200),
+    XkcdColor::new("key lime", 0xaeff6e),
+    XkcdColor::new("khaki", 0xaaa662),
+    XkcdColor::new("khaki green", 0x728639),
+    XkcdColor::new("kiwi", 0x9cef43),
+    XkcdColor::new("kiwi green", 0x8ee53f),
+    XkcdColor::new("lavender", 0xc79fef),
+    XkcdColor::new("lavender blue", 0x8b88f8),
+    XkcdColor::new("lavender pink", 0xdd85d7),
+    XkcdColor::new("lawn green", 0x4da409),
+    XkcdColor::new("leaf", 0x71aa34),
+    XkcdColor::new("leaf green", 0x5ca904),
+    XkcdColor::new("leafy green", 0x51b73b),
+    XkcdColor::new("leather", 0xac7434),
+    XkcdColor::new("lemon", 0xfdff52),
+    XkcdColor::new("lemon green", 0xadf802),
+    XkcdColor::new("lemon lime", 0xbffe28),
+    XkcdColor::new("lemon yellow", 0xfdff38),
+    XkcdColor::new("lichen", 0x8fb67b),
+    XkcdColor::new("light aqua", 0x8cffdb),
+    XkcdColor::new("light aquamarine", 0x7bfdc7),
+    XkcdColor::new("light beige", 0xfffeb6),
+    XkcdColor::new("light blue", 0x95d0fc),
+    XkcdColor::new("light blue green", 0x7efbb3),
+    XkcdColor::new("light blue grey", 0xb7c9e2),
+    XkcdColor::new("light bluish green", 0x76fda8),
+    XkcdColor::new("light bright green", 0x53fe5c),
+    XkcdColor::new("light brown", 0xad8150),
+    XkcdColor::new("light burgundy", 0xa8415b),
+    XkcdColor::new("light cyan", 0xacfffc),
+    XkcdColor::new("light eggplant", 0x894585),
+    XkcdColor::new("light forest green", 0x4f9153),
+    XkcdColor::new("light gold", 0xfddc5c),
+    XkcdColor::new("light grass green", 0x9af764),
+    XkcdColor::new("light green", 0x96f97b),
+    XkcdColor::new("light green blue", 0x56fca2),
+    XkcdColor::new("light greenish blue", 0x63f7b4),
+    XkcdColor::new("light grey", 0xd8dcd6),
+    XkcdColor::new("light grey blue", 0x9dbcd4),
+    XkcdColor::new("light grey green", 0xb7e1a1),
+    XkcdColor::new("light indigo", 0x6d5acf),
+    XkcdColor::new("light khaki", 0xe6f2a2),
+    XkcdColor::new("light lavendar", 0xefc0fe),
+    XkcdColor::new("light lavender", 0xdfc5fe),
+    XkcdColor::new("light light blue", 0xcafffb),
+    XkcdColor::new("light light green", 0xc8ffb0),
+    XkcdColor::new("light lilac", 0xedc8ff),
+    XkcdColor::new("light lime", 0xaefd6c),
+    XkcdColor::new("light lime green", 0xb9ff66),
+    XkcdColor::new("light magenta", 0xfa5ff7),
+    XkcdColor::new("light maroon", 0xa24857),
+    XkcdColor::new("light mauve", 0xc292a1),
+    XkcdColor::new("light mint", 0xb6ffbb),
+    XkcdColor::new("light mint green", 0xa6fbb2),
+    XkcdColor::new("light moss green", 0xa6c875),
+    XkcdColor::new("light mustard", 0xf7d560),
+    XkcdColor::new("light navy", 0x155084),
+    XkcdColor::new("light navy blue", 0x2e5a88),
+    XkcdColor::new("light neon green", 0x4efd54),
+    XkcdColor::new("light olive", 0xacbf69),
+    XkcdColor::new("light olive green", 0xa4be5c),
+    XkcdColor::new("light orange", 0xfdaa48),
+    XkcdColor::new("light pastel green", 0xb2fba5),
+    XkcdColor::new("light pea green", 0xc4fe82),
+    XkcdColor::new("light peach", 0xffd8b1),
+    XkcdColor::new("light periwinkle", 0xc1c6fc),
+    XkcdColor::new("light pink", 0xffd1df),
+    XkcdColor::new("light plum", 0x9d5783),
+    XkcdColor::new("light purple", 0xbf77f6),
+    XkcdColor::new("light red", 0xff474c),
+    XkcdColor::new("light rose", 0xffc5cb),
+    XkcdColor::new("light royal blue", 0x3a2efe),
+    XkcdColor::new("light sage", 0xbcecac),
+    XkcdColor::new("light salmon", 0xfea993),
+    XkcdColor::new("light sea green", 0x98f6b0),
+    XkcdColor::new("light seafoam", 0xa0febf),
+    XkcdColor::new("light seafoam green", 0xa7ffb5),
+    XkcdColor::new("light sky blue", 0xc6fcff),
+    XkcdColor::new("light tan", 0xfbeeac),
+    XkcdColor::new("light teal", 0x90e4c1),
+    XkcdColor::new("light turquoise", 0x7ef4cc),
+    XkcdColor::new("light urple", 0xb36ff6),
+    XkcdColor::new("light violet", 0xd6b4fc),
+    XkcdColor::new("light yellow", 0xfffe7a),
+    XkcdColor::new("light yellow green", 0xccfd7f),
+    XkcdColor::new("light yellowish green", 0xc2ff89),
+    XkcdColor::new("lightblue", 0x7bc8f6),
+    XkcdColor::new("lighter green", 0x75fd63),
+    XkcdColor::new("lighter purple", 0xa55af4),
+    XkcdColor::new("lightgreen", 0x76ff7b),
+    XkcdColor::new("lightish blue", 0x3d7afd),
+    XkcdColor::new("lightish green", 0x61e160),
+    XkcdColor::new("lightish purple", 0xa552e6),
+    XkcdColor::new("lightish red", 0xfe2f4a),
+    XkcdColor::new("lilac", 0xcea2fd),
+    XkcdColor::new("liliac", 0xc48efd),
+    XkcdColor::new("lime", 0xaaff32),
+    XkcdColor::new("lime green", 0x89fe05),
+    XkcdColor::new("lime yellow", 0xd0fe1d),
+    XkcdColor::new("lipstick", 0xd5174e),
+    XkcdColor::new("lipstick red", 0xc0022f),
+    XkcdColor::new("macaroni and cheese", 0xefb435),
+    XkcdColor::new("magenta", 0xc20078),
+    XkcdColor::new("mahogany", 0x4a0100),
+    XkcdColor::new("maize", 0xf4d054),
+    XkcdColor::new("mango", 0xffa62b),
+    XkcdColor::new("manilla", 0xfffa86),
+    XkcdColor::new("marigold", 0xfcc006),
+    XkcdColor::new("marine", 0x042e60),
+    XkcdColor::new("marine blue", 0x01386a),
+    XkcdColor::new("maroon", 0x650021),
+    XkcdColor::new("mauve", 0xae7181),
+    XkcdColor::new("medium blue", 0x2c6fbb),
+    XkcdColor::new("medium brown", 0x7f5112),
+    XkcdColor::new("medium green", 0x39ad48),
+    XkcdColor::new("medium grey", 0x7d7f7c),
+    XkcdColor::new("medium pink", 0xf36196),
+    XkcdColor::new("medium purple", 0x9e43a2),
+    XkcdColor::new("melon", 0xff7855),
+    XkcdColor::new("merlot", 0x730039),
+    XkcdColor::new("metallic blue", 0x4f738e),
+    XkcdColor::new("mid blue", 0x276ab3),
+    XkcdColor::new("mid green", 0x50a747),
+    XkcdColor::new("midnight", 0x03012d),
+    XkcdColor::new("midnight blue", 0x020035),
+    XkcdColor::new("midnight purple", 0x280137),
+    XkcdColor::new("military green", 0x667c3e),
+    XkcdColor::new("milk chocolate", 0x7f4e1e),
+    XkcdColor::new("mint", 0x9ffeb0),
+    XkcdColor::new("mint green", 0x8fff9f),
+    XkcdColor::new("minty green", 0x0bf77d),
+    XkcdColor::new("mocha", 0x9d7651),
+    XkcdColor::new("moss", 0x769958),
+    XkcdColor::new("moss green", 0x658b38),
+    XkcdColor::new("mossy green", 0x638b27),
+    XkcdColor::new("mud", 0x735c12),
+    XkcdColor::new("mud brown", 0x60460f),
+    XkcdColor::new("mud green", 0x606602),
+    XkcdColor::new("muddy brown", 0x886806),
+    XkcdColor::new("muddy green", 0x657432),
+    XkcdColor::new("muddy yellow", 0xbfac05),
+    XkcdColor::new("mulberry", 0x920a4e),
+    XkcdColor::new("murky green", 0x6c7a0e),
+    XkcdColor::new("mushroom", 0xba9e88),
+    XkcdColor::new("mustard", 0xceb301),
+    XkcdColor::new("mustard brown", 0xac7e04),
+    XkcdColor::new("mustard green", 0xa8b504),
+    XkcdColor::new("mustard yellow", 0xd2bd0a),
+    XkcdColor::new("muted blue", 0x3b719f),
+    XkcdColor::new("muted green", 0x5fa052),
+    XkcdColor::new("muted pink", 0xd1768f),
+    XkcdColor::new("muted purple", 0x805b87),
+    XkcdColor::new("nasty green", 0x70b23f),
+    XkcdColor::new("navy", 0x01153e),
+    XkcdColor::new("navy blue", 0x001146),
+    XkcdColor::new("navy green", 0x35530a),
+    XkcdColor::new("neon blue", 0x04d9ff),
+    XkcdColor::new("neon green", 0x0cff0c),
+    XkcdColor::new("neon pink", 0xfe019a),
+    XkcdColor::new("neon purple", 0xbc13fe),
+    XkcdColor::new("neon red", 0xff073a),
+    XkcdColor::new("neon yellow", 0xcfff04),
+    XkcdColor::new("nice blue", 0x107ab0),
+    XkcdColor::new("night blue", 0x040348),
+    XkcdColor::new("ocean", 0x017b92),
+    XkcdColor::new("ocean blue", 0x03719c),
+    XkcdColor::new("ocean green", 0x3d9973),
+    XkcdColor::new("ocher", 0xbf9b0c),
+    XkcdColor::new("ochre", 0xbf9005),
+    XkcdColor::new("ocre", 0xc69c04),
+    XkcdColor::new("off blue", 0x5684ae),
+    XkcdColor::new("off green", 0x6ba353),
+    XkcdColor::new("off white", 0xffffe4),
+    XkcdColor::new("off yellow", 0xf1f33f),
+    XkcdColor::new("old pink", 0xc77986),
+    XkcdColor::new("old rose", 0xc87f89),
+    XkcdColor::new("olive", 0x6e750e),
+    XkcdColor::new("olive brown", 0x645403),
+    XkcdColor::new("olive drab", 0x6f7632),
+    XkcdColor::new("olive green", 0x677a04),
+    XkcdColor::new("olive yellow", 0xc2b709),
+    XkcdColor::new("orange", 0xf97306),
+    XkcdColor::new("orange brown", 0xbe6400),
+    XkcdColor::new("orange pink", 0xff6f52),
+    XkcdColor::new("orange red", 0xfd411e),
+    XkcdColor::new("orange yellow", 0xffad01),
+    XkcdColor::new("orangeish", 0xfd8d49),
+    XkcdColor::new("orangered", 0xfe420f),
+    XkcdColor::new("orangey brown", 0xb16002),
+    XkcdColor::new("orangey red", 0xfa4224),
+    XkcdColor::new("orangey yellow", 0xfdb915),
+    XkcdColor::new("orangish", 0xfc824a),
+    XkcdColor::new("orangish brown", 0xb25f03),
+    XkcdColor::new("orangish red", 0xf43605),
+    XkcdColor::new("orchid", 0xc875c4),
+    XkcdColor::new("pale", 0xfff9d0),
+    XkcdColor::new("pale aqua", 0xb8ffeb),
+    XkcdColor::new("pale blue", 0xd0fefe),
+    XkcdColor::new("pale brown", 0xb1916e),
+    XkcdColor::new("pale cyan", 0xb7fffa),
+    XkcdColor::new("pale gold", 0xfdde6c),
+    XkcdColor::new("pale green", 0xc7fdb5),
+    XkcdColor::new("pale grey", 0xfdfdfe),
+    XkcdColor::new("pale lavender", 0xeecffe),
+    XkcdColor::new("pale light green", 0xb1fc99),
+    XkcdColor::new("pale lilac", 0xe4cbff),
+    XkcdColor::new("pale lime", 0xbefd73),
+    XkcdColor::new("pale lime green", 0xb1ff65),
+    XkcdColor::new("pale magenta", 0xd767ad),
+    XkcdColor::new("pale mauve", 0xfed0fc),
+    XkcdColor::new("pale olive", 0xb9cc81),
+    XkcdColor::new("pale olive green", 0xb1d27b),
+    XkcdColor::new("pale orange", 0xffa756),
+    XkcdColor::new("pale peach", 0xffe5ad),
+    XkcdColor::new("pale pink", 0xffcfdc),
+    XkcdColor::new("pale purple", 0xb790d4),
+    XkcdColor::new("pale red", 0xd9544d),
+    XkcdColor::new("pale rose", 0xfdc1c5),
+    XkcdColor::new("pale salmon", 0xffb19a),
+    XkcdColor::new("pale sky blue", 0xbdf6fe),
+    XkcdColor::new("pale teal", 0x82cbb2),
+    XkcdColor::new("pale turquoise", 0xa5fbd5),
+    XkcdColor::new("pale violet", 0xceaefa),
+    XkcdColor::new("pale yellow", 0xffff84),
+    XkcdColor::new("parchment", 0xfefcaf),
+    XkcdColor::new("pastel blue", 0xa2bffe),
+    XkcdColor::new("pastel green", 0xb0ff9d),
+    XkcdColor::new("pastel orange", 0xff964f),
+    XkcdColor::new("pastel pink", 0xffbacd),
+    XkcdColor::new("pastel purple", 0xcaa0ff),
+    XkcdColor::new("pastel red", 0xdb5856),
+    XkcdColor::new("pastel yellow", 0xfffe71),
+    XkcdColor::new("pea", 0xa4bf20),
+    XkcdColor::new("pea green", 0x8eab12),
+    XkcdColor::new("pea soup", 0x929901),
+    XkcdColor::new("pea soup green", 0x94a617),
+    XkcdColor::new("peach", 0xffb07c),
+    XkcdColor::new("peachy pink", 0xff9a8a),
+    XkcdColor::new("peacock blue", 0x016795),
+    XkcdColor::new("pear", 0xcbf85f),
+    XkcdColor::new("periwinkle", 0x8e82fe),
+    XkcdColor::new("periwinkle blue", 0x8f99fb),
+    XkcdColor::new("perrywinkle", 0x8f8ce7),
+    XkcdColor::new("petrol", 0x005f6a),
+    XkcdColor::new("pig pink", 0xe78ea5),
+    XkcdColor::new("pine", 0x2b5d34),
+    XkcdColor::new("pine green", 0x0a481e),
+    XkcdColor::new("pink", 0xff81c0),
+    XkcdColor::new("pink purple", 0xdb4bda),
+    XkcdColor::new("pink red", 0xf5054f),
+    XkcdColor::new("pink/purple", 0xef1de7),
+    XkcdColor::new("pinkish", 0xd46a7e),
+    XkcdColor::new("pinkish brown", 0xb17261),
+    XkcdColor::new("pinkish grey", 0xc8aca9),
+    XkcdColor::new("pinkish orange", 0xff724c),
+    XkcdColor::new("pinkish purple", 0xd648d7),
+    XkcdColor::new("pinkish red", 0xf10c45),
+    XkcdColor::new("pinkish tan", 0xd99b82),
+    XkcdColor::new("pinky", 0xfc86aa),
+    XkcdColor::new("pinky purple", 0xc94cbe),
+    XkcdColor::new("pinky red", 0xfc2647),
+    XkcdColor::new("piss yellow", 0xddd618),
+    XkcdColor::new("pistachio", 0xc0fa8b),
+    XkcdColor::new("plum", 0x580f41),
+    XkcdColor::new("plum purple", 0x4e0550),
+    XkcdColor::new("poison green", 0x40fd14),
+    XkcdColor::new("poo", 0x8f7303),
+    XkcdColor::new("poo brown", 0x885f01),
+    XkcdColor::new("poop", 0x7f5e00),
+    XkcdColor::new("poop brown", 0x7a5901),
+    XkcdColor::new("poop green", 0x6f7c00),
+    XkcdColor::new("powder blue", 0xb1d1fc),
+    XkcdColor::new("powder pink", 0xffb2d0),
+    XkcdColor::new("primary blue", 0x0804f9),
+    XkcdColor::new("prussian blue", 0x004577),
+    XkcdColor::new("puce", 0xa57e52),
+    XkcdColor::new("puke", 0xa5a502),
+    XkcdColor::new("puke brown", 0x947706),
+    XkcdColor::new("puke green", 0x9aae07),
+    XkcdColor::new("puke yellow", 0xc2be0e),
+    XkcdColor::new("pumpkin", 0xe17701),
+    XkcdColor::new("pumpkin orange", 0xfb7d07),
+    XkcdColor::new("pure blue", 0x0203e2),
+    XkcdColor::new("purple", 0x7e1e9c),
+    XkcdColor::new("purple blue", 0x632de9),
+    XkcdColor::new("purple brown", 0x673a3f),
+    XkcdColor::new("purple grey", 0x866f85),
+    XkcdColor::new("purple pink", 0xe03fd8),
+    XkcdColor::new("purple red", 0x990147),
+    XkcdColor::new("purple/blue", 0x5d21d0),
+    XkcdColor::new("purple/pink", 0xd725de),
+    XkcdColor::new("purpleish", 0x98568d),
+    XkcdColor::new("purpleish blue", 0x6140ef),
+    XkcdColor::new("purpleish pink", 0xdf4ec8),
+    XkcdColor::new("purpley", 0x8756e4),
+    XkcdColor::new("purpley blue", 0x5f34e7),
+    XkcdColor::new("purpley grey", 0x947e94),
+    XkcdColor::new("purpley pink", 0xc83cb9),
+    XkcdColor::new("purplish", 0x94568c),
+    XkcdColor::new("purplish blue", 0x601ef9),
+    XkcdColor::new("purplish brown", 0x6b4247),
+    XkcdColor::new("purplish grey", 0x7a687f),
+    XkcdColor::new("purplish pink", 0xce5dae),
+    XkcdColor::new("purplish red", 0xb0054b),
+    XkcdColor::new("purply", 0x983fb2),
+    XkcdColor::new("purply blue", 0x661aee),
+    XkcdColor::new("purply pink", 0xf075e6),
+    XkcdColor::new("putty", 0xbeae8a),
+    XkcdColor::new("racing green", 0x014600),
+    XkcdColor::new("radioactive green", 0x2cfa1f),
+    XkcdColor::new("raspberry", 0xb00149),
+    XkcdColor::new("raw sienna", 0x9a6200),
+    XkcdColor::new("raw umber", 0xa75e09),
+    XkcdColor::new("really light blue", 0xd4ffff),
+    XkcdColor::new("red", 0xe50000),
+    XkcdColor::new("red brown", 0x8b2e16),
+    XkcdColor::new("red orange", 0xfd3c06),
+    XkcdColor::new("red pink", 0xfa2a55),
+    XkcdColor::new("red purple", 0x820747),
+    XkcdColor::new("red violet", 0x9e0168),
+    XkcdColor::new("red wine", 0x8c0034),
+    XkcdColor::new("reddish", 0xc44240),
+    XkcdColor::new("reddish brown", 0x7f2b0a),
+    XkcdColor::new("reddish grey", 0x997570),
+    XkcdColor::new("reddish orange", 0xf8481c),
+    XkcdColor::new("reddish pink", 0xfe2c54),
+    XkcdColor::new("reddish purple", 0x910951),
+    XkcdColor::new("reddy brown", 0x6e1005),
+    XkcdColor::new("rich blue", 0x021bf9),
+    XkcdColor::new("rich purple", 0x720058),
+    XkcdColor::new("robin egg blue", 0x8af1fe),
+    XkcdColor::new("robin's egg", 0x6dedfd),
+    XkcdColor::new("robin's egg blue", 0x98eff9),
+    XkcdColor::new("rosa", 0xfe86a4),
+    XkcdColor::new("rose", 0xcf6275),
+    XkcdColor::new("rose pink", 0xf7879a),
+    XkcdColor::new("rose red", 0xbe013c),
+    XkcdColor::new("rosy pink", 0xf6688e),
+    XkcdColor::new("rouge", 0xab1239),
+    XkcdColor::new("royal", 0x0c1793),
+    XkcdColor::new("royal blue", 0x0504aa),
+    XkcdColor::new("royal purple", 0x4b006e),
+    XkcdColor::new("ruby", 0xca0147),
+    XkcdColor::new("russet", 0xa13905),
+    XkcdColor::new("rust", 0xa83c09),
+    XkcdColor::new("rust brown", 0x8b3103),
+    XkcdColor::new("rust orange", 0xc45508),
+    XkcdColor::new("rust red", 0xaa2704),
+    XkcdColor::new("rusty orange", 0xcd5909),
+    XkcdColor::new("rusty red", 0xaf2f0d),
+    XkcdColor::new("saffron", 0xfeb209),
+    XkcdColor::new("sage", 0x87ae73),
+    XkcdColor::new("sage green", 0x88b378),
+    XkcdColor::new("salmon", 0xff796c),
+    XkcdColor::new("salmon pink", 0xfe7b7c),
+    XkcdColor::new("sand", 0xe2ca76),
+    XkcdColor::new("sand brown", 0xcba560),
+    XkcdColor::new("sand yellow", 0xfce166),
+    XkcdColor::new("sandstone", 0xc9ae74),
+    XkcdColor::new("sandy", 0xf1da7a),
+    XkcdColor::new("sandy brown", 0xc4a661),
+    XkcdColor::new("sandy yellow", 0xfdee73),
+    XkcdColor::new("sap green", 0x5c8b15),
+    XkcdColor::new("sapphire", 0x2138ab),
+    XkcdColor::new("scarlet", 0xbe0119),
+    XkcdColor::new("sea", 0x3c9992),
+    XkcdColor::new("sea blue", 0x047495),
+    XkcdColor::new("sea green", 0x53fca1),
+    XkcdColor::new("seafoam", 0x80f9ad),
+    XkcdColor::new("seafoam blue", 0x78d1b6),
+    XkcdColor::new("seafoam green", 0x7af9ab),
+    XkcdColor::new("seaweed", 0x18d17b),
+    XkcdColor::new("seaweed green", 0x35ad6b),
+    XkcdColor::new("sepia", 0x985e2b),
+    XkcdColor::new("shamrock", 0x01b44c),
+    XkcdColor::new("shamrock green", 0x02c14d),
+    XkcdColor::new("shit", 0x7f5f00),
+    XkcdColor::new("shit brown", 0x7b5804),
+    XkcdColor::new("shit green", 0x758000),
+    XkcdColor::new("shocking pink", 0xfe02a2),
+    XkcdColor::new("sick green", 0x9db92c),
+    XkcdColor::new("sickly green", 0x94b21c),
+    XkcdColor::new("sickly yellow", 0xd0e429),
+    XkcdColor::new("sienna", 0xa9561e),
+    XkcdColor::new("silver", 0xc5c9c7),
+    XkcdColor::new("sky", 0x82cafc),
+    XkcdColor::new("sky blue", 0x75bbfd),
+    XkcdColor::new("slate", 0x516572),
+    XkcdColor::new("slate blue", 0x5b7c99),
+    XkcdColor::new("slate green", 0x658d6d),
+    XkcdColor::new("slate grey", 0x59656d),
+    XkcdColor::new("slime green", 0x99cc04),
+    XkcdColor::new("snot", 0xacbb0d),
+    XkcdColor::new("snot green", 0x9dc100),
+    XkcdColor::new("soft blue", 0x6488ea),
+    XkcdColor::new("soft green", 0x6fc276),
+    XkcdColor::new("soft pink", 0xfdb0c0),
+    XkcdColor::new("soft purple", 0xa66fb5),
+    XkcdColor::new("spearmint", 0x1ef876),
+    XkcdColor::new("spring green", 0xa9f971),
+    XkcdColor::new("spruce", 0x0a5f38),
+    XkcdColor::new("squash", 0xf2ab15),
+    XkcdColor::new("steel", 0x738595),
+    XkcdColor::new("steel blue", 0x5a7d9a),
+    XkcdColor::new("steel grey", 0x6f828a),
+    XkcdColor::new("stone", 0xada587),
+    XkcdColor::new("stormy blue", 0x507b9c),
+    XkcdColor::new("straw", 0xfcf679),
+    XkcdColor::new("strawberry", 0xfb2943),
+    XkcdColor::new("strong blue", 0x0c06f7),
+    XkcdColor::new("strong pink", 0xff0789),
+    XkcdColor::new("sun yellow", 0xffdf22),
+    XkcdColor::new("sunflower", 0xffc512),
+    XkcdColor::new("sunflower yellow", 0xffda03),
+    XkcdColor::new("sunny yellow", 0xfff917),
+    XkcdColor::new("sunshine yellow", 0xfffd37),
+    XkcdColor::new("swamp", 0x698339),
+    XkcdColor::new("swamp green", 0x748500),
+    XkcdColor::new("tan", 0xd1b26f),
+    XkcdColor::new("tan brown", 0xab7e4c),
+    XkcdColor::new("tan green", 0xa9be70),
+    XkcdColor::new("tangerine", 0xff9408),
+    XkcdColor::new("taupe", 0xb9a281),
+    XkcdColor::new("tea", 0x65ab7c),
+    XkcdColor::new("tea green", 0xbdf8a3),
+    XkcdColor::new("teal", 0x029386),
+    XkcdColor::new("teal blue", 0x01889f),
+    XkcdColor::new("teal green", 0x25a36f),
+    XkcdColor::new("tealish", 0x24bca8),
+    XkcdColor::new("tealish green", 0x0cdc73),
+    XkcdColor::new("terra cotta", 0xc9643b),
+    XkcdColor::new("terracota", 0xcb6843),
+    XkcdColor::new("terracotta", 0xca6641),
+    XkcdColor::new("tiffany blue", 0x7bf2da),
+    XkcdColor::new("tomato", 0xef4026),
+    XkcdColor::new("tomato red", 0xec2d01),
+    XkcdColor::new("topaz", 0x13bbaf),
+    XkcdColor::new("toupe", 0xc7ac7d),
+    XkcdColor::new("toxic green", 0x61de2a),
+    XkcdColor::new("tree green", 0x2a7e19),
+    XkcdColor::new("true blue", 0x010fcc),
+    XkcdColor::new("true green", 0x089404),
+    XkcdColor::new("turquoise", 0x06c2ac),
+    XkcdColor::new("turquoise blue", 0x06b1c4),
+    XkcdColor::new("turquoise green", 0x04f489),
+    XkcdColor::new("turtle green", 0x75b84f),
+    XkcdColor::new("twilight", 0x4e518b),
+    XkcdColor::new("twilight blue", 0x0a437a),
+    XkcdColor::new("ugly blue", 0x31668a),
+    XkcdColor::new("ugly brown", 0x7d7103),
+    XkcdColor::new("ugly green", 0x7a9703),
+    XkcdColor::new("ugly pink", 0xcd7584),
+    XkcdColor::new("ugly purple", 0xa442a0),
+    XkcdColor::new("ugly yellow", 0xd0c101),
+    XkcdColor::new("ultramarine", 0x2000b1),
+    XkcdColor::new("ultramarine blue", 0x1805db),
+    XkcdColor::new("umber", 0xb26400),
+    XkcdColor::new("velvet", 0x750851),
+    XkcdColor::new("vermillion", 0xf4320c),
+    XkcdColor::new("very dark blue", 0x000133),
+    XkcdColor::new("very dark brown", 0x1d0200),
+    XkcdColor::new("very dark green", 0x062e03),
+    XkcdColor::new("very dark purple", 0x2a0134),
+    XkcdColor::new("very light blue", 0xd5ffff),
+    XkcdColor::new("very light brown", 0xd3b683),
+    XkcdColor::new("very light green", 0xd1ffbd),
+    XkcdColor::new("very light pink", 0xfff4f2),
+    XkcdColor::new("very light purple", 0xf6cefc),
+    XkcdColor::new("very pale blue", 0xd6fffe),
+    XkcdColor::new("very pale green", 0xcffdbc),
+    XkcdColor::new("vibrant blue", 0x0339f8),
+    XkcdColor::new("vibrant green", 0x0add08),
+    XkcdColor::new("vibrant purple", 0xad03de),
+    XkcdColor::new("violet", 0x9a0eea),
+    XkcdColor::new("violet blue", 0x510ac9),
+    XkcdColor::new("violet pink", 0xfb5ffc),
+    XkcdColor::new("violet red", 0xa50055),
+    XkcdColor::new("viridian", 0x1e9167),
+    XkcdColor::new("vivid blue", 0x152eff),
+    XkcdColor::new("vivid green", 0x2fef10),
+    XkcdColor::new("vivid purple", 0x9900fa),
+    XkcdColor::new("vomit", 0xa2a415),
+    XkcdColor::new("vomit green", 0x89a203),
+    XkcdColor::new("vomit yellow", 0xc7c10c),
+    XkcdColor::new("warm blue", 0x4b57db),
+    XkcdColor::new("warm brown", 0x964e02),
+    XkcdColor::new("warm grey", 0x978a84),
+    XkcdColor::new("warm pink", 0xfb5581),
+    XkcdColor::new("warm purple", 0x952e8f),
+    XkcdColor::new("washed out green", 0xbcf5a6),
+    XkcdColor::new("water blue", 0x0e87cc),
+    XkcdColor::new("watermelon", 0xfd4659),
+    XkcdColor::new("weird green", 0x3ae57f),
+    XkcdColor::new("wheat", 0xfbdd7e),
+    XkcdColor::new("white", 0xffffff),
+    XkcdColor::new("windows blue", 0x3778bf),
+    XkcdColor::new("wine", 0x80013f),
+    XkcdColor::new("wine red", 0x7b0323),
+    XkcdColor::new("wintergreen", 0x20f986),
+    XkcdColor::new("wisteria", 0xa87dc2),
+    XkcdColor::new("yellow", 0xffff14),
+    XkcdColor::new("yellow brown", 0xb79400),
+    XkcdColor::new("yellow green", 0xc0fb2d),
+    XkcdColor::new("yellow ochre", 0xcb9d06),
+    XkcdColor::new("yellow orange", 0xfcb001),
+    XkcdColor::new("yellow tan", 0xffe36e),
+    XkcdColor::new("yellow/green", 0xc8fd3d),
+    XkcdColor::new("yellowgreen", 0xbbf90f),
+    XkcdColor::new("yellowish", 0xfaee66),
+    XkcdColor::new("yellowish brown", 0x9b7a01),
+    XkcdColor::new("yellowish green", 0xb0dd16),
+    XkcdColor::new("yellowish orange", 0xffab0f),
+    XkcdColor::new("yellowish tan", 0xfcfc81),
+    XkcdColor::new("yellowy brown", 0xae8b0c),
+    XkcdColor::new("yellowy green", 0xbff128),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::models::rgb::rgb24::RGB24;
+    use crate::models::rgb::RGBColor;
+    use crate::presets::xkcd::XkcdColor;
+
+    #[test]
+    fn all_() {
+        assert_eq!(949, XkcdColor::all().count());
+        let names: Vec<&str> = XkcdColor::all().map(|color| color.name()).collect();
+        assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn from_name_() {
+        assert_eq!(
+            RGB24::from_hex("c0737a"),
+            XkcdColor::from_name("dusty rose").unwrap().to_rgb24()
+        );
+        assert_eq!(
+            RGB24::from_hex("7e1e9c"),
+            XkcdColor::from_name("Purple").unwrap().to_rgb24()
+        );
+        assert_eq!(None, XkcdColor::from_name("not a color"));
+    }
+
+    #[test]
+    fn from_name_whitespace() {
+        assert_eq!(
+            "darkgreen",
+            XkcdColor::from_name("DarkGreen").unwrap().name()
+        );
+        assert_eq!(
+            "dark green",
+            XkcdColor::from_name("dark  green").unwrap().name()
+        );
+        assert_eq!(
+            "dusty rose",
+            XkcdColor::from_name("dustyrose").unwrap().name()
+        );
+    }
+
+    #[test]
+    fn nearest_() {
+        assert_eq!(
+            "dusty rose",
+            XkcdColor::nearest(&RGB24::from_hex("c0737a")).name()
+        );
+        assert_eq!(
+            "dusty rose",
+            XkcdColor::nearest(&RGB24::from_hex("c1737b")).name()
+        );
+        assert_eq!("white", XkcdColor::nearest(&RGB24::WHITE).name());
+        assert_eq!("black", XkcdColor::nearest(&RGB24::BLACK).name());
+    }
+
+    #[test]
+    fn fmt_() {
+        assert_eq!(
+            "dusty rose",
+            format!("{}", XkcdColor::from_name("dusty rose").unwrap())
+        );
+    }
+}
@@ -0,0 +1,708 @@
+use crate::matrix::{self, Matrix3};
+use crate::models::rgb::linear::LinearRgb;
+use crate::models::rgb::RGBColor;
+use crate::models::xyz::XYZ;
+use std::sync::OnceLock;
+
+/// Chromatic adaptation between white points
+mod adaptation;
+/// HDR encodings in absolute luminance
+mod hdr;
+/// Standard illuminants and their white points
+mod illuminant;
+/// Transfer functions between linear light and encoded values
+mod transfer;
+
+pub use adaptation::ChromaticAdaptation;
+pub use hdr::{
+    pq_decode_nits, pq_encode_nits, HlgDisplay, PQ_PEAK_LUMINANCE, REFERENCE_WHITE_LUMINANCE,
+};
+pub use illuminant::{Illuminant, Observer};
+pub use transfer::{CurveTable, ParametricCurve, TransferFunction};
+
+/// The white point [`Illuminant::D65`] as rounded by the RGB standards
+pub(crate) const D65: (f64, f64) = (0.3127, 0.3290);
+/// The white point [`Illuminant::D50`] as rounded by the RGB standards
+const D50: (f64, f64) = (0.3457, 0.3585);
+/// The white point of ACES (close to D60)
+const ACES_WHITE: (f64, f64) = (0.32168, 0.33767);
+/// The ACES primaries AP0 (containing all visible colors)
+const AP0: [(f64, f64); 3] = [(0.7347, 0.2653), (0.0, 1.0), (0.0001, -0.0770)];
+/// The ACES primaries AP1 (close to Rec. 2020)
+const AP1: [(f64, f64); 3] = [(0.713, 0.293), (0.165, 0.830), (0.128, 0.044)];
+
+/// An RGB color space
+///
+/// Defines the meaning of RGB values by
+/// - the chromaticities of the three primaries (red, green, blue)
+/// - the chromaticity of the white point (`1.0, 1.0, 1.0`)
+/// - the [`TransferFunction`] between linear light and encoded values
+///
+/// The RGB types ([`RGB24`](crate::RGB24), [`RGB48`](crate::RGB48)) store encoded values.
+/// Without further information they are [sRGB](RgbSpace::srgb), but they may be
+/// interpreted in any other space and converted between spaces via [`XYZ`].
+/// Linear values of a space are represented by [`LinearRgb`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RgbSpace {
+    name: String,
+    primaries: [(f64, f64); 3],
+    white_point: (f64, f64),
+    transfer_function: TransferFunction,
+    to_xyz: Matrix3,
+    from_xyz: Matrix3,
+}
+
+impl RgbSpace {
+    /// Creates a new `RgbSpace` from the given chromaticities, e.g. measured from a display
+    ///
+    /// The conversion matrices are derived from the primaries and the white point.
+    ///
+    /// # Parameters
+    /// - `name`: the name of the space
+    /// - `primaries`: the chromaticities `(x, y)` of red, green and blue
+    /// - `white_point`: the chromaticity `(x, y)` of white
+    /// - `transfer_function`: the transfer function between linear light and encoded values
+    ///
+    /// # Returns
+    /// `None` if the chromaticities are degenerated (e.g. primaries on a line)
+    /// or the transfer function is invalid (e.g. [`TransferFunction::Gamma`] of `0.0`)
+    ///
+    /// # Example
+    /// ```
+    /// use colorful::space::{ParametricCurve, RgbSpace, TransferFunction};
+    ///
+    /// let display = RgbSpace::new(
+    ///     "My display",
+    ///     [(0.6835, 0.3081), (0.2639, 0.6847), (0.1524, 0.0540)],
+    ///     (0.3134, 0.3291),
+    ///     TransferFunction::Parametric(ParametricCurve::gamma(2.23).unwrap()),
+    /// )
+    /// .unwrap();
+    /// assert_eq!("My display", display.name());
+    /// ```
+    pub fn new(
+        name: &str,
+        primaries: [(f64, f64); 3],
+        white_point: (f64, f64),
+        transfer_function: TransferFunction,
+    ) -> Option<Self> {
+        if !transfer_function.is_valid() {
+            return None;
+        }
+        let to_xyz = rgb_to_xyz_matrix(&primaries, white_point)?;
+        let from_xyz = matrix::inverse(&to_xyz)?;
+
+        Some(RgbSpace {
+            name: name.to_string(),
+            primaries,
+            white_point,
+            transfer_function,
+            to_xyz,
+            from_xyz,
+        })
+    }
+
+    /// [sRGB](https://en.wikipedia.org/wiki/SRGB) (IEC 61966-2-1) - the default of the RGB types
+    pub fn srgb() -> &'static RgbSpace {
+        static SPACE: OnceLock<RgbSpace> = OnceLock::new();
+        SPACE.get_or_init(|| {
+            RgbSpace::new(
+                "sRGB",
+                [(0.64, 0.33), (0.30, 0.60), (0.15, 0.06)],
+                D65,
+                TransferFunction::Srgb,
+            )
+            .expect("built-in space is valid")
+        })
+    }
+
+    /// [Display P3](https://en.wikipedia.org/wiki/DCI-P3#Display_P3) - DCI-P3 primaries with D65 and the sRGB curve
+    pub fn display_p3() -> &'static RgbSpace {
+        static SPACE: OnceLock<RgbSpace> = OnceLock::new();
+        SPACE.get_or_init(|| {
+            RgbSpace::new(
+                "Display P3",
+                [(0.680, 0.320), (0.265, 0.690), (0.150, 0.060)],
+                D65,
+                TransferFunction::Srgb,
+            )
+            .expect("built-in space is valid")
+        })
+    }
+
+    /// [Adobe RGB (1998)](https://en.wikipedia.org/wiki/Adobe_RGB_color_space)
+    pub fn adobe_rgb() -> &'static RgbSpace {
+        static SPACE: OnceLock<RgbSpace> = OnceLock::new();
+        SPACE.get_or_init(|| {
+            RgbSpace::new(
+                "Adobe RGB (1998)",
+                [(0.64, 0.33), (0.21, 0.71), (0.15, 0.06)],
+                D65,
+                TransferFunction::Gamma(563.0 / 256.0),
+            )
+            .expect("built-in space is valid")
+        })
+    }
+
+    /// [Rec. 709](https://en.wikipedia.org/wiki/Rec._709) (HDTV) - sRGB primaries with the Rec. 709 curve
+    pub fn rec709() -> &'static RgbSpace {
+        static SPACE: OnceLock<RgbSpace> = OnceLock::new();
+        SPACE.get_or_init(|| {
+            RgbSpace::new(
+                "Rec. 709",
+                [(0.64, 0.33), (0.30, 0.60), (0.15, 0.06)],
+                D65,
+                TransferFunction::Rec709,
+            )
+            .expect("built-in space is valid")
+        })
+    }
+
+    /// [Rec. 2020](https://en.wikipedia.org/wiki/Rec._2020) (UHDTV)
+    pub fn rec2020() -> &'static RgbSpace {
+        static SPACE: OnceLock<RgbSpace> = OnceLock::new();
+        SPACE.get_or_init(|| {
+            RgbSpace::new(
+                "Rec. 2020",
+                [(0.708, 0.292), (0.170, 0.797), (0.131, 0.046)],
+                D65,
+                TransferFunction::Rec709,
+            )
+            .expect("built-in space is valid")
+        })
+    }
+
+    /// [Rec. 2100](https://en.wikipedia.org/wiki/Rec._2100) with PQ (HDR10) - the primaries of Rec. 2020
+    ///
+    /// Linear `1.0` is the [`REFERENCE_WHITE_LUMINANCE`] of 203 cd/m² (nits),
+    /// so SDR white is converted to the HDR reference white (see [`TransferFunction::RelativePq`]).
+    ///
+    /// # Example
+    /// ```
+    /// use colorful::space::RgbSpace;
+    /// use colorful::{RGBColor, RGB48};
+    ///
+    /// let white: RGB48 = RgbSpace::srgb().convert(&RGB48::WHITE, RgbSpace::rec2100_pq());
+    /// assert_eq!(RGB48::from_rgb(38_055, 38_055, 38_055), white);
+    /// ```
+    pub fn rec2100_pq() -> &'static RgbSpace {
+        static SPACE: OnceLock<RgbSpace> = OnceLock::new();
+        SPACE.get_or_init(|| {
+            RgbSpace::new(
+                "Rec. 2100 PQ",
+                RgbSpace::rec2020().primaries,
+                D65,
+                TransferFunction::RelativePq(REFERENCE_WHITE_LUMINANCE),
+            )
+            .expect("built-in space is valid")
+        })
+    }
+
+    /// [Rec. 2100](https://en.wikipedia.org/wiki/Rec._2100) with HLG - the primaries of Rec. 2020
+    ///
+    /// Linear values are relative scene light, see [`HlgDisplay`] for the display light.
+    pub fn rec2100_hlg() -> &'static RgbSpace {
+        static SPACE: OnceLock<RgbSpace> = OnceLock::new();
+        SPACE.get_or_init(|| {
+            RgbSpace::new(
+                "Rec. 2100 HLG",
+                RgbSpace::rec2020().primaries,
+                D65,
+                TransferFunction::Hlg,
+            )
+            .expect("built-in space is valid")
+        })
+    }
+
+    /// [ACES2065-1](https://en.wikipedia.org/wiki/Academy_Color_Encoding_System) - linear AP0, the ACES interchange format
+    pub fn aces2065_1() -> &'static RgbSpace {
+        static SPACE: OnceLock<RgbSpace> = OnceLock::new();
+        SPACE.get_or_init(|| {
+            RgbSpace::new("ACES2065-1", AP0, ACES_WHITE, TransferFunction::Linear)
+                .expect("built-in space is valid")
+        })
+    }
+
+    /// [ACEScg](https://en.wikipedia.org/wiki/Academy_Color_Encoding_System) - linear AP1 for rendering and compositing
+    pub fn acescg() -> &'static RgbSpace {
+        static SPACE: OnceLock<RgbSpace> = OnceLock::new();
+        SPACE.get_or_init(|| {
+            RgbSpace::new("ACEScg", AP1, ACES_WHITE, TransferFunction::Linear)
+                .expect("built-in space is valid")
+        })
+    }
+
+    /// [ACEScc](https://en.wikipedia.org/wiki/Academy_Color_Encoding_System) - log encoded AP1 for color grading
+    ///
+    /// The encoded values exceed `0.0 - 1.0`, so prefer the linear values over the RGB types.
+    pub fn acescc() -> &'static RgbSpace {
+        static SPACE: OnceLock<RgbSpace> = OnceLock::new();
+        SPACE.get_or_init(|| {
+            RgbSpace::new("ACEScc", AP1, ACES_WHITE, TransferFunction::AcesCc)
+                .expect("built-in space is valid")
+        })
+    }
+
+    /// [ACEScct](https://en.wikipedia.org/wiki/Academy_Color_Encoding_System) - log encoded AP1 with a toe for color grading
+    ///
+    /// The encoded values exceed `0.0 - 1.0`, so prefer the linear values over the RGB types.
+    pub fn acescct() -> &'static RgbSpace {
+        static SPACE: OnceLock<RgbSpace> = OnceLock::new();
+        SPACE.get_or_init(|| {
+            RgbSpace::new("ACEScct", AP1, ACES_WHITE, TransferFunction::AcesCct)
+                .expect("built-in space is valid")
+        })
+    }
+
+    /// [ProPhoto RGB](https://en.wikipedia.org/wiki/ProPhoto_RGB_color_space) (ROMM RGB) - based on D50
+    pub fn prophoto() -> &'static RgbSpace {
+        static SPACE: OnceLock<RgbSpace> = OnceLock::new();
+        SPACE.get_or_init(|| {
+            RgbSpace::new(
+                "ProPhoto RGB",
+                [(0.7347, 0.2653), (0.1596, 0.8404), (0.0366, 0.0001)],
+                D50,
+                TransferFunction::ProPhoto,
+            )
+            .expect("built-in space is valid")
+        })
+    }
+
+    /// Returns the name of this space
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the chromaticities `(x, y)` of the primaries red, green and blue
+    pub fn primaries(&self) -> [(f64, f64); 3] {
+        self.primaries
+    }
+
+    /// Returns the chromaticity `(x, y)` of the white point
+    pub fn white_point(&self) -> (f64, f64) {
+        self.white_point
+    }
+
+    /// Returns the transfer function
+    pub fn transfer_function(&self) -> &TransferFunction {
+        &self.transfer_function
+    }
+
+    /// Returns the matrix converting linear RGB -> XYZ (row by row)
+    pub fn to_xyz_matrix(&self) -> [[f64; 3]; 3] {
+        self.to_xyz
+    }
+
+    /// Returns the matrix converting XYZ -> linear RGB (row by row)
+    pub fn from_xyz_matrix(&self) -> [[f64; 3]; 3] {
+        self.from_xyz
+    }
+
+    /// Decodes the given values (0.0 - 1.0) to linear light
+    pub fn decode(&self, rgb: (f64, f64, f64)) -> LinearRgb {
+        let f = &self.transfer_function;
+        LinearRgb::new(f.decode(rgb.0), f.decode(rgb.1), f.decode(rgb.2))
+    }
+
+    /// Encodes the given linear values (0.0 - 1.0 for colors in gamut)
+    pub fn encode(&self, linear: &LinearRgb) -> (f64, f64, f64) {
+        let f = &self.transfer_function;
+        (
+            f.encode(linear.r()),
+            f.encode(linear.g()),
+            f.encode(linear.b()),
+        )
+    }
+
+    /// Converts linear values of this space -> [`XYZ`] (relative to the white point of this space)
+    pub fn linear_to_xyz(&self, linear: &LinearRgb) -> XYZ {
+        XYZ::from(matrix::apply(&self.to_xyz, linear.as_tuple()))
+    }
+
+    /// Converts [`XYZ`] (relative to the white point of this space) -> linear values of this space
+    pub fn xyz_to_linear(&self, xyz: &XYZ) -> LinearRgb {
+        LinearRgb::from(matrix::apply(&self.from_xyz, xyz.as_tuple()))
+    }
+
+    /// Converts the given color (encoded in this space) -> [`XYZ`]
+    pub fn to_xyz<T>(&self, color: &impl RGBColor<T>) -> XYZ {
+        self.linear_to_xyz(&self.decode(color.as_tuple_f64()))
+    }
+
+    /// Converts [`XYZ`] -> the given [`RGBColor`] (encoded in this space)
+    ///
+    /// Out of gamut values are clamped.
+    pub fn from_xyz<S: RGBColor<U>, U>(&self, xyz: &XYZ) -> S {
+        let (r, g, b) = self.encode(&self.xyz_to_linear(xyz));
+        S::from_rgb_f64(r, g, b)
+    }
+
+    /// Converts linear values of this space -> linear values of the `target` space
+    ///
+    /// If the white points differ, the colors are adapted using the Bradford transform.
+    pub fn convert_linear(&self, linear: &LinearRgb, target: &RgbSpace) -> LinearRgb {
+        self.convert_linear_with(linear, target, ChromaticAdaptation::Bradford)
+    }
+
+    /// Converts linear values of this space -> linear values of the `target` space
+    ///
+    /// If the white points differ, the colors are adapted using the given transform.
+    pub fn convert_linear_with(
+        &self,
+        linear: &LinearRgb,
+        target: &RgbSpace,
+        adaptation: ChromaticAdaptation,
+    ) -> LinearRgb {
+        LinearRgb::from(matrix::apply(
+            &self.conversion_matrix(target, adaptation),
+            linear.as_tuple(),
+        ))
+    }
+
+    /// Converts the given color (encoded in this space) -> an [`RGBColor`] encoded in the `target` space
+    ///
+    /// If the white points differ, the colors are adapted using the Bradford transform.
+    /// Out of gamut values are clamped.
+    ///
+    /// # Example
+    /// ```
+    /// use colorful::space::RgbSpace;
+    /// use colorful::{RGBColor, RGB24};
+    ///
+    /// let red: RGB24 = RgbSpace::srgb().convert(&RGB24::RED, RgbSpace::display_p3());
+    /// assert_eq!(RGB24::from_rgb(234, 51, 35), red);
+    /// ```
+    pub fn convert<T, S: RGBColor<U>, U>(&self, color: &impl RGBColor<T>, target: &RgbSpace) -> S {
+        let linear = self.convert_linear(&self.decode(color.as_tuple_f64()), target);
+        let (r, g, b) = target.encode(&linear);
+        S::from_rgb_f64(r, g, b)
+    }
+
+    /// Returns the matrix converting linear values of this space -> linear values of the `target` space
+    fn conversion_matrix(&self, target: &RgbSpace, adaptation: ChromaticAdaptation) -> Matrix3 {
+        let adaptation = adaptation.matrix(self.white_point, target.white_point);
+        matrix::multiply(
+            &target.from_xyz,
+            &matrix::multiply(&adaptation, &self.to_xyz),
+        )
+    }
+}
+
+/// Derives the matrix converting linear RGB -> XYZ from the chromaticities
+///
+/// # Returns
+/// `None` if the chromaticities are degenerated
+fn rgb_to_xyz_matrix(primaries: &[(f64, f64); 3], white_point: (f64, f64)) -> Option<Matrix3> {
+    let is_finite = |(x, y): (f64, f64)| x.is_finite() && y.is_finite();
+    // primaries may be imaginary (e.g. ACES AP0), the white point must be a real color
+    if !primaries.iter().all(|&xy| is_finite(xy) && xy.1 != 0.0)
+        || !is_finite(white_point)
+        || white_point.1 <= 0.0
+    {
+        return None;
+    }
+
+    let [r, g, b] = primaries.map(|(x, y)| XYZ::from_xyy(x, y, 1.0));
+
+    let primaries = [
+        [r.x(), g.x(), b.x()],
+        [r.y(), g.y(), b.y()],
+        [r.z(), g.z(), b.z()],
+    ];
+    let white = XYZ::from_xyy(white_point.0, white_point.1, 1.0);
+    let scale = matrix::apply(&matrix::inverse(&primaries)?, white.as_tuple());
+
+    Some(matrix::multiply(&primaries, &matrix::diagonal(scale)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::rgb::linear::LinearRgb;
+    use crate::models::rgb::RGBColor;
+    use crate::models::xyz::XYZ;
+    use crate::space::{
+        ChromaticAdaptation, CurveTable, Illuminant, ParametricCurve, RgbSpace, TransferFunction,
+    };
+    use crate::{RGB24, RGB48};
+
+    fn assert_approx_equal(expected: (f64, f64, f64), actual: (f64, f64, f64), epsilon: f64) {
+        if (expected.0 - actual.0).abs() >= epsilon
+            || (expected.1 - actual.1).abs() >= epsilon
+            || (expected.2 - actual.2).abs() >= epsilon
+        {
+            panic!("{:?} !~ {:?}", expected, actual);
+        }
+    }
+
+    fn all() -> Vec<&'static RgbSpace> {
+        vec![
+            RgbSpace::srgb(),
+            RgbSpace::display_p3(),
+            RgbSpace::adobe_rgb(),
+            RgbSpace::rec709(),
+            RgbSpace::rec2020(),
+            RgbSpace::prophoto(),
+        ]
+    }
+
+    #[test]
+    fn srgb_matrix() {
+        let m = RgbSpace::srgb().to_xyz_matrix();
+        assert_approx_equal((0.4124, 0.3576, 0.1805), (m[0][0], m[0][1], m[0][2]), 1e-4);
+        assert_approx_equal((0.2126, 0.7152, 0.0722), (m[1][0], m[1][1], m[1][2]), 1e-4);
+        assert_approx_equal((0.0193, 0.1192, 0.9505), (m[2][0], m[2][1], m[2][2]), 1e-4);
+    }
+
+    #[test]
+    fn white_is_white() {
+        for source in all() {
+            for target in all() {
+                let white = source.convert_linear(&LinearRgb::WHITE, target);
+                assert_approx_equal((1.0, 1.0, 1.0), white.as_tuple(), 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let color = RGB48::from_rgb(12_000, 40_000, 61_000);
+        for source in all() {
+            for target in all() {
+                let converted = source.convert_linear(&source.decode(color.as_tuple_f64()), target);
+                let back = target.convert_linear(&converted, source);
+                assert_approx_equal(
+                    source.decode(color.as_tuple_f64()).as_tuple(),
+                    back.as_tuple(),
+                    1e-9,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn srgb_to_display_p3() {
+        let linear =
+            RgbSpace::srgb().convert_linear(&LinearRgb::new(1.0, 0.0, 0.0), RgbSpace::display_p3());
+        assert_approx_equal((0.8225, 0.0332, 0.0171), linear.as_tuple(), 1e-4);
+        let red = RgbSpace::display_p3().encode(&linear);
+        assert_approx_equal((0.9175, 0.2003, 0.1386), red, 1e-4);
+    }
+
+    #[test]
+    fn display_p3_to_srgb_clamps() {
+        let red: RGB24 = RgbSpace::display_p3().convert(&RGB24::RED, RgbSpace::srgb());
+        assert_eq!(RGB24::RED, red);
+    }
+
+    #[test]
+    fn srgb_to_adobe_rgb() {
+        let green: RGB24 = RgbSpace::srgb().convert(&RGB24::GREEN, RgbSpace::adobe_rgb());
+        assert_eq!(RGB24::from_rgb(144, 255, 60), green);
+    }
+
+    #[test]
+    fn srgb_to_rec2020() {
+        let linear =
+            RgbSpace::srgb().convert_linear(&LinearRgb::new(1.0, 0.0, 0.0), RgbSpace::rec2020());
+        assert_approx_equal((0.6274, 0.0691, 0.0164), linear.as_tuple(), 1e-4);
+    }
+
+    #[test]
+    fn srgb_to_prophoto() {
+        let linear = RgbSpace::srgb().decode(RGB24::RED.as_tuple_f64());
+        let red = RgbSpace::prophoto()
+            .encode(&RgbSpace::srgb().convert_linear(&linear, RgbSpace::prophoto()));
+        assert_approx_equal((0.7022, 0.2757, 0.1036), red, 1e-3);
+    }
+
+    #[test]
+    fn rec709_and_srgb_share_primaries() {
+        let linear = LinearRgb::new(0.2, 0.4, 0.6);
+        assert_approx_equal(
+            linear.as_tuple(),
+            RgbSpace::rec709()
+                .convert_linear(&linear, RgbSpace::srgb())
+                .as_tuple(),
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn new_custom() {
+        let custom = RgbSpace::new(
+            "sRGB with gamma 2.2",
+            RgbSpace::srgb().primaries(),
+            RgbSpace::srgb().white_point(),
+            TransferFunction::Parametric(ParametricCurve::gamma(2.2).unwrap()),
+        )
+        .unwrap();
+        assert_eq!(RgbSpace::srgb().to_xyz_matrix(), custom.to_xyz_matrix());
+
+        let gray: RGB24 = RgbSpace::srgb().convert(&RGB24::from_rgb(128, 128, 128), &custom);
+        assert_eq!(RGB24::from_rgb(127, 127, 127), gray);
+    }
+
+    #[test]
+    fn new_d50_table() {
+        let custom = RgbSpace::new(
+            "Linear ProPhoto",
+            RgbSpace::prophoto().primaries(),
+            (0.3457, 0.3585),
+            TransferFunction::Table(CurveTable::new(vec![0.0, 1.0]).unwrap()),
+        )
+        .unwrap();
+        let linear =
+            RgbSpace::srgb().convert_linear(&LinearRgb::new(0.2, 0.5, 0.9), RgbSpace::prophoto());
+        let (r, g, b) = custom
+            .encode(&RgbSpace::srgb().convert_linear(&LinearRgb::new(0.2, 0.5, 0.9), &custom));
+        assert_approx_equal(linear.as_tuple(), (r, g, b), 1e-12);
+    }
+
+    #[test]
+    fn aces_matrices() {
+        // reference from S-2008-001 (ACES2065-1) and S-2014-004 (ACEScg)
+        let ap0 = [
+            [0.952_552_395_9, 0.0, 0.000_093_678_6],
+            [0.343_966_449_8, 0.728_166_096_6, -0.072_132_546_4],
+            [0.0, 0.0, 1.008_825_184_4],
+        ];
+        let ap1 = [
+            [0.662_454_181_1, 0.134_004_206_5, 0.156_187_687_0],
+            [0.272_228_716_8, 0.674_081_765_8, 0.053_689_517_4],
+            [-0.005_574_649_5, 0.004_060_733_5, 1.010_339_100_3],
+        ];
+        for (expected, space) in [(ap0, RgbSpace::aces2065_1()), (ap1, RgbSpace::acescg())] {
+            let actual = space.to_xyz_matrix();
+            for row in 0..3 {
+                let expected = (expected[row][0], expected[row][1], expected[row][2]);
+                let actual = (actual[row][0], actual[row][1], actual[row][2]);
+                assert_approx_equal(expected, actual, 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn aces_conversions() {
+        let white = RgbSpace::srgb().convert_linear(&LinearRgb::WHITE, RgbSpace::acescg());
+        assert_approx_equal((1.0, 1.0, 1.0), white.as_tuple(), 1e-12);
+
+        let gray = RgbSpace::srgb().decode((0.5, 0.5, 0.5));
+        let cc =
+            RgbSpace::acescc().encode(&RgbSpace::srgb().convert_linear(&gray, RgbSpace::acescc()));
+        let cct = RgbSpace::acescct()
+            .encode(&RgbSpace::srgb().convert_linear(&gray, RgbSpace::acescct()));
+        assert_approx_equal(cc, cct, 1e-12);
+
+        for color in [RGB24::from_rgb(200, 30, 90), RGB24::from_rgb(10, 250, 3)] {
+            for space in [
+                RgbSpace::aces2065_1(),
+                RgbSpace::acescg(),
+                RgbSpace::acescc(),
+                RgbSpace::acescct(),
+            ] {
+                let linear = RgbSpace::srgb().convert_linear(&LinearRgb::from(color), space);
+                let encoded = space.encode(&linear);
+                let back = RgbSpace::srgb()
+                    .encode(&space.convert_linear(&space.decode(encoded), RgbSpace::srgb()));
+                assert_eq!(
+                    color,
+                    RGB24::from_rgb_f64(back.0, back.1, back.2),
+                    "{}",
+                    space.name()
+                );
+
+                let xyz = ChromaticAdaptation::Bradford.adapt(
+                    &RgbSpace::srgb().to_xyz(&color),
+                    RgbSpace::srgb().white_point(),
+                    space.white_point(),
+                );
+                let linear_from_xyz = space.xyz_to_linear(&xyz);
+                assert_approx_equal(linear.as_tuple(), linear_from_xyz.as_tuple(), 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn white_points() {
+        let spaces = [
+            (RgbSpace::srgb(), Illuminant::D65),
+            (RgbSpace::display_p3(), Illuminant::D65),
+            (RgbSpace::adobe_rgb(), Illuminant::D65),
+            (RgbSpace::rec709(), Illuminant::D65),
+            (RgbSpace::rec2020(), Illuminant::D65),
+            (RgbSpace::prophoto(), Illuminant::D50),
+        ];
+        for (space, illuminant) in spaces {
+            let (x, y) = space.white_point();
+            assert!(
+                (illuminant.white_point().0 - x).abs() < 1e-4,
+                "{}",
+                space.name()
+            );
+            assert!(
+                (illuminant.white_point().1 - y).abs() < 1e-4,
+                "{}",
+                space.name()
+            );
+        }
+    }
+
+    #[test]
+    fn convert_linear_with() {
+        for adaptation in ChromaticAdaptation::all() {
+            let white = RgbSpace::srgb().convert_linear_with(
+                &LinearRgb::WHITE,
+                RgbSpace::prophoto(),
+                adaptation,
+            );
+            assert_approx_equal((1.0, 1.0, 1.0), white.as_tuple(), 1e-12);
+        }
+
+        let color = LinearRgb::new(0.2, 0.5, 0.9);
+        let bradford = RgbSpace::srgb().convert_linear(&color, RgbSpace::prophoto());
+        let cat16 = RgbSpace::srgb().convert_linear_with(
+            &color,
+            RgbSpace::prophoto(),
+            ChromaticAdaptation::Cat16,
+        );
+        assert_ne!(bradford, cat16);
+        assert_approx_equal(bradford.as_tuple(), cat16.as_tuple(), 0.01);
+    }
+
+    #[test]
+    fn new_invalid() {
+        let collinear = [(0.1, 0.1), (0.2, 0.2), (0.3, 0.3)];
+        assert_eq!(
+            None,
+            RgbSpace::new("", collinear, (0.3127, 0.3290), TransferFunction::Linear)
+        );
+        let primaries = RgbSpace::srgb().primaries();
+        assert_eq!(
+            None,
+            RgbSpace::new("", primaries, (0.3, 0.0), TransferFunction::Linear)
+        );
+        assert_eq!(
+            None,
+            RgbSpace::new("", primaries, (f64::NAN, 0.3), TransferFunction::Linear)
+        );
+        for gamma in [0.0, -2.2, f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                None,
+                RgbSpace::new(
+                    "",
+                    primaries,
+                    (0.3127, 0.3290),
+                    TransferFunction::Gamma(gamma)
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn to_xyz_from_xyz() {
+        let xyz = RgbSpace::srgb().to_xyz(&RGB24::WHITE);
+        assert_approx_equal((0.9505, 1.0, 1.0891), xyz.as_tuple(), 1e-4);
+        assert_eq!(RGB24::WHITE, RgbSpace::srgb().from_xyz(&xyz));
+        assert_eq!(RGB48::BLACK, RgbSpace::prophoto().from_xyz(&XYZ::default()));
+    }
+}
@@ -0,0 +1,203 @@
+use crate::matrix::{self, Matrix3};
+use crate::models::xyz::XYZ;
+
+/// A [chromatic adaptation transform](https://en.wikipedia.org/wiki/Chromatic_adaptation)
+///
+/// Adapts [`XYZ`] colors seen under one white point to the corresponding colors
+/// under another white point (e.g. D50 of print data -> D65 of screens).
+/// All transforms scale the cone responses given by their matrix (von Kries-type, full adaptation).
+///
+/// # Example
+/// ```
+/// use colorful::space::{ChromaticAdaptation, Illuminant};
+/// use colorful::XYZ;
+///
+/// let d65 = Illuminant::D65.white_point();
+/// let d50 = Illuminant::D50.white_point();
+/// let white = XYZ::from_xyy(d65.0, d65.1, 1.0);
+/// let adapted = ChromaticAdaptation::Bradford.adapt(&white, d65, d50);
+/// let (x, y) = adapted.chromaticity();
+/// assert!((x - d50.0).abs() < 1e-12 && (y - d50.1).abs() < 1e-12);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum ChromaticAdaptation {
+    /// The Bradford transform (used by ICC profiles), the default
+    #[default]
+    Bradford,
+    /// The von Kries transform using the Hunt-Pointer-Estevez cone responses
+    VonKries,
+    /// The transform of CIECAM02
+    Cat02,
+    /// The transform of CAM16
+    Cat16,
+}
+
+/// The Bradford cone response matrix
+const BRADFORD: Matrix3 = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+/// The Hunt-Pointer-Estevez cone response matrix (normalized to D65)
+const VON_KRIES: Matrix3 = [
+    [0.40024, 0.70760, -0.08081],
+    [-0.22630, 1.16532, 0.04570],
+    [0.0, 0.0, 0.91822],
+];
+
+/// The CAT02 cone response matrix
+const CAT02: Matrix3 = [
+    [0.7328, 0.4296, -0.1624],
+    [-0.7036, 1.6975, 0.0061],
+    [0.0030, 0.0136, 0.9834],
+];
+
+/// The CAT16 cone response matrix
+const CAT16: Matrix3 = [
+    [0.401288, 0.650173, -0.051461],
+    [-0.250268, 1.204414, 0.045854],
+    [-0.002079, 0.048952, 0.953127],
+];
+
+impl ChromaticAdaptation {
+    /// Returns all transforms
+    pub fn all() -> [ChromaticAdaptation; 4] {
+        [
+            ChromaticAdaptation::Bradford,
+            ChromaticAdaptation::VonKries,
+            ChromaticAdaptation::Cat02,
+            ChromaticAdaptation::Cat16,
+        ]
+    }
+
+    /// Returns the matrix converting XYZ -> cone responses (row by row)
+    pub fn cone_matrix(&self) -> [[f64; 3]; 3] {
+        match self {
+            ChromaticAdaptation::Bradford => BRADFORD,
+            ChromaticAdaptation::VonKries => VON_KRIES,
+            ChromaticAdaptation::Cat02 => CAT02,
+            ChromaticAdaptation::Cat16 => CAT16,
+        }
+    }
+
+    /// Returns the matrix adapting XYZ from the `source` white point -> `target` white point (row by row)
+    ///
+    /// # Parameters
+    /// - `source`: the chromaticity `(x, y)` of the white point the colors are seen under
+    /// - `target`: the chromaticity `(x, y)` of the white point to adapt to
+    pub fn matrix(&self, source: (f64, f64), target: (f64, f64)) -> [[f64; 3]; 3] {
+        if source == target {
+            return matrix::IDENTITY;
+        }
+
+        let cone = self.cone_matrix();
+        let source = matrix::apply(&cone, XYZ::from_xyy(source.0, source.1, 1.0).as_tuple());
+        let target = matrix::apply(&cone, XYZ::from_xyy(target.0, target.1, 1.0).as_tuple());
+        let scale = matrix::diagonal((
+            target.0 / source.0,
+            target.1 / source.1,
+            target.2 / source.2,
+        ));
+        let inverse = matrix::inverse(&cone).expect("cone response matrix is invertible");
+
+        matrix::multiply(&inverse, &matrix::multiply(&scale, &cone))
+    }
+
+    /// Adapts the given color from the `source` white point -> `target` white point
+    ///
+    /// # Parameters
+    /// - `xyz`: the color seen under the `source` white point
+    /// - `source`: the chromaticity `(x, y)` of the white point the color is seen under
+    /// - `target`: the chromaticity `(x, y)` of the white point to adapt to
+    pub fn adapt(&self, xyz: &XYZ, source: (f64, f64), target: (f64, f64)) -> XYZ {
+        XYZ::from(matrix::apply(&self.matrix(source, target), xyz.as_tuple()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::xyz::XYZ;
+    use crate::space::{ChromaticAdaptation, Illuminant};
+
+    const D65: (f64, f64) = (0.3127, 0.3290);
+    const D50: (f64, f64) = (0.3457, 0.3585);
+
+    fn assert_approx_equal(expected: (f64, f64, f64), actual: (f64, f64, f64), tolerance: f64) {
+        assert!(
+            (expected.0 - actual.0).abs() < tolerance
+                && (expected.1 - actual.1).abs() < tolerance
+                && (expected.2 - actual.2).abs() < tolerance,
+            "expected {:?}, got {:?}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn white_to_white() {
+        let a = Illuminant::A.white_point();
+        let f11 = Illuminant::F11.white_point();
+        for adaptation in ChromaticAdaptation::all() {
+            for (source, target) in [(D65, D50), (D50, D65), (D65, a), (a, D50), (D50, f11)] {
+                let white = XYZ::from_xyy(source.0, source.1, 1.0);
+                let expected = XYZ::from_xyy(target.0, target.1, 1.0);
+                let actual = adaptation.adapt(&white, source, target);
+                assert_approx_equal(expected.as_tuple(), actual.as_tuple(), 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let a = Illuminant::A.white_point();
+        let color = XYZ::new(0.2, 0.3, 0.4);
+        for adaptation in ChromaticAdaptation::all() {
+            let adapted = adaptation.adapt(&color, D65, a);
+            let back = adaptation.adapt(&adapted, a, D65);
+            assert_approx_equal(color.as_tuple(), back.as_tuple(), 1e-12);
+        }
+    }
+
+    #[test]
+    fn same_white_point() {
+        let color = XYZ::new(0.2, 0.3, 0.4);
+        for adaptation in ChromaticAdaptation::all() {
+            assert_eq!(color, adaptation.adapt(&color, D50, D50));
+        }
+    }
+
+    #[test]
+    fn bradford_d65_to_d50() {
+        // reference from http://www.brucelindbloom.com/Eqn_ChromAdapt.html
+        let expected = [
+            [1.0478112, 0.0228866, -0.0501270],
+            [0.0295424, 0.9904844, -0.0170491],
+            [-0.0092345, 0.0150436, 0.7521316],
+        ];
+        let actual = ChromaticAdaptation::Bradford.matrix(D65, D50);
+        for (expected, actual) in expected.iter().zip(actual.iter()) {
+            assert_approx_equal(
+                (expected[0], expected[1], expected[2]),
+                (actual[0], actual[1], actual[2]),
+                5e-4,
+            );
+        }
+    }
+
+    #[test]
+    fn methods_differ() {
+        let a = Illuminant::A.white_point();
+        let color = XYZ::new(0.2, 0.3, 0.4);
+        let bradford = ChromaticAdaptation::Bradford.adapt(&color, D65, a);
+        for adaptation in [
+            ChromaticAdaptation::VonKries,
+            ChromaticAdaptation::Cat02,
+            ChromaticAdaptation::Cat16,
+        ] {
+            let adapted = adaptation.adapt(&color, D65, a);
+            assert_ne!(bradford, adapted);
+            assert_approx_equal(bradford.as_tuple(), adapted.as_tuple(), 0.02);
+        }
+    }
+}
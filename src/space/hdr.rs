@@ -0,0 +1,192 @@
+use crate::models::rgb::linear::LinearRgb;
+use crate::space::TransferFunction;
+
+/// The absolute luminance in cd/m² (nits) of the PQ signal `1.0`
+pub const PQ_PEAK_LUMINANCE: f64 = 10000.0;
+
+/// The luminance in cd/m² (nits) of the HDR reference white (ITU-R BT.2408)
+///
+/// Relative colors (SDR white `Y = 1.0`) are placed at this luminance if an absolute one is needed.
+pub const REFERENCE_WHITE_LUMINANCE: f64 = 203.0;
+
+/// The luminance coefficients of Rec. 2020 / Rec. 2100
+const LUMINANCE: (f64, f64, f64) = (0.2627, 0.6780, 0.0593);
+
+/// Encodes the given luminance in cd/m² (nits) -> PQ signal value (0.0 - 1.0)
+///
+/// # Example
+/// ```
+/// use colorful::space::pq_encode_nits;
+///
+/// assert!((pq_encode_nits(1000.0) - 0.7518).abs() < 1e-4);
+/// ```
+pub fn pq_encode_nits(nits: f64) -> f64 {
+    TransferFunction::Pq.encode(nits / PQ_PEAK_LUMINANCE)
+}
+
+/// Decodes the given PQ signal value (0.0 - 1.0) -> luminance in cd/m² (nits)
+pub fn pq_decode_nits(signal: f64) -> f64 {
+    TransferFunction::Pq.decode(signal) * PQ_PEAK_LUMINANCE
+}
+
+/// A display showing [HLG](https://en.wikipedia.org/wiki/Hybrid_log%E2%80%93gamma) signals
+///
+/// HLG signals encode relative scene light. The display applies its OOTF
+/// (opto-optical transfer function) depending on its peak luminance to get
+/// the display light in cd/m² (nits). A black level of zero is assumed.
+///
+/// # Example
+/// ```
+/// use colorful::space::HlgDisplay;
+///
+/// // HLG reference white (75%) on a 1000 nits display
+/// let display = HlgDisplay::new(1000.0);
+/// let white = display.decode_nits((0.75, 0.75, 0.75));
+/// assert!((white.g() - 203.0).abs() < 0.5);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HlgDisplay {
+    peak_luminance: f64,
+    gamma: f64,
+}
+
+impl HlgDisplay {
+    /// Creates a new `HlgDisplay` with the system gamma of ITU-R BT.2100
+    ///
+    /// # Parameters
+    /// - `peak_luminance`: the nominal peak luminance of the display in cd/m² (nits)
+    ///
+    /// # Panics
+    /// If `peak_luminance` is not positive
+    pub fn new(peak_luminance: f64) -> Self {
+        assert!(
+            peak_luminance > 0.0,
+            "peak luminance must be positive: {}",
+            peak_luminance
+        );
+        HlgDisplay {
+            peak_luminance,
+            gamma: 1.2 + 0.42 * (peak_luminance / 1000.0).log10(),
+        }
+    }
+
+    /// Returns the nominal peak luminance in cd/m² (nits)
+    pub fn peak_luminance(&self) -> f64 {
+        self.peak_luminance
+    }
+
+    /// Returns the system gamma of the OOTF
+    pub fn gamma(&self) -> f64 {
+        self.gamma
+    }
+
+    /// Converts the relative scene light -> display light in cd/m² (nits)
+    pub fn ootf(&self, scene: &LinearRgb) -> LinearRgb {
+        let luminance = rec2100_luminance(scene);
+        if luminance <= 0.0 {
+            return LinearRgb::BLACK;
+        }
+        scene.scale(self.peak_luminance * luminance.powf(self.gamma - 1.0))
+    }
+
+    /// Converts the display light in cd/m² (nits) -> relative scene light
+    pub fn inverse_ootf(&self, display: &LinearRgb) -> LinearRgb {
+        let luminance = rec2100_luminance(display) / self.peak_luminance;
+        if luminance <= 0.0 {
+            return LinearRgb::BLACK;
+        }
+        let scene_luminance = luminance.powf(1.0 / self.gamma);
+        display.scale(1.0 / (self.peak_luminance * scene_luminance.powf(self.gamma - 1.0)))
+    }
+
+    /// Decodes the given HLG signal values (0.0 - 1.0) -> display light in cd/m² (nits)
+    pub fn decode_nits(&self, signal: (f64, f64, f64)) -> LinearRgb {
+        let f = TransferFunction::Hlg;
+        let scene = LinearRgb::new(f.decode(signal.0), f.decode(signal.1), f.decode(signal.2));
+        self.ootf(&scene)
+    }
+
+    /// Encodes the given display light in cd/m² (nits) -> HLG signal values (0.0 - 1.0)
+    pub fn encode_nits(&self, display: &LinearRgb) -> (f64, f64, f64) {
+        let f = TransferFunction::Hlg;
+        let scene = self.inverse_ootf(display);
+        (
+            f.encode(scene.r()),
+            f.encode(scene.g()),
+            f.encode(scene.b()),
+        )
+    }
+}
+
+/// Returns the luminance of the given linear Rec. 2020 values
+fn rec2100_luminance(rgb: &LinearRgb) -> f64 {
+    LUMINANCE.0 * rgb.r() + LUMINANCE.1 * rgb.g() + LUMINANCE.2 * rgb.b()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::rgb::linear::LinearRgb;
+    use crate::models::rgb::RGBColor;
+    use crate::space::{
+        pq_decode_nits, pq_encode_nits, HlgDisplay, RgbSpace, REFERENCE_WHITE_LUMINANCE,
+    };
+    use crate::RGB48;
+
+    #[test]
+    fn pq_nits() {
+        assert!((pq_encode_nits(100.0) - 0.508_078).abs() < 1e-6);
+        assert!((pq_encode_nits(203.0) - 0.580_689).abs() < 1e-6);
+        assert!((pq_encode_nits(10000.0) - 1.0).abs() < 1e-12);
+        for nits in [0.0, 0.005, 1.0, 100.0, 1000.0, 4000.0, 10000.0] {
+            assert!((nits - pq_decode_nits(pq_encode_nits(nits))).abs() < 1e-9 * nits.max(1.0));
+        }
+    }
+
+    #[test]
+    fn hlg_gamma() {
+        assert!((HlgDisplay::new(1000.0).gamma() - 1.2).abs() < 1e-12);
+        assert!((HlgDisplay::new(2000.0).gamma() - 1.326_44).abs() < 1e-5);
+        assert!((HlgDisplay::new(400.0).gamma() - 1.032_87).abs() < 1e-5);
+    }
+
+    #[test]
+    fn hlg_nits() {
+        let display = HlgDisplay::new(1000.0);
+        let white = display.decode_nits((1.0, 1.0, 1.0));
+        assert!((white.r() - 1000.0).abs() < 0.1);
+        assert!((white.b() - 1000.0).abs() < 0.1);
+        assert_eq!(LinearRgb::BLACK, display.decode_nits((0.0, 0.0, 0.0)));
+
+        let reference = display.decode_nits((0.75, 0.75, 0.75));
+        assert!((reference.r() - 203.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn hlg_round_trip() {
+        for peak in [400.0, 1000.0, 4000.0] {
+            let display = HlgDisplay::new(peak);
+            for signal in [(0.1, 0.5, 0.9), (0.75, 0.75, 0.75), (1.0, 0.0, 0.2)] {
+                let (r, g, b) = display.encode_nits(&display.decode_nits(signal));
+                assert!((signal.0 - r).abs() < 1e-9, "{:?}: {}", signal, r);
+                assert!((signal.1 - g).abs() < 1e-9, "{:?}: {}", signal, g);
+                assert!((signal.2 - b).abs() < 1e-9, "{:?}: {}", signal, b);
+            }
+        }
+    }
+
+    #[test]
+    fn rec2100_spaces() {
+        let pq = RgbSpace::rec2100_pq();
+        let hlg = RgbSpace::rec2100_hlg();
+        assert_eq!(RgbSpace::rec2020().to_xyz_matrix(), pq.to_xyz_matrix());
+        assert_eq!(RgbSpace::rec2020().to_xyz_matrix(), hlg.to_xyz_matrix());
+
+        // SDR white is placed at the reference white of 203 nits (about 58% PQ)
+        let white: RGB48 = RgbSpace::srgb().convert(&RGB48::WHITE, pq);
+        let signal = white.as_tuple_f64();
+        assert!((signal.0 - 0.5807).abs() < 1e-4, "{:?}", signal);
+        assert!((signal.1 - signal.0).abs() < 1e-4 && (signal.2 - signal.0).abs() < 1e-4);
+        let gray = pq.decode((0.5, 0.5, 0.5));
+        assert!((gray.r() * REFERENCE_WHITE_LUMINANCE - pq_decode_nits(0.5)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,327 @@
+use crate::models::xyz::XYZ;
+use crate::names::normalize_name;
+use std::fmt::{Display, Formatter, Result};
+
+/// A CIE standard observer (color matching functions)
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Observer {
+    /// The CIE 1931 2° standard observer, the default
+    #[default]
+    Cie1931,
+    /// The CIE 1964 10° supplementary standard observer
+    Cie1964,
+}
+
+/// A [standard illuminant](https://en.wikipedia.org/wiki/Standard_illuminant) of the CIE
+///
+/// Provides the white points of the illuminants as chromaticity and [`XYZ`]
+/// for the 2° and 10° observer (values of CIE 15:2018).
+/// The LED series is only published for the 2° observer.
+///
+/// # Example
+/// ```
+/// use colorful::space::{Illuminant, Observer};
+///
+/// assert_eq!((0.34567, 0.35850), Illuminant::D50.white_point());
+/// assert_eq!(Some((0.34773, 0.35952)), Illuminant::D50.chromaticity(Observer::Cie1964));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Illuminant {
+    /// Incandescent / tungsten light (2856 K)
+    A,
+    /// Direct sunlight at noon (obsolete, 4874 K)
+    B,
+    /// Average daylight (obsolete, 6774 K)
+    C,
+    /// Horizon light (5003 K) - the white of ICC profiles and print
+    D50,
+    /// Mid-morning / mid-afternoon daylight (5503 K)
+    D55,
+    /// Noon daylight (6504 K) - the white of sRGB and most screens
+    D65,
+    /// North sky daylight (7504 K)
+    D75,
+    /// Equal energy
+    E,
+    /// Daylight fluorescent (6430 K)
+    F1,
+    /// Cool white fluorescent (4230 K)
+    F2,
+    /// White fluorescent (3450 K)
+    F3,
+    /// Warm white fluorescent (2940 K)
+    F4,
+    /// Daylight fluorescent (6350 K)
+    F5,
+    /// Lite white fluorescent (4150 K)
+    F6,
+    /// Broadband daylight fluorescent (6500 K)
+    F7,
+    /// Broadband fluorescent (5000 K)
+    F8,
+    /// Broadband cool white fluorescent (4150 K)
+    F9,
+    /// Narrowband fluorescent (5000 K)
+    F10,
+    /// Narrowband fluorescent (4000 K)
+    F11,
+    /// Narrowband fluorescent (3000 K)
+    F12,
+    /// Phosphor-converted blue LED (2733 K)
+    LedB1,
+    /// Phosphor-converted blue LED (2998 K)
+    LedB2,
+    /// Phosphor-converted blue LED (4103 K)
+    LedB3,
+    /// Phosphor-converted blue LED (5109 K)
+    LedB4,
+    /// Phosphor-converted blue LED (6598 K)
+    LedB5,
+    /// Mixing of phosphor-converted blue LED and red LED (2851 K)
+    LedBh1,
+    /// Mixing of red, green and blue LEDs (2840 K)
+    LedRgb1,
+    /// Phosphor-converted violet LED (2724 K)
+    LedV1,
+    /// Phosphor-converted violet LED (4070 K)
+    LedV2,
+}
+
+/// All illuminants in declaration order
+const ILLUMINANTS: [Illuminant; 29] = [
+    Illuminant::A,
+    Illuminant::B,
+    Illuminant::C,
+    Illuminant::D50,
+    Illuminant::D55,
+    Illuminant::D65,
+    Illuminant::D75,
+    Illuminant::E,
+    Illuminant::F1,
+    Illuminant::F2,
+    Illuminant::F3,
+    Illuminant::F4,
+    Illuminant::F5,
+    Illuminant::F6,
+    Illuminant::F7,
+    Illuminant::F8,
+    Illuminant::F9,
+    Illuminant::F10,
+    Illuminant::F11,
+    Illuminant::F12,
+    Illuminant::LedB1,
+    Illuminant::LedB2,
+    Illuminant::LedB3,
+    Illuminant::LedB4,
+    Illuminant::LedB5,
+    Illuminant::LedBh1,
+    Illuminant::LedRgb1,
+    Illuminant::LedV1,
+    Illuminant::LedV2,
+];
+
+impl Illuminant {
+    /// Returns all illuminants
+    pub fn all() -> &'static [Illuminant] {
+        &ILLUMINANTS
+    }
+
+    /// Returns the illuminant with the given name (ignoring case, whitespace and dashes)
+    ///
+    /// # Example
+    /// ```
+    /// use colorful::space::Illuminant;
+    ///
+    /// assert_eq!(Some(Illuminant::D65), Illuminant::from_name("d65"));
+    /// assert_eq!(Some(Illuminant::LedB3), Illuminant::from_name("LED-B3"));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Illuminant> {
+        let normalized = normalize_name(name);
+        ILLUMINANTS
+            .iter()
+            .find(|illuminant| normalize_name(illuminant.name()) == normalized)
+            .copied()
+    }
+
+    /// Returns the name as used by the CIE (e.g. "D65", "LED-B3")
+    pub fn name(&self) -> &'static str {
+        match self {
+            Illuminant::A => "A",
+            Illuminant::B => "B",
+            Illuminant::C => "C",
+            Illuminant::D50 => "D50",
+            Illuminant::D55 => "D55",
+            Illuminant::D65 => "D65",
+            Illuminant::D75 => "D75",
+            Illuminant::E => "E",
+            Illuminant::F1 => "F1",
+            Illuminant::F2 => "F2",
+            Illuminant::F3 => "F3",
+            Illuminant::F4 => "F4",
+            Illuminant::F5 => "F5",
+            Illuminant::F6 => "F6",
+            Illuminant::F7 => "F7",
+            Illuminant::F8 => "F8",
+            Illuminant::F9 => "F9",
+            Illuminant::F10 => "F10",
+            Illuminant::F11 => "F11",
+            Illuminant::F12 => "F12",
+            Illuminant::LedB1 => "LED-B1",
+            Illuminant::LedB2 => "LED-B2",
+            Illuminant::LedB3 => "LED-B3",
+            Illuminant::LedB4 => "LED-B4",
+            Illuminant::LedB5 => "LED-B5",
+            Illuminant::LedBh1 => "LED-BH1",
+            Illuminant::LedRgb1 => "LED-RGB1",
+            Illuminant::LedV1 => "LED-V1",
+            Illuminant::LedV2 => "LED-V2",
+        }
+    }
+
+    /// Returns the chromaticity `(x, y)` for the 2° observer
+    ///
+    /// This is the white point as used by RGB color spaces.
+    pub fn white_point(&self) -> (f64, f64) {
+        self.chromaticities().0
+    }
+
+    /// Returns the chromaticity `(x, y)` for the given observer
+    ///
+    /// # Returns
+    /// `None` if there is no published value for the observer
+    pub fn chromaticity(&self, observer: Observer) -> Option<(f64, f64)> {
+        match observer {
+            Observer::Cie1931 => Some(self.chromaticities().0),
+            Observer::Cie1964 => self.chromaticities().1,
+        }
+    }
+
+    /// Returns the white point as [`XYZ`] normalized to `Y = 1.0` for the given observer
+    ///
+    /// # Returns
+    /// `None` if there is no published value for the observer
+    pub fn xyz(&self, observer: Observer) -> Option<XYZ> {
+        self.chromaticity(observer)
+            .map(|(x, y)| XYZ::from_xyy(x, y, 1.0))
+    }
+
+    /// Returns the chromaticities for the 2° and (if published) 10° observer
+    fn chromaticities(&self) -> ((f64, f64), Option<(f64, f64)>) {
+        match self {
+            Illuminant::A => ((0.44757, 0.40745), Some((0.45117, 0.40594))),
+            Illuminant::B => ((0.34842, 0.35161), Some((0.34980, 0.35270))),
+            Illuminant::C => ((0.31006, 0.31616), Some((0.31039, 0.31905))),
+            Illuminant::D50 => ((0.34567, 0.35850), Some((0.34773, 0.35952))),
+            Illuminant::D55 => ((0.33242, 0.34743), Some((0.33411, 0.34877))),
+            Illuminant::D65 => ((0.31271, 0.32902), Some((0.31382, 0.33100))),
+            Illuminant::D75 => ((0.29902, 0.31485), Some((0.29968, 0.31740))),
+            Illuminant::E => ((1.0 / 3.0, 1.0 / 3.0), Some((1.0 / 3.0, 1.0 / 3.0))),
+            Illuminant::F1 => ((0.31310, 0.33727), Some((0.31811, 0.33559))),
+            Illuminant::F2 => ((0.37208, 0.37529), Some((0.37925, 0.36733))),
+            Illuminant::F3 => ((0.40910, 0.39430), Some((0.41761, 0.38324))),
+            Illuminant::F4 => ((0.44018, 0.40329), Some((0.44920, 0.39074))),
+            Illuminant::F5 => ((0.31379, 0.34531), Some((0.31975, 0.34246))),
+            Illuminant::F6 => ((0.37790, 0.38835), Some((0.38660, 0.37847))),
+            Illuminant::F7 => ((0.31292, 0.32933), Some((0.31569, 0.32960))),
+            Illuminant::F8 => ((0.34588, 0.35875), Some((0.34902, 0.35939))),
+            Illuminant::F9 => ((0.37417, 0.37281), Some((0.37829, 0.37045))),
+            Illuminant::F10 => ((0.34609, 0.35986), Some((0.35090, 0.35444))),
+            Illuminant::F11 => ((0.38052, 0.37713), Some((0.38541, 0.37123))),
+            Illuminant::F12 => ((0.43695, 0.40441), Some((0.44256, 0.39717))),
+            Illuminant::LedB1 => ((0.4560, 0.4078), None),
+            Illuminant::LedB2 => ((0.4357, 0.4012), None),
+            Illuminant::LedB3 => ((0.3756, 0.3723), None),
+            Illuminant::LedB4 => ((0.3422, 0.3502), None),
+            Illuminant::LedB5 => ((0.3118, 0.3236), None),
+            Illuminant::LedBh1 => ((0.4474, 0.4066), None),
+            Illuminant::LedRgb1 => ((0.4557, 0.4211), None),
+            Illuminant::LedV1 => ((0.4560, 0.4548), None),
+            Illuminant::LedV2 => ((0.3781, 0.3775), None),
+        }
+    }
+}
+
+impl Display for Illuminant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::space::{Illuminant, Observer};
+    use std::collections::HashSet;
+
+    #[test]
+    fn all_() {
+        let all = Illuminant::all();
+        assert_eq!(29, all.len());
+        assert_eq!(29, all.iter().collect::<HashSet<_>>().len());
+        assert_eq!(
+            29,
+            all.iter().map(|i| i.name()).collect::<HashSet<_>>().len()
+        );
+    }
+
+    #[test]
+    fn from_name() {
+        for illuminant in Illuminant::all() {
+            assert_eq!(Some(*illuminant), Illuminant::from_name(illuminant.name()));
+            let lowercase = illuminant.name().to_lowercase();
+            assert_eq!(Some(*illuminant), Illuminant::from_name(&lowercase));
+        }
+        assert_eq!(Some(Illuminant::LedRgb1), Illuminant::from_name("led rgb1"));
+        assert_eq!(None, Illuminant::from_name("D60"));
+    }
+
+    #[test]
+    fn xyz_() {
+        // published tristimulus values normalized to Y = 100
+        let expected = [
+            (Illuminant::A, Observer::Cie1931, (109.850, 100.0, 35.585)),
+            (Illuminant::C, Observer::Cie1931, (98.074, 100.0, 118.232)),
+            (Illuminant::D50, Observer::Cie1931, (96.422, 100.0, 82.521)),
+            (Illuminant::D65, Observer::Cie1931, (95.047, 100.0, 108.883)),
+            (Illuminant::D65, Observer::Cie1964, (94.811, 100.0, 107.304)),
+        ];
+        for (illuminant, observer, (x, y, z)) in expected {
+            let xyz = illuminant.xyz(observer).unwrap();
+            assert!(
+                (x / 100.0 - xyz.x()).abs() < 5e-4,
+                "{} {:?}",
+                illuminant,
+                xyz
+            );
+            assert!(
+                (y / 100.0 - xyz.y()).abs() < 1e-12,
+                "{} {:?}",
+                illuminant,
+                xyz
+            );
+            assert!(
+                (z / 100.0 - xyz.z()).abs() < 5e-4,
+                "{} {:?}",
+                illuminant,
+                xyz
+            );
+        }
+    }
+
+    #[test]
+    fn observers() {
+        for illuminant in Illuminant::all() {
+            let two_degree = illuminant.chromaticity(Observer::Cie1931);
+            assert_eq!(Some(illuminant.white_point()), two_degree);
+            let is_led = illuminant.name().starts_with("LED");
+            assert_eq!(is_led, illuminant.chromaticity(Observer::Cie1964).is_none());
+        }
+        assert_eq!(None, Illuminant::LedB1.xyz(Observer::Cie1964));
+    }
+
+    #[test]
+    fn equal_energy() {
+        let xyz = Illuminant::E.xyz(Observer::Cie1931).unwrap();
+        assert!((1.0 - xyz.x()).abs() < 1e-12);
+        assert!((1.0 - xyz.z()).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,552 @@
+use crate::converter;
+use crate::space::PQ_PEAK_LUMINANCE;
+
+/// A transfer function (often called "gamma") between linear light and encoded values
+///
+/// All functions except the log encodings of ACES are mirrored at zero,
+/// so negative (out of gamut) values are preserved.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransferFunction {
+    /// No encoding at all - the values are linear
+    Linear,
+    /// The piecewise curve of [sRGB](https://en.wikipedia.org/wiki/SRGB#Transfer_function_(%22gamma%22))
+    Srgb,
+    /// A pure power function: `encoded = linear ^ (1 / gamma)`
+    ///
+    /// The gamma must be positive and finite, see [`TransferFunction::gamma`].
+    Gamma(f64),
+    /// The curve of Rec. 709 and Rec. 2020 (OETF of ITU-R BT.709 / BT.2020)
+    Rec709,
+    /// The curve of ProPhoto RGB (ROMM RGB): gamma 1.8 with a linear segment near black
+    ProPhoto,
+    /// The perceptual quantizer of HDR10 (SMPTE ST 2084 / ITU-R BT.2100)
+    ///
+    /// Linear `1.0` is the absolute luminance of 10000 cd/m² (nits),
+    /// see [`pq_encode_nits`](crate::space::pq_encode_nits) for values in nits.
+    Pq,
+    /// The perceptual quantizer for relative values: linear `1.0` is white of the given luminance in nits
+    ///
+    /// The luminance must be positive and finite, usually it is
+    /// [`REFERENCE_WHITE_LUMINANCE`](crate::space::REFERENCE_WHITE_LUMINANCE).
+    RelativePq(f64),
+    /// The OETF of hybrid log-gamma (ARIB STD-B67 / ITU-R BT.2100)
+    ///
+    /// Linear values are relative scene light, see [`HlgDisplay`](crate::space::HlgDisplay)
+    /// for the display light in nits.
+    Hlg,
+    /// The pure log encoding of ACEScc (S-2014-003)
+    ///
+    /// Linear values `<= 0.0` are encoded as the minimum, so they can't be restored.
+    AcesCc,
+    /// The log encoding of ACEScct (S-2016-001) - ACEScc with a linear toe
+    AcesCct,
+    /// A parametric curve as used by ICC profiles
+    Parametric(ParametricCurve),
+    /// A curve given by a table of linear values as used by ICC profiles
+    Table(CurveTable),
+}
+
+/// A parametric curve as defined by ICC profiles (`parametricCurveType`)
+///
+/// Decodes an encoded value `X` to linear light `Y`:
+/// - `Y = (a * X + b) ^ g + e` if `X >= d`
+/// - `Y = c * X + f` if `X < d`
+///
+/// The simpler function types of ICC profiles are special cases of this
+/// (e.g. a pure gamma is `a = 1` and everything else `0`).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ParametricCurve {
+    g: f64,
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    e: f64,
+    f: f64,
+}
+
+/// A curve given by a table of linear values for equally spaced encoded values (ICC `curveType`)
+///
+/// The first entry belongs to the encoded value `0.0`, the last to `1.0`.
+/// Values in between are interpolated linearly, values above `1.0` are extrapolated
+/// from the last segment.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurveTable {
+    values: Vec<f64>,
+}
+
+/// `α` of the Rec. 709 / Rec. 2020 curve
+const REC709_ALPHA: f64 = 1.099_296_826_809_44;
+/// `β` of the Rec. 709 / Rec. 2020 curve
+const REC709_BETA: f64 = 0.018_053_968_510_807;
+/// The linear value where ProPhoto switches from the linear segment to the power function
+const PROPHOTO_THRESHOLD: f64 = 1.0 / 512.0;
+
+/// The constants of PQ (SMPTE ST 2084)
+const PQ_M1: f64 = 2610.0 / 16384.0;
+const PQ_M2: f64 = 2523.0 / 4096.0 * 128.0;
+const PQ_C1: f64 = 3424.0 / 4096.0;
+const PQ_C2: f64 = 2413.0 / 4096.0 * 32.0;
+const PQ_C3: f64 = 2392.0 / 4096.0 * 32.0;
+
+/// The constants of HLG (ITU-R BT.2100)
+const HLG_A: f64 = 0.178_832_77;
+const HLG_B: f64 = 0.284_668_92;
+const HLG_C: f64 = 0.559_910_73;
+
+/// The constants of the ACES log encodings
+const ACES_LOG_OFFSET: f64 = 9.72;
+const ACES_LOG_SCALE: f64 = 17.52;
+/// The largest value of half floats
+const ACES_MAX: f64 = 65504.0;
+const ACESCCT_TOE_THRESHOLD: f64 = 0.0078125;
+const ACESCCT_TOE_SLOPE: f64 = 10.540_237_741_654_5;
+const ACESCCT_TOE_OFFSET: f64 = 0.072_905_534_195_835_5;
+
+impl TransferFunction {
+    /// Creates a pure power function [`TransferFunction::Gamma`]
+    ///
+    /// # Returns
+    /// `None` if `gamma` is not positive and finite
+    pub fn gamma(gamma: f64) -> Option<Self> {
+        let function = TransferFunction::Gamma(gamma);
+        if function.is_valid() {
+            Some(function)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the parameters of this function are valid
+    ///
+    /// Only [`TransferFunction::Gamma`] and [`TransferFunction::RelativePq`] can be created
+    /// with invalid parameters, the curves are validated by their constructors.
+    pub(crate) fn is_valid(&self) -> bool {
+        match self {
+            TransferFunction::Gamma(gamma) => gamma.is_finite() && *gamma > 0.0,
+            TransferFunction::RelativePq(white) => white.is_finite() && *white > 0.0,
+            _ => true,
+        }
+    }
+
+    /// Encodes the given linear value
+    pub fn encode(&self, linear: f64) -> f64 {
+        let abs = linear.abs();
+        let encoded = match self {
+            TransferFunction::Linear => abs,
+            TransferFunction::Srgb => converter::srgb_encode(abs),
+            TransferFunction::Gamma(gamma) => abs.powf(1.0 / gamma),
+            TransferFunction::Rec709 => {
+                if abs < REC709_BETA {
+                    4.5 * abs
+                } else {
+                    REC709_ALPHA * abs.powf(0.45) - (REC709_ALPHA - 1.0)
+                }
+            }
+            TransferFunction::ProPhoto => {
+                if abs < PROPHOTO_THRESHOLD {
+                    16.0 * abs
+                } else {
+                    abs.powf(1.0 / 1.8)
+                }
+            }
+            TransferFunction::Pq => {
+                let power = abs.powf(PQ_M1);
+                ((PQ_C1 + PQ_C2 * power) / (1.0 + PQ_C3 * power)).powf(PQ_M2)
+            }
+            TransferFunction::RelativePq(white) => {
+                TransferFunction::Pq.encode(abs * white / PQ_PEAK_LUMINANCE)
+            }
+            TransferFunction::Hlg => {
+                if abs <= 1.0 / 12.0 {
+                    (3.0 * abs).sqrt()
+                } else {
+                    HLG_A * (12.0 * abs - HLG_B).ln() + HLG_C
+                }
+            }
+            TransferFunction::AcesCc => return acescc_encode(linear),
+            TransferFunction::AcesCct => return acescct_encode(linear),
+            TransferFunction::Parametric(curve) => curve.encode(abs),
+            TransferFunction::Table(table) => table.encode(abs),
+        };
+        encoded.copysign(linear)
+    }
+
+    /// Decodes the given encoded value to linear light
+    pub fn decode(&self, encoded: f64) -> f64 {
+        let abs = encoded.abs();
+        let linear = match self {
+            TransferFunction::Linear => abs,
+            TransferFunction::Srgb => converter::srgb_decode(abs),
+            TransferFunction::Gamma(gamma) => abs.powf(*gamma),
+            TransferFunction::Rec709 => {
+                if abs < 4.5 * REC709_BETA {
+                    abs / 4.5
+                } else {
+                    ((abs + REC709_ALPHA - 1.0) / REC709_ALPHA).powf(1.0 / 0.45)
+                }
+            }
+            TransferFunction::ProPhoto => {
+                if abs < 16.0 * PROPHOTO_THRESHOLD {
+                    abs / 16.0
+                } else {
+                    abs.powf(1.8)
+                }
+            }
+            TransferFunction::Pq => {
+                let power = abs.powf(1.0 / PQ_M2);
+                ((power - PQ_C1).max(0.0) / (PQ_C2 - PQ_C3 * power)).powf(1.0 / PQ_M1)
+            }
+            TransferFunction::RelativePq(white) => {
+                TransferFunction::Pq.decode(abs) * PQ_PEAK_LUMINANCE / white
+            }
+            TransferFunction::Hlg => {
+                if abs <= 0.5 {
+                    abs * abs / 3.0
+                } else {
+                    (((abs - HLG_C) / HLG_A).exp() + HLG_B) / 12.0
+                }
+            }
+            TransferFunction::AcesCc => return acescc_decode(encoded),
+            TransferFunction::AcesCct => return acescct_decode(encoded),
+            TransferFunction::Parametric(curve) => curve.decode(abs),
+            TransferFunction::Table(table) => table.decode(abs),
+        };
+        linear.copysign(encoded)
+    }
+}
+
+/// Encodes the given linear value with ACEScc
+fn acescc_encode(linear: f64) -> f64 {
+    let log = if linear <= 0.0 {
+        -16.0
+    } else if linear < 2.0_f64.powi(-15) {
+        (2.0_f64.powi(-16) + linear * 0.5).log2()
+    } else {
+        linear.log2()
+    };
+    (log + ACES_LOG_OFFSET) / ACES_LOG_SCALE
+}
+
+/// Decodes the given ACEScc value
+fn acescc_decode(encoded: f64) -> f64 {
+    if encoded <= (ACES_LOG_OFFSET - 15.0) / ACES_LOG_SCALE {
+        (2.0_f64.powf(encoded * ACES_LOG_SCALE - ACES_LOG_OFFSET) - 2.0_f64.powi(-16)) * 2.0
+    } else {
+        2.0_f64
+            .powf(encoded * ACES_LOG_SCALE - ACES_LOG_OFFSET)
+            .min(ACES_MAX)
+    }
+}
+
+/// Encodes the given linear value with ACEScct
+fn acescct_encode(linear: f64) -> f64 {
+    if linear <= ACESCCT_TOE_THRESHOLD {
+        ACESCCT_TOE_SLOPE * linear + ACESCCT_TOE_OFFSET
+    } else {
+        (linear.log2() + ACES_LOG_OFFSET) / ACES_LOG_SCALE
+    }
+}
+
+/// Decodes the given ACEScct value
+fn acescct_decode(encoded: f64) -> f64 {
+    if encoded <= acescct_encode(ACESCCT_TOE_THRESHOLD) {
+        (encoded - ACESCCT_TOE_OFFSET) / ACESCCT_TOE_SLOPE
+    } else {
+        2.0_f64
+            .powf(encoded * ACES_LOG_SCALE - ACES_LOG_OFFSET)
+            .min(ACES_MAX)
+    }
+}
+
+impl ParametricCurve {
+    /// Creates a new `ParametricCurve` from the ICC parameters
+    ///
+    /// # Returns
+    /// `None` if the curve is not invertible, i.e. if `g` or `a` is not positive
+    /// or `c` is negative
+    pub fn new(g: f64, a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Option<Self> {
+        let parameters = [g, a, b, c, d, e, f];
+        if parameters.iter().any(|p| !p.is_finite()) || g <= 0.0 || a <= 0.0 || c < 0.0 {
+            return None;
+        }
+        Some(ParametricCurve {
+            g,
+            a,
+            b,
+            c,
+            d,
+            e,
+            f,
+        })
+    }
+
+    /// Creates a pure gamma curve: `Y = X ^ g`
+    ///
+    /// # Returns
+    /// `None` if `g` is not positive
+    pub fn gamma(g: f64) -> Option<Self> {
+        ParametricCurve::new(g, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Returns the parameters as tuple `(g, a, b, c, d, e, f)`
+    pub fn as_tuple(&self) -> (f64, f64, f64, f64, f64, f64, f64) {
+        (self.g, self.a, self.b, self.c, self.d, self.e, self.f)
+    }
+
+    fn decode(&self, x: f64) -> f64 {
+        if x >= self.d {
+            (self.a * x + self.b).max(0.0).powf(self.g) + self.e
+        } else {
+            self.c * x + self.f
+        }
+    }
+
+    fn encode(&self, y: f64) -> f64 {
+        if self.d > 0.0 && y < self.c * self.d + self.f {
+            if self.c == 0.0 {
+                0.0
+            } else {
+                (y - self.f) / self.c
+            }
+        } else {
+            (((y - self.e).max(0.0)).powf(1.0 / self.g) - self.b) / self.a
+        }
+    }
+}
+
+impl CurveTable {
+    /// Creates a new `CurveTable` from the linear values
+    ///
+    /// # Returns
+    /// `None` if there are less than 2 values or the values are not increasing
+    pub fn new(values: Vec<f64>) -> Option<Self> {
+        let is_increasing = values
+            .windows(2)
+            .all(|pair| pair[0].is_finite() && pair[1].is_finite() && pair[0] <= pair[1]);
+        if values.len() < 2 || !is_increasing || values.first() == values.last() {
+            return None;
+        }
+        Some(CurveTable { values })
+    }
+
+    /// Returns the linear values
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    fn decode(&self, x: f64) -> f64 {
+        let position = x.max(0.0) * (self.values.len() - 1) as f64;
+        let index = (position.floor() as usize).min(self.values.len() - 2);
+        let fraction = position - index as f64;
+        self.values[index] + (self.values[index + 1] - self.values[index]) * fraction
+    }
+
+    fn encode(&self, y: f64) -> f64 {
+        let last = self.values.len() - 1;
+        let upper = self
+            .values
+            .partition_point(|&value| value < y)
+            .clamp(1, last);
+        let (low, high) = (self.values[upper - 1], self.values[upper]);
+        let fraction = if high == low {
+            0.0
+        } else {
+            ((y - low) / (high - low)).max(0.0)
+        };
+        (upper as f64 - 1.0 + fraction) / last as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::space::{
+        pq_encode_nits, CurveTable, ParametricCurve, TransferFunction, REFERENCE_WHITE_LUMINANCE,
+    };
+
+    fn srgb_curve() -> ParametricCurve {
+        ParametricCurve::new(
+            2.4,
+            1.0 / 1.055,
+            0.055 / 1.055,
+            1.0 / 12.92,
+            0.04045,
+            0.0,
+            0.0,
+        )
+        .unwrap()
+    }
+
+    fn all() -> Vec<TransferFunction> {
+        vec![
+            TransferFunction::Linear,
+            TransferFunction::Srgb,
+            TransferFunction::Gamma(2.2),
+            TransferFunction::Rec709,
+            TransferFunction::ProPhoto,
+            TransferFunction::Parametric(srgb_curve()),
+            TransferFunction::Table(CurveTable::new(vec![0.0, 0.1, 0.3, 1.0]).unwrap()),
+        ]
+    }
+
+    #[test]
+    fn round_trip() {
+        for function in all() {
+            for step in -100..=200 {
+                let value = step as f64 / 100.0;
+                let result = function.decode(function.encode(value));
+                assert!((value - result).abs() < 1e-12, "{:?}: {}", function, value);
+            }
+        }
+    }
+
+    #[test]
+    fn black_and_white() {
+        for function in all() {
+            assert_eq!(0.0, function.encode(0.0));
+            assert!((1.0 - function.encode(1.0)).abs() < 1e-12, "{:?}", function);
+            assert!((1.0 - function.decode(1.0)).abs() < 1e-12, "{:?}", function);
+        }
+    }
+
+    #[test]
+    fn encode_() {
+        assert_eq!(0.5, TransferFunction::Linear.encode(0.5));
+        assert!((TransferFunction::Gamma(2.0).encode(0.25) - 0.5).abs() < 1e-12);
+        assert!((TransferFunction::Rec709.encode(0.01) - 0.045).abs() < 1e-12);
+        assert!((TransferFunction::Rec709.encode(0.18) - 0.408_848_109).abs() < 1e-9);
+        assert!((TransferFunction::ProPhoto.encode(0.001) - 0.016).abs() < 1e-12);
+        assert_eq!(-0.5, TransferFunction::Gamma(2.0).encode(-0.25));
+    }
+
+    #[test]
+    fn pq() {
+        // reference values of ITU-R BT.2100
+        assert!((TransferFunction::Pq.encode(0.01) - 0.508_078).abs() < 1e-6);
+        assert!((TransferFunction::Pq.encode(0.1) - 0.751_827).abs() < 1e-6);
+        assert!((TransferFunction::Pq.decode(0.5) - 0.009_224_6).abs() < 1e-6);
+        assert_eq!(0.0, TransferFunction::Pq.decode(0.0));
+        assert!((1.0 - TransferFunction::Pq.encode(1.0)).abs() < 1e-12);
+        for step in -100..=200 {
+            let value = step as f64 / 100.0;
+            let result = TransferFunction::Pq.decode(TransferFunction::Pq.encode(value));
+            assert!((value - result).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn relative_pq() {
+        let pq = TransferFunction::RelativePq(REFERENCE_WHITE_LUMINANCE);
+        assert!((pq.encode(1.0) - pq_encode_nits(REFERENCE_WHITE_LUMINANCE)).abs() < 1e-12);
+        assert!((pq.encode(1.0) - 0.580_7).abs() < 1e-4);
+        assert!((pq.decode(1.0) * REFERENCE_WHITE_LUMINANCE - 10000.0).abs() < 1e-6);
+        for step in -100..=200 {
+            let value = step as f64 / 100.0;
+            assert!((value - pq.decode(pq.encode(value))).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn hlg() {
+        let hlg = TransferFunction::Hlg;
+        assert!((hlg.encode(1.0 / 12.0) - 0.5).abs() < 1e-12);
+        assert!((hlg.encode(1.0) - 1.0).abs() < 1e-6);
+        assert!((hlg.decode(0.5) - 1.0 / 12.0).abs() < 1e-12);
+        assert!((hlg.decode(0.75) - 0.264_962).abs() < 1e-6);
+        for step in -100..=200 {
+            let value = step as f64 / 100.0;
+            assert!((value - hlg.decode(hlg.encode(value))).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn acescc() {
+        let cc = TransferFunction::AcesCc;
+        assert!((cc.encode(0.18) - 0.413_588_4).abs() < 1e-7);
+        assert!((cc.encode(1.0) - 0.554_794_5).abs() < 1e-7);
+        assert!((cc.encode(0.0) - (-0.358_447_5)).abs() < 1e-7);
+        assert_eq!(cc.encode(0.0), cc.encode(-1.0));
+        assert_eq!(65504.0, cc.decode(2.0));
+        for value in [0.0, 1e-6, 2e-5, 0.001, 0.18, 1.0, 16.0, 1000.0] {
+            assert!((value - cc.decode(cc.encode(value))).abs() < 1e-9 * value.max(1.0));
+        }
+    }
+
+    #[test]
+    fn acescct() {
+        let cct = TransferFunction::AcesCct;
+        assert!((cct.encode(0.18) - 0.413_588_4).abs() < 1e-7);
+        assert!((cct.encode(0.0) - 0.072_905_534_195_835_5).abs() < 1e-12);
+        // both segments meet at the threshold
+        let threshold = 0.0078125;
+        let log = (f64::log2(threshold) + 9.72) / 17.52;
+        assert!((cct.encode(threshold) - log).abs() < 1e-7);
+        assert_eq!(65504.0, cct.decode(2.0));
+        for value in [-0.01, 0.0, 0.001, 0.0078125, 0.01, 0.18, 1.0, 1000.0] {
+            assert!((value - cct.decode(cct.encode(value))).abs() < 1e-9 * value.max(1.0));
+        }
+    }
+
+    #[test]
+    fn parametric_srgb() {
+        let parametric = TransferFunction::Parametric(srgb_curve());
+        for step in 0..=100 {
+            let value = step as f64 / 100.0;
+            let expected = TransferFunction::Srgb.decode(value);
+            assert!((expected - parametric.decode(value)).abs() < 1e-12);
+            let expected = TransferFunction::Srgb.encode(value);
+            assert!((expected - parametric.encode(value)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn parametric_gamma() {
+        let parametric = TransferFunction::Parametric(ParametricCurve::gamma(2.2).unwrap());
+        assert!((TransferFunction::Gamma(2.2).decode(0.3) - parametric.decode(0.3)).abs() < 1e-12);
+        assert!((TransferFunction::Gamma(2.2).encode(0.3) - parametric.encode(0.3)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn gamma() {
+        assert_eq!(
+            Some(TransferFunction::Gamma(2.2)),
+            TransferFunction::gamma(2.2)
+        );
+        assert_eq!(None, TransferFunction::gamma(0.0));
+        assert_eq!(None, TransferFunction::gamma(-2.2));
+        assert_eq!(None, TransferFunction::gamma(f64::INFINITY));
+        assert_eq!(None, TransferFunction::gamma(f64::NAN));
+    }
+
+    #[test]
+    fn parametric_invalid() {
+        assert_eq!(None, ParametricCurve::gamma(0.0));
+        assert_eq!(
+            None,
+            ParametricCurve::new(2.2, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            None,
+            ParametricCurve::new(2.2, 1.0, f64::NAN, 0.0, 0.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn table_() {
+        let table = TransferFunction::Table(CurveTable::new(vec![0.0, 0.2, 1.0]).unwrap());
+        assert!((0.1 - table.decode(0.25)).abs() < 1e-12);
+        assert!((0.6 - table.decode(0.75)).abs() < 1e-12);
+        assert!((1.8 - table.decode(1.5)).abs() < 1e-12);
+        assert!((0.25 - table.encode(0.1)).abs() < 1e-12);
+        assert!((0.75 - table.encode(0.6)).abs() < 1e-12);
+        assert!((1.625 - table.encode(2.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn table_invalid() {
+        assert_eq!(None, CurveTable::new(vec![]));
+        assert_eq!(None, CurveTable::new(vec![1.0]));
+        assert_eq!(None, CurveTable::new(vec![0.0, 0.5, 0.4]));
+        assert_eq!(None, CurveTable::new(vec![0.5, 0.5]));
+    }
+}
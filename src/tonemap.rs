@@ -0,0 +1,328 @@
+use crate::models::rgb::linear::LinearRgb;
+use crate::models::rgb::RGBColor;
+use crate::space::{pq_decode_nits, pq_encode_nits};
+use crate::{RGB24, RGB48};
+
+/// A [tone mapping](https://en.wikipedia.org/wiki/Tone_mapping) operator compressing HDR -> SDR
+///
+/// The operators work on linear values where `1.0` is the white of the SDR display
+/// and map them into the displayable range `0.0 - 1.0`. Each channel is mapped
+/// on its own, negative values are treated as `0.0`.
+///
+/// Operators with invalid parameters (see [`ToneMapper::is_valid`]) only clamp the values.
+///
+/// # Example
+/// ```
+/// use colorful::tonemap::ToneMapper;
+/// use colorful::{LinearRgb, RGBColor, RGB24};
+///
+/// let highlight = LinearRgb::new(4.0, 2.0, 0.5);
+/// // clamping loses the difference between red and green
+/// assert_eq!(RGB24::from_rgb(255, 255, 188), highlight.to_rgb24());
+///
+/// let mapped = ToneMapper::AcesFilmic.to_rgb24(&highlight);
+/// assert!(mapped.r() > mapped.g() && mapped.g() > mapped.b());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ToneMapper {
+    /// Reinhard: `x / (1 + x)`
+    Reinhard,
+    /// Extended Reinhard with the given white (the smallest value mapped to `1.0`)
+    ///
+    /// The white must be positive and finite, see [`ToneMapper::reinhard_extended`].
+    ReinhardExtended(f64),
+    /// The filmic curve of John Hable used in Uncharted 2 (with exposure bias 2.0)
+    Hable,
+    /// The approximation of the ACES filmic curve by Krzysztof Narkowicz
+    AcesFilmic,
+    /// The EETF of ITU-R BT.2390 - a roll-off of the highlights in the PQ domain
+    ///
+    /// Maps content mastered for `source_peak` to a display with `target_peak`
+    /// (both in cd/m²). The input `1.0` is `target_peak`.
+    /// Both peaks must be positive and finite, see [`ToneMapper::bt2390`].
+    Bt2390 {
+        /// The peak luminance of the content in cd/m² (nits)
+        source_peak: f64,
+        /// The peak luminance of the display in cd/m² (nits)
+        target_peak: f64,
+    },
+}
+
+/// The parameters of the Hable curve (shoulder, linear, toe)
+const HABLE_A: f64 = 0.15;
+const HABLE_B: f64 = 0.50;
+const HABLE_C: f64 = 0.10;
+const HABLE_D: f64 = 0.20;
+const HABLE_E: f64 = 0.02;
+const HABLE_F: f64 = 0.30;
+/// The linear white of the Hable curve
+const HABLE_WHITE: f64 = 11.2;
+/// The exposure bias applied before the Hable curve
+const HABLE_EXPOSURE_BIAS: f64 = 2.0;
+
+impl ToneMapper {
+    /// Creates [`ToneMapper::ReinhardExtended`]
+    ///
+    /// # Parameters
+    /// - `white`: the smallest value mapped to `1.0`
+    ///
+    /// # Returns
+    /// `None` if `white` is not positive and finite
+    pub fn reinhard_extended(white: f64) -> Option<Self> {
+        let mapper = ToneMapper::ReinhardExtended(white);
+        if mapper.is_valid() {
+            Some(mapper)
+        } else {
+            None
+        }
+    }
+
+    /// Creates [`ToneMapper::Bt2390`]
+    ///
+    /// # Parameters
+    /// - `source_peak`: the peak luminance of the content in cd/m² (nits)
+    /// - `target_peak`: the peak luminance of the display in cd/m² (nits)
+    ///
+    /// # Returns
+    /// `None` if one of the peaks is not positive and finite
+    pub fn bt2390(source_peak: f64, target_peak: f64) -> Option<Self> {
+        let mapper = ToneMapper::Bt2390 {
+            source_peak,
+            target_peak,
+        };
+        if mapper.is_valid() {
+            Some(mapper)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the parameters of this operator are positive and finite
+    pub fn is_valid(&self) -> bool {
+        let is_valid = |value: f64| value.is_finite() && value > 0.0;
+        match *self {
+            ToneMapper::ReinhardExtended(white) => is_valid(white),
+            ToneMapper::Bt2390 {
+                source_peak,
+                target_peak,
+            } => is_valid(source_peak) && is_valid(target_peak),
+            _ => true,
+        }
+    }
+
+    /// Maps a single linear value -> `0.0 - 1.0`
+    pub fn map_value(&self, value: f64) -> f64 {
+        let x = value.max(0.0);
+        if !self.is_valid() {
+            return x.min(1.0);
+        }
+        let mapped = match *self {
+            ToneMapper::Reinhard => x / (1.0 + x),
+            ToneMapper::ReinhardExtended(white) => x * (1.0 + x / (white * white)) / (1.0 + x),
+            ToneMapper::Hable => hable(x * HABLE_EXPOSURE_BIAS) / hable(HABLE_WHITE),
+            ToneMapper::AcesFilmic => (x * (2.51 * x + 0.03)) / (x * (2.43 * x + 0.59) + 0.14),
+            ToneMapper::Bt2390 {
+                source_peak,
+                target_peak,
+            } => bt2390(x * target_peak, source_peak, target_peak) / target_peak,
+        };
+        mapped.clamp(0.0, 1.0)
+    }
+
+    /// Maps the given linear color -> `0.0 - 1.0`
+    pub fn map(&self, color: &LinearRgb) -> LinearRgb {
+        LinearRgb::new(
+            self.map_value(color.r()),
+            self.map_value(color.g()),
+            self.map_value(color.b()),
+        )
+    }
+
+    /// Maps the given linear color and encodes it to the given [`RGBColor`] (sRGB)
+    pub fn to_rgb<S: RGBColor<U>, U>(&self, color: &LinearRgb) -> S {
+        self.map(color).to_rgb()
+    }
+
+    /// Maps the given linear color and encodes it to [`RGB24`] (sRGB)
+    pub fn to_rgb24(&self, color: &LinearRgb) -> RGB24 {
+        self.map(color).to_rgb24()
+    }
+
+    /// Maps the given linear color and encodes it to [`RGB48`] (sRGB)
+    pub fn to_rgb48(&self, color: &LinearRgb) -> RGB48 {
+        self.map(color).to_rgb48()
+    }
+}
+
+/// The curve of John Hable
+fn hable(x: f64) -> f64 {
+    (x * (HABLE_A * x + HABLE_C * HABLE_B) + HABLE_D * HABLE_E)
+        / (x * (HABLE_A * x + HABLE_B) + HABLE_D * HABLE_F)
+        - HABLE_E / HABLE_F
+}
+
+/// The EETF of BT.2390 mapping the given luminance (cd/m²) -> luminance of the target display
+fn bt2390(nits: f64, source_peak: f64, target_peak: f64) -> f64 {
+    if target_peak >= source_peak {
+        return nits.min(source_peak);
+    }
+
+    let black = pq_encode_nits(0.0);
+    let range = pq_encode_nits(source_peak) - black;
+    let normalized = ((pq_encode_nits(nits) - black) / range).clamp(0.0, 1.0);
+    let max_luminance = (pq_encode_nits(target_peak) - black) / range;
+    let knee = 1.5 * max_luminance - 0.5;
+
+    let mapped = if normalized < knee {
+        normalized
+    } else {
+        // Hermite spline from the knee to the maximum luminance
+        let t = (normalized - knee) / (1.0 - knee);
+        let (t2, t3) = (t * t, t * t * t);
+        (2.0 * t3 - 3.0 * t2 + 1.0) * knee
+            + (t3 - 2.0 * t2 + t) * (1.0 - knee)
+            + (-2.0 * t3 + 3.0 * t2) * max_luminance
+    };
+
+    pq_decode_nits(mapped * range + black)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::rgb::linear::LinearRgb;
+    use crate::models::rgb::RGBColor;
+    use crate::tonemap::ToneMapper;
+    use crate::{RGB24, RGB48};
+
+    fn all() -> [ToneMapper; 5] {
+        [
+            ToneMapper::Reinhard,
+            ToneMapper::ReinhardExtended(4.0),
+            ToneMapper::Hable,
+            ToneMapper::AcesFilmic,
+            ToneMapper::Bt2390 {
+                source_peak: 1000.0,
+                target_peak: 100.0,
+            },
+        ]
+    }
+
+    #[test]
+    fn monotonic() {
+        for mapper in all() {
+            let mut last = mapper.map_value(0.0);
+            assert!(last.abs() < 1e-3, "{:?}: {}", mapper, last);
+            for step in 1..=2000 {
+                let value = mapper.map_value(step as f64 / 100.0);
+                assert!(value >= last, "{:?}: {}", mapper, step);
+                assert!(value <= 1.0, "{:?}: {}", mapper, step);
+                last = value;
+            }
+        }
+    }
+
+    #[test]
+    fn negative() {
+        for mapper in all() {
+            assert_eq!(mapper.map_value(0.0), mapper.map_value(-1.0));
+        }
+    }
+
+    #[test]
+    fn reinhard() {
+        assert_eq!(0.5, ToneMapper::Reinhard.map_value(1.0));
+        assert_eq!(0.8, ToneMapper::Reinhard.map_value(4.0));
+        assert_eq!(1.0, ToneMapper::ReinhardExtended(4.0).map_value(4.0));
+        assert_eq!(1.0, ToneMapper::ReinhardExtended(4.0).map_value(10.0));
+        assert!((ToneMapper::ReinhardExtended(4.0).map_value(1.0) - 0.53125).abs() < 1e-12);
+    }
+
+    #[test]
+    fn hable() {
+        assert!((ToneMapper::Hable.map_value(5.6) - 1.0).abs() < 1e-12);
+        assert!((ToneMapper::Hable.map_value(0.5) - 0.3043).abs() < 1e-4);
+    }
+
+    #[test]
+    fn aces_filmic() {
+        assert!((ToneMapper::AcesFilmic.map_value(1.0) - 0.803_797).abs() < 1e-6);
+        assert_eq!(1.0, ToneMapper::AcesFilmic.map_value(100.0));
+    }
+
+    #[test]
+    fn bt2390() {
+        let mapper = ToneMapper::Bt2390 {
+            source_peak: 1000.0,
+            target_peak: 100.0,
+        };
+        // dark values are unchanged, the source peak is mapped to the target peak
+        assert!((mapper.map_value(0.1) - 0.1).abs() < 1e-9);
+        assert!((mapper.map_value(10.0) - 1.0).abs() < 1e-9);
+        assert!((mapper.map_value(20.0) - 1.0).abs() < 1e-9);
+        assert!(mapper.map_value(1.0) < 1.0);
+
+        let identity = ToneMapper::Bt2390 {
+            source_peak: 100.0,
+            target_peak: 1000.0,
+        };
+        assert!((identity.map_value(0.05) - 0.05).abs() < 1e-12);
+    }
+
+    #[test]
+    fn constructors() {
+        assert_eq!(
+            ToneMapper::ReinhardExtended(4.0),
+            ToneMapper::reinhard_extended(4.0).unwrap()
+        );
+        assert_eq!(
+            ToneMapper::Bt2390 {
+                source_peak: 1000.0,
+                target_peak: 100.0,
+            },
+            ToneMapper::bt2390(1000.0, 100.0).unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        for value in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(None, ToneMapper::reinhard_extended(value));
+            assert_eq!(None, ToneMapper::bt2390(value, 100.0));
+            assert_eq!(None, ToneMapper::bt2390(1000.0, value));
+        }
+
+        // created without the constructors the values are only clamped
+        let invalid = [
+            ToneMapper::ReinhardExtended(0.0),
+            ToneMapper::Bt2390 {
+                source_peak: 1000.0,
+                target_peak: f64::NAN,
+            },
+        ];
+        for mapper in invalid {
+            assert!(!mapper.is_valid());
+            assert_eq!(0.5, mapper.map_value(0.5));
+            assert_eq!(1.0, mapper.map_value(4.0));
+            assert_eq!(0.0, mapper.map_value(-1.0));
+        }
+        assert!(all().iter().all(ToneMapper::is_valid));
+    }
+
+    #[test]
+    fn to_rgb() {
+        let color = LinearRgb::new(0.5, 2.0, 8.0);
+        for mapper in all() {
+            let rgb24 = mapper.to_rgb24(&color);
+            assert_eq!(mapper.map(&color).to_rgb24(), rgb24);
+            assert_eq!(rgb24, mapper.to_rgb::<RGB24, u8>(&color));
+            assert!(
+                rgb24.r() < rgb24.g() && rgb24.g() <= rgb24.b(),
+                "{:?}",
+                mapper
+            );
+
+            let rgb48: RGB48 = mapper.to_rgb(&color);
+            assert_eq!(mapper.to_rgb48(&color), rgb48);
+        }
+    }
+}